@@ -0,0 +1,15 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Packable)]
+pub struct Point {
+    x: u32,
+    #[packable(default = 0)]
+    y: u32,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::{error::DelimitedLenError, Packable};
+
+#[derive(Packable)]
+#[packable(unpack_error = DelimitedLenError)]
+pub struct Envelope {
+    #[packable(length_prefixed = u8)]
+    body: u32,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+fn verify_y(_: &u32) -> Result<(), core::convert::Infallible> {
+    Ok(())
+}
+
+#[derive(Packable)]
+pub struct Point {
+    x: u32,
+    #[packable(skip)]
+    #[packable(verify_with = verify_y)]
+    y: u32,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+#[derive(Packable)]
+#[packable(tag_type = u8)]
+pub enum Payload {
+    #[packable(tag = 0)]
+    Value(u32),
+    #[packable(unknown)]
+    Unknown(u8, Vec<u8>),
+}
+
+fn main() {}
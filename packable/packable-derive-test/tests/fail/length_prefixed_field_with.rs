@@ -0,0 +1,16 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::{error::DelimitedLenError, Packable};
+
+#[derive(Packable)]
+#[packable(unpack_error = DelimitedLenError)]
+pub struct Point {
+    #[packable(length_prefixed = u16)]
+    #[packable(with = flag)]
+    visible: bool,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::{error::DelimitedLenError, Packable};
+
+#[derive(Packable)]
+#[packable(unpack_error = DelimitedLenError)]
+pub struct Envelope<'a> {
+    #[packable(length_prefixed = u16)]
+    body: &'a [u8; 4],
+}
+
+fn main() {}
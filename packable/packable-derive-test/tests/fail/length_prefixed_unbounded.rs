@@ -0,0 +1,37 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{
+    error::{DelimitedLenError, UnpackError},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable,
+};
+
+pub struct Blob;
+
+impl Packable for Blob {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    fn pack<P: Packer>(&self, _packer: &mut P) -> Result<(), P::Error> {
+        Ok(())
+    }
+
+    fn unpack<U: Unpacker>(
+        _unpacker: &mut U,
+        _visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        Ok(Self)
+    }
+}
+
+#[derive(Packable)]
+#[packable(unpack_error = DelimitedLenError)]
+pub struct Envelope(#[packable(length_prefixed = u16)] Blob);
+
+fn main() {}
@@ -0,0 +1,19 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::{packer::Packer, Packable};
+
+fn pack_flag<P: Packer>(value: &bool, packer: &mut P) -> Result<(), P::Error> {
+    packer.pack_bytes([*value as u8])
+}
+
+#[derive(Packable)]
+pub struct Point {
+    x: u64,
+    #[packable(pack_with = pack_flag)]
+    visible: bool,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::{prefix::SlicePrefix, Packable};
+
+#[derive(Packable)]
+pub struct Pair<'a, 'b> {
+    first: SlicePrefix<'a, u8>,
+    second: SlicePrefix<'b, u8>,
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{
+    error::UnknownTagError,
+    prefix::{SlicePrefix, UnpackPrefixError},
+    Packable, UnpackBorrowed,
+};
+
+#[derive(Debug)]
+pub struct PayloadError(u8);
+
+impl From<Infallible> for PayloadError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<UnknownTagError<u8>> for PayloadError {
+    fn from(err: UnknownTagError<u8>) -> Self {
+        Self(err.0)
+    }
+}
+
+impl From<UnpackPrefixError<Infallible, Infallible>> for PayloadError {
+    fn from(err: UnpackPrefixError<Infallible, Infallible>) -> Self {
+        match Infallible::from(err) {}
+    }
+}
+
+#[derive(Packable)]
+#[packable(tag_type = u8)]
+#[packable(unpack_error = PayloadError)]
+pub enum Payload<'a> {
+    #[packable(tag = 0)]
+    Empty,
+    #[packable(tag = 1)]
+    Data(SlicePrefix<'a, u32>),
+    #[packable(tag = 2)]
+    Tagged { tag: u16, data: SlicePrefix<'a, u8> },
+}
+
+fn main() {}
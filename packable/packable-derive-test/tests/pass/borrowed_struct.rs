@@ -0,0 +1,48 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::{convert::Infallible, str::Utf8Error};
+
+use packable::{
+    prefix::{SlicePrefix, StrPrefix, UnpackPrefixError},
+    Packable, UnpackBorrowed,
+};
+
+#[derive(Debug)]
+pub enum PointError {
+    Name(Utf8Error),
+}
+
+impl From<Infallible> for PointError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<UnpackPrefixError<Infallible, Infallible>> for PointError {
+    fn from(err: UnpackPrefixError<Infallible, Infallible>) -> Self {
+        match Infallible::from(err) {}
+    }
+}
+
+impl From<UnpackPrefixError<Utf8Error, Infallible>> for PointError {
+    fn from(err: UnpackPrefixError<Utf8Error, Infallible>) -> Self {
+        Self::Name(err.into_item_err())
+    }
+}
+
+#[derive(Packable)]
+#[packable(unpack_error = PointError)]
+pub struct Point<'a> {
+    x: u32,
+    name: StrPrefix<'a, u8>,
+    data: SlicePrefix<'a, u16>,
+    hash: &'a [u8; 32],
+}
+
+#[derive(Packable)]
+pub struct Wrapper<'a>(Point<'a>, bool);
+
+fn main() {}
@@ -0,0 +1,58 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{
+    error::{UnknownTagError, UnpackError},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable,
+};
+
+mod le_u64 {
+    use super::*;
+
+    pub fn pack<P: Packer>(value: &u64, packer: &mut P) -> Result<(), P::Error> {
+        packer.pack_bytes(value.to_le_bytes())
+    }
+
+    pub fn unpack<U: Unpacker>(unpacker: &mut U, _: Option<&()>) -> Result<u64, UnpackError<Infallible, U::Error>> {
+        let mut bytes = [0u8; 8];
+        unpacker.unpack_bytes(&mut bytes)?;
+
+        Ok(u64::from_le_bytes(bytes))
+    }
+}
+
+fn pack_flag<P: Packer>(value: &bool, packer: &mut P) -> Result<(), P::Error> {
+    packer.pack_bytes([*value as u8])
+}
+
+fn unpack_flag<U: Unpacker>(unpacker: &mut U, _: Option<&()>) -> Result<bool, UnpackError<Infallible, U::Error>> {
+    let mut bytes = [0u8; 1];
+    unpacker.unpack_bytes(&mut bytes)?;
+
+    Ok(bytes[0] != 0)
+}
+
+#[derive(Packable)]
+#[packable(unpack_error = Infallible)]
+pub struct Point {
+    #[packable(with = le_u64)]
+    x: u64,
+    #[packable(pack_with = pack_flag, unpack_with = unpack_flag)]
+    visible: bool,
+}
+
+#[derive(Packable)]
+#[packable(tag_type = u8)]
+#[packable(unpack_error = UnknownTagError<u8>)]
+pub enum Shape {
+    #[packable(tag = 0)]
+    Dot(#[packable(with = le_u64)] u64),
+}
+
+fn main() {}
@@ -0,0 +1,52 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{error::DelimitedLenError, Packable};
+
+#[derive(Packable)]
+#[packable(unpack_error = DelimitedLenError)]
+pub struct Envelope {
+    version: u8,
+    #[packable(length_prefixed = u16)]
+    body: (u32, u8),
+    #[packable(length_prefixed = u32)]
+    tail: u64,
+}
+
+#[derive(Packable)]
+#[packable(unpack_error = DelimitedLenError)]
+pub struct Generic<T: Packable<UnpackError = DelimitedLenError>>(#[packable(length_prefixed = u32)] T);
+
+#[derive(Packable)]
+#[packable(tag_type = u8, with_error = MessageError::UnknownTag)]
+#[packable(unpack_error = MessageError)]
+pub enum Message {
+    #[packable(tag = 0)]
+    Ping,
+    #[packable(tag = 1)]
+    Data(#[packable(length_prefixed = u16)] u64),
+}
+
+#[derive(Debug)]
+pub enum MessageError {
+    UnknownTag(u8),
+    InvalidLen(DelimitedLenError),
+}
+
+impl From<DelimitedLenError> for MessageError {
+    fn from(err: DelimitedLenError) -> Self {
+        Self::InvalidLen(err)
+    }
+}
+
+impl From<Infallible> for MessageError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+fn main() {}
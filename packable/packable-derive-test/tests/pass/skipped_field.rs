@@ -0,0 +1,38 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use packable::Packable;
+
+pub struct Cache(Vec<u8>);
+
+#[derive(Packable)]
+#[packable(post_unpack = Point::fill_cache)]
+pub struct Point {
+    x: u32,
+    y: u32,
+    #[packable(skip, default = Cache(Vec::new()))]
+    cache: Cache,
+}
+
+impl Point {
+    fn fill_cache(&mut self) {
+        self.cache.0.extend_from_slice(&self.x.to_le_bytes());
+    }
+}
+
+#[derive(Packable)]
+#[packable(tag_type = u8)]
+pub enum Shape {
+    #[packable(tag = 0)]
+    Dot(u32, #[packable(skip)] Option<u64>),
+    #[packable(tag = 1)]
+    Line {
+        len: u32,
+        #[packable(skip)]
+        area: u64,
+    },
+}
+
+fn main() {}
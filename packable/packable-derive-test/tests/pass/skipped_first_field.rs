@@ -0,0 +1,34 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::cell::Cell;
+
+use packable::Packable;
+
+#[derive(Packable)]
+pub struct Memo {
+    #[packable(skip)]
+    hash: Cell<Option<u64>>,
+    value: u32,
+}
+
+#[derive(Packable)]
+pub struct Cache {
+    #[packable(skip)]
+    hash: Cell<Option<u64>>,
+}
+
+#[derive(Packable)]
+#[packable(tag_type = u8)]
+pub enum Entry {
+    #[packable(tag = 0)]
+    Memo {
+        #[packable(skip)]
+        hash: Cell<Option<u64>>,
+        value: u32,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{
+    error::{DelimitedLenError, UnpackErrorExt},
+    prefix::VecPrefix,
+    KnownTags, Packable,
+};
+
+#[derive(Packable)]
+#[packable(tag_type = u16)]
+#[packable(unpack_error = PayloadError)]
+pub enum Payload {
+    #[packable(tag = 0)]
+    Value(u32),
+    #[packable(tag = 1)]
+    Named { value: u64 },
+    #[packable(unknown)]
+    Unknown(u16, VecPrefix<u8, u32>),
+}
+
+#[derive(Debug)]
+pub enum PayloadError {
+    InvalidLen(DelimitedLenError),
+}
+
+impl From<DelimitedLenError> for PayloadError {
+    fn from(err: DelimitedLenError) -> Self {
+        Self::InvalidLen(err)
+    }
+}
+
+impl From<Infallible> for PayloadError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl Payload {
+    pub fn unknown(tag: u16, payload: VecPrefix<u8, u32>) -> Option<Self> {
+        (!Self::is_known_tag(&tag)).then_some(Self::Unknown(tag, payload))
+    }
+}
+
+fn main() {}
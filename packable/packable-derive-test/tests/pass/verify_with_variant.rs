@@ -0,0 +1,50 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![allow(unused_imports)]
+
+use core::convert::Infallible;
+
+use packable::{error::UnknownTagError, Packable};
+
+#[derive(Debug)]
+pub enum RangeError {
+    UnknownTag(u8),
+    Empty,
+    TooLong,
+}
+
+impl From<Infallible> for RangeError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+fn verify_bounds(value: &Range, _: &()) -> Result<(), RangeError> {
+    match value {
+        Range::Bounded { start, end } if start >= end => Err(RangeError::Empty),
+        _ => Ok(()),
+    }
+}
+
+fn verify_len(value: &Range, _: &()) -> Result<(), RangeError> {
+    match value {
+        Range::Bounded { start, end } if end - start > 100 => Err(RangeError::TooLong),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Packable)]
+#[packable(tag_type = u8, with_error = RangeError::UnknownTag)]
+#[packable(unpack_error = RangeError)]
+#[packable(verify_with = verify_len)]
+#[packable(unpack_visitor = ())]
+pub enum Range {
+    #[packable(tag = 0)]
+    Full,
+    #[packable(verify_with = verify_bounds)]
+    #[packable(tag = 1)]
+    Bounded { start: u32, end: u32 },
+}
+
+fn main() {}
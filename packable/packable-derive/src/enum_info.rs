@@ -1,22 +1,37 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use syn::{parse_quote, Attribute, DataEnum, Field, Ident, Result, Type};
+use syn::{parse::ParseStream, parse_quote, Attribute, DataEnum, Error, Field, Ident, Path, Result, Type};
 
 use crate::{
-    parse::filter_attrs, tag_type_info::TagTypeInfo, unpack_error_info::UnpackErrorInfo,
-    unpack_visitor_info::UnpackVisitorInfo, variant_info::VariantInfo,
+    field_info::FieldInfo,
+    parse::{filter_attrs, parse_kv, skip_stream},
+    tag_type_info::TagTypeInfo,
+    trait_info::TraitInfo,
+    unpack_error_info::UnpackErrorInfo,
+    unpack_visitor_info::UnpackVisitorInfo,
+    variant_info::{UnknownVariantInfo, VariantInfo},
 };
 
 pub(crate) struct EnumInfo {
     pub(crate) unpack_error: UnpackErrorInfo,
+    pub(crate) verify_with: Option<Path>,
     pub(crate) unpack_visitor: UnpackVisitorInfo,
     pub(crate) tag_type: TagTypeInfo,
     pub(crate) variants_info: Vec<VariantInfo>,
+    pub(crate) unknown_variant_info: Option<UnknownVariantInfo>,
 }
 
 impl EnumInfo {
-    pub(crate) fn new(ident: Ident, data: DataEnum, attrs: &[Attribute], crate_name: &Ident) -> Result<Self> {
+    pub(crate) fn new(
+        ident: Ident,
+        data: DataEnum,
+        attrs: &[Attribute],
+        crate_name: &Ident,
+        trait_info: &TraitInfo,
+    ) -> Result<Self> {
+        let trait_path = &trait_info.path;
+
         let repr_type = attrs
             .iter()
             .find(|attr| attr.path().is_ident("repr"))
@@ -28,19 +43,49 @@ impl EnumInfo {
         let tag_type = TagTypeInfo::new(&ident, filtered_attrs.clone(), &repr_type, crate_name)?;
         let tag_ty = &tag_type.tag_type;
 
-        let unpack_error = UnpackErrorInfo::new(
-            filtered_attrs.clone(),
-            || parse_quote!(#crate_name::error::UnknownTagError<#tag_ty>),
-        )?;
+        let mut known_variants = Vec::with_capacity(data.variants.len());
+        let mut unknown_variant = None;
+
+        for variant in &data.variants {
+            if !UnknownVariantInfo::is_unknown(variant)? {
+                known_variants.push(variant);
+            } else if unknown_variant.replace(variant).is_some() {
+                return Err(Error::new(
+                    variant.ident.span(),
+                    "Enums that derive `Packable` can have at most one `unknown` variant.",
+                ));
+            }
+        }
+
+        // Unknown tags cannot be found if there is an `unknown` variant, but payloads can have an invalid length.
+        let unpack_error = UnpackErrorInfo::new(filtered_attrs.clone(), || match unknown_variant {
+            Some(_) => parse_quote!(#crate_name::error::DelimitedLenError),
+            None => parse_quote!(#crate_name::error::UnknownTagError<#tag_ty>),
+        })?;
+
+        let mut verify_with_opt = None;
+
+        for attr in filtered_attrs.clone() {
+            if let Some(verify_with) = attr.parse_args_with(|stream: ParseStream| {
+                let opt = parse_kv("verify_with", stream)?;
+                if opt.is_none() {
+                    skip_stream(stream)?;
+                }
+                Ok(opt)
+            })? {
+                verify_with_opt = Some(verify_with);
+                break;
+            }
+        }
 
         let unpack_visitor = UnpackVisitorInfo::new(filtered_attrs, || {
-            let (unpack_visitor, explicit) = match data
-                .variants
-                .iter()
-                .next()
-                .and_then(|variant| variant.fields.iter().next())
-            {
-                Some(Field { ty, .. }) => (parse_quote!(<#ty as #crate_name::Packable>::UnpackVisitor), true),
+            let first_field = match data.variants.iter().next() {
+                Some(variant) => FieldInfo::first_unskipped(&variant.fields)?,
+                None => None,
+            };
+
+            let (unpack_visitor, explicit) = match first_field {
+                Some(Field { ty, .. }) => (parse_quote!(<#ty as #trait_path>::UnpackVisitor), true),
                 None => (parse_quote!(()), false),
             };
 
@@ -50,17 +95,22 @@ impl EnumInfo {
             })
         })?;
 
-        let variants_info = data
-            .variants
-            .iter()
+        let variants_info = known_variants
+            .into_iter()
             .map(|variant| VariantInfo::new(variant, &ident, &unpack_error.with))
             .collect::<Result<Vec<_>>>()?;
 
+        let unknown_variant_info = unknown_variant
+            .map(|variant| UnknownVariantInfo::new(variant, &ident, &unpack_error.with))
+            .transpose()?;
+
         Ok(Self {
             unpack_error,
+            verify_with: verify_with_opt,
             unpack_visitor,
             tag_type,
             variants_info,
+            unknown_variant_info,
         })
     }
 }
@@ -3,15 +3,30 @@
 
 use proc_macro2::Span;
 use quote::{format_ident, ToTokens};
-use syn::{parse::ParseStream, Expr, Field, Ident, Index, Path, Result, Type};
+use syn::{
+    ext::IdentExt, parse::ParseStream, parse_quote, spanned::Spanned, Error, Expr, Field, Ident, Index, Path, Result,
+    Type,
+};
 
-use crate::parse::{filter_attrs, parse_kv, skip_stream};
+use crate::parse::{filter_attrs, parse_flag, parse_kv, parse_kv_after_comma, skip_stream};
+
+const VALID_LENGTH_PREFIX_TYPES: &[&str] = &["u16", "u32"];
 
 pub(crate) enum IdentOrIndex {
     Ident(Ident),
     Index(Index),
 }
 
+impl IdentOrIndex {
+    /// Returns the name of the field as written in its path.
+    pub(crate) fn name(&self) -> String {
+        match self {
+            Self::Ident(ident) => ident.unraw().to_string(),
+            Self::Index(index) => index.index.to_string(),
+        }
+    }
+}
+
 impl ToTokens for IdentOrIndex {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         match self {
@@ -24,12 +39,43 @@ impl ToTokens for IdentOrIndex {
 pub(crate) struct FieldInfo {
     pub(crate) unpack_error_with: Expr,
     pub(crate) verify_with: Option<Path>,
+    pub(crate) length_prefix: Option<Type>,
+    pub(crate) pack_with: Option<Path>,
+    pub(crate) unpack_with: Option<Path>,
+    // The value of the field if it is skipped.
+    pub(crate) default: Option<Expr>,
     pub(crate) pattern_ident: IdentOrIndex,
     pub(crate) ident: Ident,
     pub(crate) ty: Type,
 }
 
 impl FieldInfo {
+    /// Returns `true` if the field has the `#[packable(skip)]` attribute.
+    pub(crate) fn is_skipped(field: &Field) -> Result<bool> {
+        for attr in filter_attrs(&field.attrs) {
+            if attr.parse_args_with(|stream: ParseStream| {
+                let found = parse_flag("skip", stream)?;
+                skip_stream(stream)?;
+                Ok(found)
+            })? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Returns the first field that is not skipped, from which the default unpack error and visitor types are taken.
+    pub(crate) fn first_unskipped<'a>(fields: impl IntoIterator<Item = &'a Field>) -> Result<Option<&'a Field>> {
+        for field in fields {
+            if !Self::is_skipped(field)? {
+                return Ok(Some(field));
+            }
+        }
+
+        Ok(None)
+    }
+
     pub(crate) fn new(field: &Field, default_unpack_error_with: &Expr, index: usize) -> Result<Self> {
         let pattern_ident = match &field.ident {
             Some(ident) => IdentOrIndex::Ident(ident.clone()),
@@ -43,6 +89,10 @@ impl FieldInfo {
 
         let mut unpack_error_with_opt = None;
         let mut verify_with_opt = None;
+        let mut length_prefix_opt = None;
+        let mut pack_with_opt = None;
+        let mut unpack_with_opt = None;
+        let mut default_opt = None;
 
         for attr in filter_attrs(&field.attrs) {
             if let Some(verify_with) = attr.parse_args_with(|stream: ParseStream| {
@@ -64,11 +114,111 @@ impl FieldInfo {
             })? {
                 unpack_error_with_opt = Some(unpack_error_with);
             }
+
+            if let Some(length_prefix) = attr.parse_args_with(|stream: ParseStream| {
+                let opt = parse_kv::<Type>("length_prefixed", stream)?;
+                if opt.is_none() {
+                    skip_stream(stream)?;
+                }
+                Ok(opt)
+            })? {
+                if !VALID_LENGTH_PREFIX_TYPES.contains(&length_prefix.to_token_stream().to_string().as_str()) {
+                    return Err(Error::new(
+                        length_prefix.span(),
+                        "Length prefixes for fields can only be of type `u16` or `u32`.",
+                    ));
+                }
+
+                length_prefix_opt = Some(length_prefix);
+            }
+
+            // `with = module` is a shorthand for `pack_with = module::pack, unpack_with = module::unpack`.
+            if let Some(with) = attr.parse_args_with(|stream: ParseStream| {
+                let opt = parse_kv::<Path>("with", stream)?;
+                if opt.is_none() {
+                    skip_stream(stream)?;
+                }
+                Ok(opt)
+            })? {
+                let mut pack_with = with.clone();
+                pack_with.segments.push(format_ident!("pack").into());
+                let mut unpack_with = with;
+                unpack_with.segments.push(format_ident!("unpack").into());
+
+                pack_with_opt = Some(pack_with);
+                unpack_with_opt = Some(unpack_with);
+            }
+
+            if let Some((pack_with, unpack_with)) =
+                attr.parse_args_with(|stream: ParseStream| match parse_kv::<Path>("pack_with", stream)? {
+                    Some(pack_with) => match parse_kv_after_comma::<Path>("unpack_with", stream)? {
+                        Some(unpack_with) => Ok(Some((pack_with, unpack_with))),
+                        None => Err(Error::new(
+                            pack_with.span(),
+                            "The `pack_with` attribute must be followed by an `unpack_with` attribute.",
+                        )),
+                    },
+                    None => {
+                        skip_stream(stream)?;
+                        Ok(None)
+                    }
+                })?
+            {
+                pack_with_opt = Some(pack_with);
+                unpack_with_opt = Some(unpack_with);
+            }
+
+            if let Some(default) = attr.parse_args_with(|stream: ParseStream| {
+                if parse_flag("skip", stream)? {
+                    match parse_kv_after_comma("default", stream)? {
+                        Some(default) => Ok(Some(default)),
+                        None => Ok(Some(parse_quote!(core::default::Default::default()))),
+                    }
+                } else {
+                    skip_stream(stream)?;
+                    Ok(None)
+                }
+            })? {
+                default_opt = Some(default);
+            }
+
+            if let Some(default) = attr.parse_args_with(|stream: ParseStream| {
+                let opt = parse_kv::<Expr>("default", stream)?;
+                if opt.is_none() {
+                    skip_stream(stream)?;
+                }
+                Ok(opt)
+            })? {
+                return Err(Error::new(
+                    default.span(),
+                    "The `default` attribute must follow a `skip` attribute.",
+                ));
+            }
+        }
+
+        if default_opt.is_some()
+            && (verify_with_opt.is_some() || length_prefix_opt.is_some() || pack_with_opt.is_some())
+        {
+            return Err(Error::new(
+                field.span(),
+                "Skipped fields cannot be verified, length-prefixed or have custom pack and unpack functions.",
+            ));
+        }
+
+        if let (Some(length_prefix), Some(_)) = (&length_prefix_opt, &pack_with_opt) {
+            return Err(Error::new(
+                length_prefix.span(),
+                "Fields with custom pack and unpack functions cannot be length-prefixed.",
+            ));
         }
 
         Ok(Self {
             unpack_error_with: unpack_error_with_opt.unwrap_or_else(|| default_unpack_error_with.clone()),
             verify_with: verify_with_opt,
+            length_prefix: length_prefix_opt,
+            pack_with: pack_with_opt,
+            unpack_with: unpack_with_opt,
+            default: default_opt,
             ident,
             pattern_ident,
             ty: field.ty.clone(),
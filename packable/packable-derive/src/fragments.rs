@@ -5,34 +5,195 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Ident, Path};
 
-use crate::{record_info::RecordInfo, unpack_visitor_info::UnpackVisitorInfo};
+use crate::{
+    field_info::IdentOrIndex, record_info::RecordInfo, trait_info::TraitInfo, unpack_visitor_info::UnpackVisitorInfo,
+};
 
 pub(crate) struct Fragments {
     // The pattern used to destructure the record.
     pub(crate) pattern: TokenStream,
     // An expression that packs the record.
     pub(crate) pack: TokenStream,
-    // An expression that unpacks the record.
+    // A sequence of statements that unpacks the record into a binding named `unpacked`.
     pub(crate) unpack: TokenStream,
+    // A sequence of statements that skips the record, if it can be done without unpacking it.
+    pub(crate) skip: Option<TokenStream>,
+    // A constant expression for the packed length of the record, if it is the same for all its values.
+    pub(crate) packed_len: TokenStream,
+    // A constant expression for the largest packed length of the record, if it is bounded.
+    pub(crate) max_packed_len: TokenStream,
+    // Constant expressions that fail to evaluate if the length prefix of a field cannot represent its packed length.
+    pub(crate) length_prefix_checks: Vec<TokenStream>,
+    // An expression for the packed length of the destructured record.
+    pub(crate) len: TokenStream,
 }
 
 impl Fragments {
     pub(crate) fn new(
         info: RecordInfo,
         verify_with: Option<Path>,
+        post_unpack: Option<Path>,
         unpack_visitor_info: &UnpackVisitorInfo,
         crate_name: &Ident,
+        trait_info: &TraitInfo,
     ) -> Self {
         let RecordInfo {
             path,
             fields_unpack_error_with,
             fields_verify_with,
+            fields_length_prefix,
+            fields_pack_with,
+            fields_unpack_with,
+            fields_default,
             fields_ident,
             fields_pattern_ident,
             fields_type,
         } = info;
 
-        let fields_verification = fields_verify_with.into_iter().zip(fields_ident.iter()).map(
+        let TraitInfo {
+            path: trait_path,
+            pack: pack_fn,
+            unpack: unpack_fn,
+            ..
+        } = trait_info;
+
+        // The fields of newtypes are not part of the path as they are the same value as the record itself.
+        let is_newtype = matches!(fields_pattern_ident.as_slice(), [IdentOrIndex::Index(_)]);
+        let (fields_push_path, fields_pop_path): (Vec<_>, Vec<_>) = fields_pattern_ident
+            .iter()
+            .zip(fields_default.iter())
+            .map(|(pattern_ident, default)| {
+                if is_newtype || default.is_some() {
+                    (quote!(), quote!())
+                } else {
+                    let name = pattern_ident.name();
+                    (
+                        quote!(#crate_name::unpacker::Unpacker::push_path(unpacker, #crate_name::unpacker::PathSegment::Field(#name));),
+                        quote!(#crate_name::unpacker::Unpacker::pop_path(unpacker);),
+                    )
+                }
+            })
+            .unzip();
+
+        // Skipped fields are set to their default value, fields with custom functions are unpacked with them and
+        // length-prefixed fields are unpacked from exactly the number of bytes given by their prefix.
+        let fields_unpack = (0..fields_ident.len())
+            .map(|index| {
+                let field_type = &fields_type[index];
+                let unpack_error_with = &fields_unpack_error_with[index];
+
+                if let Some(default) = &fields_default[index] {
+                    return quote!(#default);
+                }
+
+                match (&fields_unpack_with[index], &fields_length_prefix[index]) {
+                    (Some(unpack_with), _) => quote! {
+                        #unpack_with(unpacker, visitor.map(Borrow::borrow)).map_packable_err(#unpack_error_with).coerce()?
+                    },
+                    (None, Some(length_prefix)) => quote! {{
+                        let len = <#length_prefix as #crate_name::Packable>::unpack(unpacker, visitor.map(Borrow::<()>::borrow)).coerce()? as usize;
+                        #crate_name::unpack_length_prefixed::<#field_type, _>(unpacker, len, visitor.map(Borrow::<<#field_type as #trait_path>::UnpackVisitor>::borrow)).map_packable_err(#unpack_error_with).coerce()?.map_err(#crate_name::error::UnpackError::from_packable)?
+                    }},
+                    (None, None) => quote! {
+                        <#field_type as #trait_path>::#unpack_fn(unpacker, visitor.map(Borrow::<<#field_type as #trait_path>::UnpackVisitor>::borrow)).map_packable_err(#unpack_error_with).coerce()?
+                    },
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // The length prefix of a field must be able to represent the packed length of any value of that field, so
+        // packing it cannot fail.
+        let length_prefix_check = |field_type, length_prefix| {
+            quote! {
+                #crate_name::check_length_prefix(
+                    <#field_type as #crate_name::Packable>::MAX_PACKED_LEN,
+                    <#length_prefix as #crate_name::bounded::Bounded>::MAX_LEN,
+                )
+            }
+        };
+
+        let length_prefix_checks = (0..fields_ident.len())
+            .filter_map(|index| {
+                match (
+                    &fields_default[index],
+                    &fields_pack_with[index],
+                    &fields_length_prefix[index],
+                ) {
+                    (None, None, Some(length_prefix)) => Some(length_prefix_check(&fields_type[index], length_prefix)),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let fields_pack = (0..fields_ident.len()).map(|index| {
+            let field_ident = &fields_ident[index];
+            let field_type = &fields_type[index];
+
+            if fields_default[index].is_some() {
+                return quote!();
+            }
+
+            match (&fields_pack_with[index], &fields_length_prefix[index]) {
+                (Some(pack_with), _) => quote!(#pack_with(#field_ident, packer)?;),
+                (None, Some(length_prefix)) => {
+                    let length_prefix_check = length_prefix_check(field_type, length_prefix);
+
+                    quote! {
+                    const { #length_prefix_check };
+                    #crate_name::pack_length_prefix::<#length_prefix, _>(#crate_name::PackableExt::packed_len(#field_ident), packer)?;
+                    <#field_type as #trait_path>::#pack_fn(#field_ident, packer)?;
+                    }
+                }
+                (None, None) => quote!(<#field_type as #trait_path>::#pack_fn(#field_ident, packer)?;),
+            }
+        });
+
+        // The packed length of fields with custom functions is never assumed to be constant.
+        let fields_packed_len = (0..fields_ident.len()).map(|index| {
+            let field_type = &fields_type[index];
+
+            if fields_default[index].is_some() {
+                return quote!(Some(0));
+            }
+
+            match (&fields_pack_with[index], &fields_length_prefix[index]) {
+                (Some(_), _) => quote!(None),
+                (None, Some(length_prefix)) => quote!(#crate_name::sum_packed_len(&[<#length_prefix as #crate_name::Packable>::PACKED_LEN, <#field_type as #crate_name::Packable>::PACKED_LEN])),
+                (None, None) => quote!(<#field_type as #crate_name::Packable>::PACKED_LEN),
+            }
+        });
+
+        let fields_max_packed_len = (0..fields_ident.len()).map(|index| {
+            let field_type = &fields_type[index];
+
+            if fields_default[index].is_some() {
+                return quote!(Some(0));
+            }
+
+            match (&fields_pack_with[index], &fields_length_prefix[index]) {
+                (Some(_), _) => quote!(None),
+                (None, Some(length_prefix)) => quote!(#crate_name::sum_packed_len(&[<#length_prefix as #crate_name::Packable>::MAX_PACKED_LEN, <#field_type as #crate_name::Packable>::MAX_PACKED_LEN])),
+                (None, None) => quote!(<#field_type as #crate_name::Packable>::MAX_PACKED_LEN),
+            }
+        });
+
+        let fields_len = (0..fields_ident.len()).map(|index| {
+            let field_ident = &fields_ident[index];
+
+            if fields_default[index].is_some() {
+                return quote!(0);
+            }
+
+            match (&fields_pack_with[index], &fields_length_prefix[index]) {
+                (Some(pack_with), _) => quote!(#crate_name::packed_len_with(#field_ident, #pack_with)),
+                (None, Some(length_prefix)) => quote!(#crate_name::length_prefixed_len::<#length_prefix>(
+                    #crate_name::PackableExt::packed_len(#field_ident)
+                )),
+                (None, None) => quote!(#crate_name::PackableExt::packed_len(#field_ident)),
+            }
+        });
+
+        let fields_verification = fields_verify_with.iter().zip(fields_ident.iter()).map(
             |(verify_with, field_ident)| match verify_with {
                 Some(verify_with) => if unpack_visitor_info.explicit {
                     quote! {
@@ -49,47 +210,117 @@ impl Fragments {
                 },
                 None => quote!(),
             },
-        );
+        ).collect::<Vec<_>>();
 
-        let verify_with = match verify_with {
-            Some(verify_with) => {
-                if unpack_visitor_info.explicit {
-                    quote! {
-                        if let Some(visitor) = visitor {
-                            #verify_with(&unpacked, visitor).map_err(#crate_name::error::UnpackError::from_packable)?;
+        // Fields that must be verified have to be unpacked, the rest can be skipped. Types that borrow from the
+        // unpacker and records that are verified as a whole cannot be skipped.
+        let skip = (trait_info.lifetime.is_none() && verify_with.is_none()).then(|| {
+            let fields_skip = (0..fields_ident.len()).map(|index| {
+                let field_ident = &fields_ident[index];
+                let field_type = &fields_type[index];
+                let unpack_error_with = &fields_unpack_error_with[index];
+
+                if fields_default[index].is_some() {
+                    return quote!();
+                }
+
+                match (&fields_verify_with[index], &fields_unpack_with[index], &fields_length_prefix[index]) {
+                    (Some(_), _, _) => {
+                        let unpack = &fields_unpack[index];
+                        let verification = &fields_verification[index];
+
+                        quote! {
+                            let #field_ident = #unpack;
+                            #verification
                         }
                     }
-                } else {
-                    quote! {
-                        if visitor.is_some() {
-                            #verify_with(&unpacked).map_err(#crate_name::error::UnpackError::from_packable)?;
-                        }
+                    // Fields with custom functions can only be skipped by unpacking them.
+                    (None, Some(_), _) => {
+                        let unpack = &fields_unpack[index];
+
+                        quote!(let _ = #unpack;)
                     }
+                    // The bytes of length-prefixed fields can be skipped without knowing their layout.
+                    (None, None, Some(length_prefix)) => quote! {
+                        let len = <#length_prefix as #crate_name::Packable>::unpack(unpacker, visitor.map(Borrow::<()>::borrow)).coerce()? as usize;
+                        #crate_name::unpacker::Unpacker::skip_bytes(unpacker, len)?;
+                    },
+                    (None, None, None) => quote! {
+                        <#field_type as #trait_path>::skip(unpacker, visitor.map(Borrow::<<#field_type as #trait_path>::UnpackVisitor>::borrow)).map_packable_err(#unpack_error_with).coerce()?;
+                    },
                 }
-            }
-            None => quote!(),
+            });
+
+            quote!(#(#fields_skip)*)
+        });
+
+        let verify_with = verify_with
+            .map(|verify_with| verification(&verify_with, unpack_visitor_info, crate_name))
+            .unwrap_or_default();
+
+        let (unpacked, post_unpack) = match post_unpack {
+            Some(post_unpack) => (quote!(mut unpacked), quote!(#post_unpack(&mut unpacked);)),
+            None => (quote!(unpacked), quote!()),
         };
 
+        // Skipped fields are not bound by the pattern as they are not packed.
+        let fields_binding =
+            fields_ident
+                .iter()
+                .zip(fields_default.iter())
+                .map(|(field_ident, default)| match default {
+                    Some(_) => quote!(_),
+                    None => quote!(#field_ident),
+                });
+
         Self {
-            pattern: quote!(#path { #(#fields_pattern_ident: #fields_ident),* }),
+            pattern: quote!(#path { #(#fields_pattern_ident: #fields_binding),* }),
             pack: quote! {
-                #(<#fields_type as #crate_name::Packable>::pack(#fields_ident, packer)?;) *
+                #(#fields_pack)*
                 Ok(())
             },
             unpack: quote! {
                 #(
-                    let #fields_ident = <#fields_type as #crate_name::Packable>::unpack(unpacker, visitor.map(Borrow::<<#fields_type as #crate_name::Packable>::UnpackVisitor>::borrow)).map_packable_err(#fields_unpack_error_with).coerce()?;
+                    #fields_push_path
+                    let #fields_ident = #fields_unpack;
                     #fields_verification
+                    #fields_pop_path
                 )*
 
-                let unpacked = #path {
+                let #unpacked = #path {
                     #(#fields_pattern_ident: #fields_ident,)*
                 };
 
+                #post_unpack
                 #verify_with
-
-                Ok(unpacked)
             },
+            skip,
+            packed_len: quote!(#crate_name::sum_packed_len(&[#(#fields_packed_len),*])),
+            max_packed_len: quote!(#crate_name::sum_packed_len(&[#(#fields_max_packed_len),*])),
+            length_prefix_checks,
+            len: quote!(0 #(+ #fields_len)*),
+        }
+    }
+}
+
+/// Generates the statements that verify the value bound to `unpacked` using the `verify_with` function of a type or
+/// one of its variants.
+pub(crate) fn verification(
+    verify_with: &Path,
+    unpack_visitor_info: &UnpackVisitorInfo,
+    crate_name: &Ident,
+) -> TokenStream {
+    if unpack_visitor_info.explicit {
+        quote! {
+            if let Some(visitor) = visitor {
+                #verify_with(&unpacked, visitor).map_err(#crate_name::error::UnpackError::from_packable)?;
+            }
+        }
+    } else {
+        quote! {
+            if visitor.is_some() {
+                #verify_with(&unpacked).map_err(#crate_name::error::UnpackError::from_packable)?;
+            }
         }
     }
 }
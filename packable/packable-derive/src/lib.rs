@@ -11,6 +11,7 @@ mod record_info;
 mod struct_info;
 mod tag_type_info;
 mod trait_impl;
+mod trait_info;
 mod unpack_error_info;
 mod unpack_visitor_info;
 mod variant_info;
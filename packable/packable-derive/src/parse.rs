@@ -32,6 +32,13 @@ pub(crate) fn parse_kv<T: Parse>(ident: &'static str, stream: ParseStream) -> Re
     }
 }
 
+pub(crate) fn parse_flag(ident: &'static str, stream: ParseStream) -> Result<bool> {
+    let found_ident = stream.parse::<Ident>()?;
+    validate_ident(&found_ident)?;
+
+    Ok(found_ident == ident)
+}
+
 pub(crate) fn parse_kv_after_comma<T: Parse>(ident: &'static str, stream: ParseStream) -> Result<Option<T>> {
     if stream.is_empty() {
         return Ok(None);
@@ -50,6 +57,13 @@ fn validate_ident(ident: &Ident) -> Result<()> {
         "unpack_visitor",
         "tag_type",
         "tag",
+        "unknown",
+        "length_prefixed",
+        "pack_with",
+        "unpack_with",
+        "skip",
+        "default",
+        "post_unpack",
         "with",
         "with_error",
         "verify_with",
@@ -1,14 +1,21 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use syn::{Expr, Fields, Ident, Path, Result, Type};
+use syn::{spanned::Spanned, Error, Expr, Fields, Ident, Path, Result, Type};
 
-use crate::field_info::{FieldInfo, IdentOrIndex};
+use crate::{
+    field_info::{FieldInfo, IdentOrIndex},
+    trait_info::TraitInfo,
+};
 
 pub(crate) struct RecordInfo {
     pub(crate) path: Path,
     pub(crate) fields_unpack_error_with: Vec<Expr>,
     pub(crate) fields_verify_with: Vec<Option<Path>>,
+    pub(crate) fields_length_prefix: Vec<Option<Type>>,
+    pub(crate) fields_pack_with: Vec<Option<Path>>,
+    pub(crate) fields_unpack_with: Vec<Option<Path>>,
+    pub(crate) fields_default: Vec<Option<Expr>>,
     pub(crate) fields_pattern_ident: Vec<IdentOrIndex>,
     pub(crate) fields_ident: Vec<Ident>,
     pub(crate) fields_type: Vec<Type>,
@@ -19,6 +26,10 @@ impl RecordInfo {
         let len = fields.len();
         let mut fields_unpack_error_with = Vec::with_capacity(len);
         let mut fields_verify_with = Vec::with_capacity(len);
+        let mut fields_length_prefix = Vec::with_capacity(len);
+        let mut fields_pack_with = Vec::with_capacity(len);
+        let mut fields_unpack_with = Vec::with_capacity(len);
+        let mut fields_default = Vec::with_capacity(len);
         let mut fields_ident = Vec::with_capacity(len);
         let mut fields_pattern_ident = Vec::with_capacity(len);
         let mut fields_type = Vec::with_capacity(len);
@@ -27,6 +38,10 @@ impl RecordInfo {
             let FieldInfo {
                 unpack_error_with,
                 verify_with,
+                length_prefix,
+                pack_with,
+                unpack_with,
+                default,
                 ident,
                 pattern_ident,
                 ty,
@@ -34,6 +49,10 @@ impl RecordInfo {
 
             fields_unpack_error_with.push(unpack_error_with);
             fields_verify_with.push(verify_with);
+            fields_length_prefix.push(length_prefix);
+            fields_pack_with.push(pack_with);
+            fields_unpack_with.push(unpack_with);
+            fields_default.push(default);
             fields_ident.push(ident);
             fields_pattern_ident.push(pattern_ident);
             fields_type.push(ty);
@@ -43,9 +62,24 @@ impl RecordInfo {
             path,
             fields_unpack_error_with,
             fields_verify_with,
+            fields_length_prefix,
+            fields_pack_with,
+            fields_unpack_with,
+            fields_default,
             fields_pattern_ident,
             fields_ident,
             fields_type,
         })
     }
+
+    /// Fails if the record cannot be derived for the given trait.
+    pub(crate) fn check_trait(&self, trait_info: &TraitInfo) -> Result<()> {
+        match (&trait_info.lifetime, self.fields_length_prefix.iter().flatten().next()) {
+            (Some(_), Some(length_prefix)) => Err(Error::new(
+                length_prefix.span(),
+                "Fields of types with a lifetime parameter cannot be length-prefixed.",
+            )),
+            _ => Ok(()),
+        }
+    }
 }
@@ -1,11 +1,13 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use syn::{parse::ParseStream, parse_quote, Attribute, Field, Fields, Ident, Path, Result};
+use syn::{parse::ParseStream, parse_quote, Attribute, Field, Fields, Path, Result};
 
 use crate::{
+    field_info::FieldInfo,
     parse::{filter_attrs, parse_kv, skip_stream},
     record_info::RecordInfo,
+    trait_info::TraitInfo,
     unpack_error_info::UnpackErrorInfo,
     unpack_visitor_info::UnpackVisitorInfo,
 };
@@ -13,16 +15,22 @@ use crate::{
 pub(crate) struct StructInfo {
     pub(crate) unpack_error: UnpackErrorInfo,
     pub(crate) verify_with: Option<Path>,
+    pub(crate) post_unpack: Option<Path>,
     pub(crate) unpack_visitor: UnpackVisitorInfo,
     pub(crate) inner: RecordInfo,
 }
 
 impl StructInfo {
-    pub(crate) fn new(path: Path, fields: &Fields, attrs: &[Attribute], crate_name: &Ident) -> Result<Self> {
+    pub(crate) fn new(path: Path, fields: &Fields, attrs: &[Attribute], trait_info: &TraitInfo) -> Result<Self> {
+        let trait_path = &trait_info.path;
+
         let filtered_attrs = filter_attrs(attrs);
 
-        let unpack_error = UnpackErrorInfo::new(filtered_attrs.clone(), || match fields.iter().next() {
-            Some(Field { ty, .. }) => parse_quote!(<#ty as #crate_name::Packable>::UnpackError),
+        // Skipped fields are not unpacked, so the defaults are taken from the first field that is.
+        let first_field = FieldInfo::first_unskipped(fields)?;
+
+        let unpack_error = UnpackErrorInfo::new(filtered_attrs.clone(), || match first_field {
+            Some(Field { ty, .. }) => parse_quote!(<#ty as #trait_path>::UnpackError),
             None => parse_quote!(core::convert::Infallible),
         })?;
 
@@ -41,8 +49,23 @@ impl StructInfo {
             }
         }
 
+        let mut post_unpack_opt = None;
+
+        for attr in filtered_attrs.clone() {
+            if let Some(post_unpack) = attr.parse_args_with(|stream: ParseStream| {
+                let opt = parse_kv("post_unpack", stream)?;
+                if opt.is_none() {
+                    skip_stream(stream)?;
+                }
+                Ok(opt)
+            })? {
+                post_unpack_opt = Some(post_unpack);
+                break;
+            }
+        }
+
         let unpack_visitor = UnpackVisitorInfo::new(filtered_attrs, || {
-            let (unpack_visitor, explicit) = match fields.iter().next() {
+            let (unpack_visitor, explicit) = match first_field {
                 Some(Field { attrs, ty, .. }) => {
                     let mut explicit = false;
 
@@ -61,7 +84,7 @@ impl StructInfo {
                         }
                     }
 
-                    (parse_quote!(<#ty as #crate_name::Packable>::UnpackVisitor), explicit)
+                    (parse_quote!(<#ty as #trait_path>::UnpackVisitor), explicit)
                 }
                 None => (parse_quote!(()), false),
             };
@@ -77,6 +100,7 @@ impl StructInfo {
         Ok(Self {
             unpack_error,
             verify_with: verify_with_opt,
+            post_unpack: post_unpack_opt,
             unpack_visitor,
             inner,
         })
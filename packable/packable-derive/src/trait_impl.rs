@@ -6,8 +6,12 @@ use quote::{format_ident, quote, ToTokens};
 use syn::{spanned::Spanned, Data, DeriveInput, Generics, Ident};
 
 use crate::{
-    enum_info::EnumInfo, fragments::Fragments, struct_info::StructInfo, tag_type_info::TagTypeInfo,
-    variant_info::VariantInfo,
+    enum_info::EnumInfo,
+    fragments::{self, Fragments},
+    struct_info::StructInfo,
+    tag_type_info::TagTypeInfo,
+    trait_info::TraitInfo,
+    variant_info::{UnknownVariantInfo, VariantInfo},
 };
 
 pub(crate) struct TraitImpl {
@@ -17,20 +21,46 @@ pub(crate) struct TraitImpl {
     unpack_visitor: TokenStream,
     pack: TokenStream,
     unpack: TokenStream,
+    skip: Option<TokenStream>,
+    packed_len: TokenStream,
+    max_packed_len: TokenStream,
+    length_prefix_checks: Vec<TokenStream>,
+    known_tags: Option<TokenStream>,
     crate_name: Ident,
+    trait_info: TraitInfo,
 }
 
 impl TraitImpl {
     pub(crate) fn new(input: DeriveInput, crate_name: Ident) -> syn::Result<Self> {
+        let trait_info = TraitInfo::new(&input.generics, &crate_name)?;
+
         match input.data {
             Data::Struct(data) => {
-                let info = StructInfo::new(input.ident.clone().into(), &data.fields, &input.attrs, &crate_name)?;
+                let info = StructInfo::new(input.ident.clone().into(), &data.fields, &input.attrs, &trait_info)?;
+                info.inner.check_trait(&trait_info)?;
 
                 let unpack_error = info.unpack_error.unpack_error.clone().into_token_stream();
                 let unpack_visitor = info.unpack_visitor.unpack_visitor.clone().into_token_stream();
 
-                let Fragments { pattern, pack, unpack } =
-                    Fragments::new(info.inner, info.verify_with, &info.unpack_visitor, &crate_name);
+                let Fragments {
+                    pattern,
+                    pack,
+                    unpack,
+                    skip,
+                    packed_len,
+                    max_packed_len,
+                    length_prefix_checks,
+                    ..
+                } = Fragments::new(
+                    info.inner,
+                    info.verify_with,
+                    info.post_unpack,
+                    &info.unpack_visitor,
+                    &crate_name,
+                    &trait_info,
+                );
+
+                let type_name = input.ident.to_string();
 
                 Ok(Self {
                     ident: input.ident,
@@ -41,13 +71,41 @@ impl TraitImpl {
                         let #pattern = self;
                         #pack
                     },
-                    unpack,
+                    unpack: quote! {
+                        #crate_name::unpacker::Unpacker::push_path(unpacker, #crate_name::unpacker::PathSegment::Type(#type_name));
+                        #unpack
+                        #crate_name::unpacker::Unpacker::pop_path(unpacker);
+                        Ok(unpacked)
+                    },
+                    skip: skip.map(|skip| {
+                        quote! {
+                            #skip
+                            Ok(())
+                        }
+                    }),
+                    packed_len,
+                    max_packed_len,
+                    length_prefix_checks,
+                    known_tags: None,
                     crate_name,
+                    trait_info,
                 })
             }
             Data::Enum(data) => {
                 let enum_ident = &input.ident;
-                let info = EnumInfo::new(enum_ident.clone(), data, &input.attrs, &crate_name)?;
+                let enum_name = enum_ident.to_string();
+                let info = EnumInfo::new(enum_ident.clone(), data, &input.attrs, &crate_name, &trait_info)?;
+
+                // The payloads of the variants are prefixed by their length if there is an `unknown` variant, so the
+                // payloads of the variants that are not known can be captured.
+                let delimited = info.unknown_variant_info.is_some();
+
+                if delimited && trait_info.lifetime.is_some() {
+                    return Err(syn::Error::new(
+                        enum_ident.span(),
+                        "Enums with an `unknown` variant cannot have a lifetime parameter.",
+                    ));
+                }
 
                 let TagTypeInfo {
                     tag_type,
@@ -57,38 +115,109 @@ impl TraitImpl {
                 let unpack_error = info.unpack_error.unpack_error.into_token_stream();
                 let unpack_visitor = info.unpack_visitor.unpack_visitor.clone().into_token_stream();
 
+                let verification = info
+                    .verify_with
+                    .as_ref()
+                    .map(|verify_with| fragments::verification(verify_with, &info.unpack_visitor, &crate_name));
+
                 let len = info.variants_info.len();
                 let mut pack_arms = Vec::with_capacity(len);
                 let mut unpack_arms = Vec::with_capacity(len);
+                let mut skip_arms = Vec::with_capacity(len);
+                let mut variants_packed_len = Vec::with_capacity(len);
+                let mut variants_max_packed_len = Vec::with_capacity(len);
+                let mut length_prefix_checks = Vec::new();
                 let mut tag_decls = Vec::with_capacity(len);
+                let mut tags = Vec::with_capacity(len);
                 let mut tag_variants_and_idents = Vec::with_capacity(len);
 
-                for (index, VariantInfo { tag, inner }) in info.variants_info.into_iter().enumerate() {
+                for (
+                    index,
+                    VariantInfo {
+                        tag,
+                        verify_with,
+                        inner,
+                    },
+                ) in info.variants_info.into_iter().enumerate()
+                {
+                    inner.check_trait(&trait_info)?;
+
                     let variant_ident = inner.path.segments.last().unwrap().clone();
+                    let variant_name = variant_ident.ident.to_string();
 
-                    let Fragments { pattern, pack, unpack } =
-                        Fragments::new(inner, None, &info.unpack_visitor, &crate_name);
+                    let Fragments {
+                        pattern,
+                        pack,
+                        unpack,
+                        skip,
+                        packed_len,
+                        max_packed_len,
+                        length_prefix_checks: variant_length_prefix_checks,
+                        len: payload_len,
+                    } = Fragments::new(inner, verify_with, None, &info.unpack_visitor, &crate_name, &trait_info);
 
                     // @pvdrz: The span here is very important, otherwise the compiler won't detect
                     // unreachable patterns in the generated code for some reason. I think this is related
                     // to `https://github.com/rust-lang/rust/pull/80632`
                     let tag_ident = format_ident!("__TAG_{}", index, span = tag.span());
 
+                    let pack_payload_len =
+                        delimited.then(|| quote!(#crate_name::pack_delimited_len(#payload_len, packer)?;));
+
                     pack_arms.push(quote!(#pattern => {
                         <#tag_type as #crate_name::Packable>::pack(&#tag, packer)?;
+                        #pack_payload_len
                         #pack
                     }));
 
+                    // Delimited payloads are unpacked through an unpacker confined to their length, so reading
+                    // less or more bytes than the length prefix fails without consuming the bytes of the next value.
+                    let unpack = if delimited {
+                        quote! {
+                            let unpacked = #crate_name::unpack_delimited::<_, Self::UnpackError, _, _>(unpacker, len, |unpacker| {
+                                #unpack
+                                Ok(unpacked)
+                            })?.map_err(#crate_name::error::UnpackError::from_packable)?;
+                        }
+                    } else {
+                        unpack
+                    };
+
                     unpack_arms.push(quote!(#tag_ident => {
+                        #crate_name::unpacker::Unpacker::push_path(unpacker, #crate_name::unpacker::PathSegment::Variant(#variant_name));
                         #unpack
+                        #crate_name::unpacker::Unpacker::pop_path(unpacker);
+                        unpacked
                     }));
 
+                    variants_packed_len.push(packed_len);
+                    variants_max_packed_len.push(max_packed_len);
+                    length_prefix_checks.extend(variant_length_prefix_checks);
+
+                    if let Some(skip) = skip {
+                        let skip = if delimited {
+                            quote! {
+                                #crate_name::unpack_delimited::<_, Self::UnpackError, _, _>(unpacker, len, |unpacker| {
+                                    #skip
+                                    Ok(())
+                                })?.map_err(#crate_name::error::UnpackError::from_packable)?;
+                            }
+                        } else {
+                            skip
+                        };
+
+                        skip_arms.push(quote!(#tag_ident => {
+                            #skip
+                        }));
+                    }
+
                     tag_decls.push(quote!(const #tag_ident: #tag_type = #tag;));
+                    tags.push(tag);
 
                     tag_variants_and_idents.push((tag_ident, variant_ident));
                 }
 
-                let mut tag_asserts = Vec::with_capacity(len * (len - 1) / 2);
+                let mut tag_asserts = Vec::with_capacity(len * len.saturating_sub(1) / 2);
 
                 for (index, (fst, fst_variant)) in tag_variants_and_idents.iter().enumerate() {
                     if let Some(idents_and_variants) = tag_variants_and_idents.get((index + 1)..) {
@@ -101,6 +230,76 @@ impl TraitImpl {
                     }
                 }
 
+                let known_tags = delimited.then(|| {
+                    quote! {
+                        type Tag = #tag_type;
+
+                        const KNOWN_TAGS: &'static [#tag_type] = &[#(#tags),*];
+                    }
+                });
+
+                let (unpack_fallback_arm, skip_fallback_arm, unpack_payload_len, packed_len, max_packed_len) =
+                    match info.unknown_variant_info {
+                        Some(UnknownVariantInfo { inner }) => {
+                            let path = &inner.path;
+                            let variant_name = path.segments.last().unwrap().ident.to_string();
+                            let [tag_pattern_ident, payload_pattern_ident] = &inner.fields_pattern_ident[..] else {
+                                unreachable!("the `unknown` variant has exactly two fields")
+                            };
+
+                            // An `unknown` variant with the tag of a known variant would be unpacked as that variant.
+                            pack_arms.push(
+                                quote!(#path { #tag_pattern_ident: tag, #payload_pattern_ident: payload } => {
+                                    debug_assert!(
+                                        !<Self as #crate_name::KnownTags>::is_known_tag(tag),
+                                        "the tag of an `unknown` variant cannot be the tag of a known variant"
+                                    );
+                                    <#tag_type as #crate_name::Packable>::pack(tag, packer)?;
+                                    #crate_name::pack_unknown_payload(payload, packer)
+                                }),
+                            );
+
+                            (
+                                quote!(tag => {
+                                    #crate_name::unpacker::Unpacker::push_path(unpacker, #crate_name::unpacker::PathSegment::Variant(#variant_name));
+                                    let payload = #crate_name::unpack_unknown_payload(unpacker, len).map_err(#crate_name::error::UnpackError::Unpacker)?;
+                                    #crate_name::unpacker::Unpacker::pop_path(unpacker);
+                                    #path { #tag_pattern_ident: tag, #payload_pattern_ident: payload }
+                                }),
+                                quote!(_ => #crate_name::unpacker::Unpacker::skip_bytes(unpacker, len).map_err(#crate_name::error::UnpackError::Unpacker)?,),
+                                quote! {
+                                    let len = #crate_name::unpack_delimited_len(unpacker).map_err(#crate_name::error::UnpackError::Unpacker)?;
+                                },
+                                quote!(None),
+                                // The payload of the `unknown` variant can be at most `u32::MAX` bytes long.
+                                quote! {
+                                    #crate_name::sum_packed_len(&[
+                                        <#tag_type as #crate_name::Packable>::MAX_PACKED_LEN,
+                                        Some(4),
+                                        #crate_name::max_packed_len(&[#(#variants_max_packed_len,)* Some(u32::MAX as usize)]),
+                                    ])
+                                },
+                            )
+                        }
+                        None => (
+                            quote!(tag => return Err(#crate_name::error::UnpackError::from_packable(#tag_with_error(tag)))),
+                            quote!(tag => return Err(#crate_name::error::UnpackError::from_packable(#tag_with_error(tag)))),
+                            quote!(),
+                            quote! {
+                                #crate_name::sum_packed_len(&[
+                                    <#tag_type as #crate_name::Packable>::PACKED_LEN,
+                                    #crate_name::same_packed_len(&[#(#variants_packed_len),*]),
+                                ])
+                            },
+                            quote! {
+                                #crate_name::sum_packed_len(&[
+                                    <#tag_type as #crate_name::Packable>::MAX_PACKED_LEN,
+                                    #crate_name::max_packed_len(&[#(#variants_max_packed_len),*]),
+                                ])
+                            },
+                        ),
+                    };
+
                 Ok(Self {
                     ident: enum_ident.clone(),
                     generics: input.generics,
@@ -113,12 +312,43 @@ impl TraitImpl {
                         #(#tag_decls)*
                         #(#tag_asserts)*
 
-                        match <#tag_type as #crate_name::Packable>::unpack(unpacker, visitor.map(Borrow::<<#tag_type as #crate_name::Packable>::UnpackVisitor>::borrow)).coerce()? {
+                        #crate_name::unpacker::Unpacker::push_path(unpacker, #crate_name::unpacker::PathSegment::Type(#enum_name));
+
+                        let tag = <#tag_type as #crate_name::Packable>::unpack(unpacker, visitor.map(Borrow::<<#tag_type as #crate_name::Packable>::UnpackVisitor>::borrow)).coerce()?;
+                        #unpack_payload_len
+
+                        let unpacked = match tag {
                             #(#unpack_arms)*
-                            tag => Err(#crate_name::error::UnpackError::from_packable(#tag_with_error(tag)))
-                        }
+                            #unpack_fallback_arm
+                        };
+
+                        #verification
+
+                        #crate_name::unpacker::Unpacker::pop_path(unpacker);
+
+                        Ok(unpacked)
                     },
+                    skip: (skip_arms.len() == len && info.verify_with.is_none()).then(|| {
+                        quote! {
+                            #(#tag_decls)*
+
+                            let tag = <#tag_type as #crate_name::Packable>::unpack(unpacker, visitor.map(Borrow::<<#tag_type as #crate_name::Packable>::UnpackVisitor>::borrow)).coerce()?;
+                            #unpack_payload_len
+
+                            match tag {
+                                #(#skip_arms)*
+                                #skip_fallback_arm
+                            }
+
+                            Ok(())
+                        }
+                    }),
+                    packed_len,
+                    max_packed_len,
+                    length_prefix_checks,
+                    known_tags,
                     crate_name,
+                    trait_info,
                 })
             }
             Data::Union(_) => Err(syn::Error::new(
@@ -138,29 +368,87 @@ impl ToTokens for TraitImpl {
             unpack_visitor,
             pack,
             unpack,
+            skip,
+            packed_len,
+            max_packed_len,
+            length_prefix_checks,
+            known_tags,
             crate_name,
+            trait_info,
         } = &self;
 
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-        let impl_tokens = quote! {
-            impl #impl_generics #crate_name::Packable for #type_name #ty_generics #where_clause {
-                type UnpackError = #unpack_error;
-                type UnpackVisitor = #unpack_visitor;
-
-                fn pack<P: #crate_name::packer::Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
-                    use #crate_name::error::UnpackErrorExt;
-                    #pack
-                }
-
-                fn unpack<U: #crate_name::unpacker::Unpacker>(unpacker: &mut U, visitor: Option<&Self::UnpackVisitor>) -> Result<Self, #crate_name::error::UnpackError<Self::UnpackError, U::Error>> {
+        let skip = skip.as_ref().map(|skip| {
+            quote! {
+                fn skip<U: #crate_name::unpacker::Unpacker>(unpacker: &mut U, visitor: Option<&Self::UnpackVisitor>) -> Result<(), #crate_name::error::UnpackError<Self::UnpackError, U::Error>> {
                     use #crate_name::error::UnpackErrorExt;
                     use core::borrow::Borrow;
-                    #unpack
+                    #skip
                 }
             }
+        });
+
+        let impl_tokens = match &trait_info.lifetime {
+            None => quote! {
+                impl #impl_generics #crate_name::Packable for #type_name #ty_generics #where_clause {
+                    type UnpackError = #unpack_error;
+                    type UnpackVisitor = #unpack_visitor;
+
+                    const PACKED_LEN: Option<usize> = #packed_len;
+
+                    const MAX_PACKED_LEN: Option<usize> = #max_packed_len;
+
+                    fn pack<P: #crate_name::packer::Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                        use #crate_name::error::UnpackErrorExt;
+                        #pack
+                    }
+
+                    fn unpack<U: #crate_name::unpacker::Unpacker>(unpacker: &mut U, visitor: Option<&Self::UnpackVisitor>) -> Result<Self, #crate_name::error::UnpackError<Self::UnpackError, U::Error>> {
+                        use #crate_name::error::UnpackErrorExt;
+                        use core::borrow::Borrow;
+                        #unpack
+                    }
+
+                    #skip
+                }
+            },
+            Some(lifetime) => quote! {
+                impl #impl_generics #crate_name::UnpackBorrowed<#lifetime> for #type_name #ty_generics #where_clause {
+                    type UnpackError = #unpack_error;
+                    type UnpackVisitor = #unpack_visitor;
+
+                    fn pack_borrowed<P: #crate_name::packer::Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                        use #crate_name::error::UnpackErrorExt;
+                        #pack
+                    }
+
+                    fn unpack_borrowed(unpacker: &mut #crate_name::unpacker::SliceUnpacker<#lifetime>, visitor: Option<&Self::UnpackVisitor>) -> Result<Self, #crate_name::error::UnpackError<Self::UnpackError, #crate_name::error::UnexpectedEOF>> {
+                        use #crate_name::error::UnpackErrorExt;
+                        use core::borrow::Borrow;
+                        #unpack
+                    }
+                }
+            },
         };
 
         impl_tokens.to_tokens(tokens);
+
+        if let Some(known_tags) = known_tags {
+            quote! {
+                impl #impl_generics #crate_name::KnownTags for #type_name #ty_generics #where_clause {
+                    #known_tags
+                }
+            }
+            .to_tokens(tokens);
+        }
+
+        // The length prefixes of types without generic parameters are checked even if they are never packed. The
+        // checks of generic types are evaluated when `pack` is instantiated.
+        if generics.type_params().next().is_none() && generics.const_params().next().is_none() {
+            for length_prefix_check in length_prefix_checks {
+                quote!(const _: () = #length_prefix_check;).to_tokens(tokens);
+            }
+        }
     }
 }
@@ -0,0 +1,46 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use proc_macro2::Span;
+use syn::{parse_quote, spanned::Spanned, Error, Generics, Ident, Lifetime, Path, Result};
+
+/// The trait implemented by the derive macro.
+pub(crate) struct TraitInfo {
+    // The path to the trait, either `Packable` or `UnpackBorrowed<'a>`.
+    pub(crate) path: Path,
+    // The name of the packing method.
+    pub(crate) pack: Ident,
+    // The name of the unpacking method.
+    pub(crate) unpack: Ident,
+    // The lifetime of the unpacked data if the type borrows from the unpacker.
+    pub(crate) lifetime: Option<Lifetime>,
+}
+
+impl TraitInfo {
+    pub(crate) fn new(generics: &Generics, crate_name: &Ident) -> Result<Self> {
+        let mut lifetimes = generics.lifetimes();
+
+        match (lifetimes.next(), lifetimes.next()) {
+            (None, _) => Ok(Self {
+                path: parse_quote!(#crate_name::Packable),
+                pack: Ident::new("pack", Span::call_site()),
+                unpack: Ident::new("unpack", Span::call_site()),
+                lifetime: None,
+            }),
+            (Some(param), None) => {
+                let lifetime = &param.lifetime;
+
+                Ok(Self {
+                    path: parse_quote!(#crate_name::UnpackBorrowed<#lifetime>),
+                    pack: Ident::new("pack_borrowed", Span::call_site()),
+                    unpack: Ident::new("unpack_borrowed", Span::call_site()),
+                    lifetime: Some(lifetime.clone()),
+                })
+            }
+            (Some(_), Some(param)) => Err(Error::new(
+                param.span(),
+                "Types that derive `Packable` can have at most one lifetime parameter.",
+            )),
+        }
+    }
+}
@@ -5,11 +5,11 @@ use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::{
     parse::{Parse, ParseStream},
-    parse_quote, Error, Expr, ExprLit, ExprPath, Result, Variant,
+    parse_quote, Error, Expr, ExprLit, ExprPath, Fields, Path, Result, Variant,
 };
 
 use crate::{
-    parse::{filter_attrs, parse_kv},
+    parse::{filter_attrs, parse_flag, parse_kv, skip_stream},
     record_info::RecordInfo,
 };
 
@@ -41,6 +41,7 @@ impl ToTokens for ExprTag {
 
 pub(crate) struct VariantInfo {
     pub(crate) tag: ExprTag,
+    pub(crate) verify_with: Option<Path>,
     pub(crate) inner: RecordInfo,
 }
 
@@ -48,32 +49,111 @@ impl VariantInfo {
     pub(crate) fn new(variant: &Variant, enum_ident: &syn::Ident, default_unpack_error_with: &Expr) -> Result<Self> {
         let variant_ident = variant.ident.clone();
 
+        let mut tag_opt = None;
+        let mut verify_with_opt = None;
+
         for attr in filter_attrs(&variant.attrs) {
-            if let Some(tag) = attr.parse_args_with(|stream: ParseStream| parse_kv("tag", stream))? {
-                return Ok(Self {
-                    tag,
-                    inner: RecordInfo::new(
-                        parse_quote!(#enum_ident::#variant_ident),
-                        &variant.fields,
-                        default_unpack_error_with,
-                    )?,
-                });
+            if let Some(tag) = attr.parse_args_with(|stream: ParseStream| {
+                let opt = parse_kv("tag", stream)?;
+                if opt.is_none() {
+                    skip_stream(stream)?;
+                }
+                Ok(opt)
+            })? {
+                tag_opt.get_or_insert(tag);
+            }
+
+            if let Some(verify_with) = attr.parse_args_with(|stream: ParseStream| {
+                let opt = parse_kv("verify_with", stream)?;
+                if opt.is_none() {
+                    skip_stream(stream)?;
+                }
+                Ok(opt)
+            })? {
+                verify_with_opt.get_or_insert(verify_with);
             }
         }
 
-        match &variant.discriminant {
-            Some((_, tag)) => Ok(Self {
-                tag: parse_quote!(#tag),
-                inner: RecordInfo::new(
-                    parse_quote!(#enum_ident::#variant_ident),
-                    &variant.fields,
-                    default_unpack_error_with,
-                )?,
-            }),
-            None => Err(Error::new(
+        let tag = match (tag_opt, &variant.discriminant) {
+            (Some(tag), _) => tag,
+            (None, Some((_, tag))) => parse_quote!(#tag),
+            (None, None) => {
+                return Err(Error::new(
+                    variant_ident.span(),
+                    "All variants of an enum that derives `Packable` require a `#[packable(tag = ...)]` attribute or an explicitly set discriminant.",
+                ));
+            }
+        };
+
+        Ok(Self {
+            tag,
+            verify_with: verify_with_opt,
+            inner: RecordInfo::new(
+                parse_quote!(#enum_ident::#variant_ident),
+                &variant.fields,
+                default_unpack_error_with,
+            )?,
+        })
+    }
+}
+
+/// The variant that captures the tag and the payload of the variants that are not known when unpacking.
+pub(crate) struct UnknownVariantInfo {
+    pub(crate) inner: RecordInfo,
+}
+
+impl UnknownVariantInfo {
+    /// Returns `true` if the variant has the `#[packable(unknown)]` attribute.
+    pub(crate) fn is_unknown(variant: &Variant) -> Result<bool> {
+        for attr in filter_attrs(&variant.attrs) {
+            if attr.parse_args_with(|stream: ParseStream| {
+                let found = parse_flag("unknown", stream)?;
+                skip_stream(stream)?;
+                Ok(found)
+            })? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    pub(crate) fn new(variant: &Variant, enum_ident: &syn::Ident, default_unpack_error_with: &Expr) -> Result<Self> {
+        let variant_ident = variant.ident.clone();
+
+        for attr in filter_attrs(&variant.attrs) {
+            if attr
+                .parse_args_with(|stream: ParseStream| {
+                    let opt = parse_kv::<ExprTag>("tag", stream)?;
+                    if opt.is_none() {
+                        skip_stream(stream)?;
+                    }
+                    Ok(opt)
+                })?
+                .is_some()
+            {
+                return Err(Error::new(
+                    variant_ident.span(),
+                    "The `unknown` variant of an enum that derives `Packable` cannot have a tag.",
+                ));
+            }
+        }
+
+        if !matches!(&variant.fields, Fields::Named(fields) if fields.named.len() == 2)
+            && !matches!(&variant.fields, Fields::Unnamed(fields) if fields.unnamed.len() == 2)
+        {
+            return Err(Error::new(
                 variant_ident.span(),
-                "All variants of an enum that derives `Packable` require a `#[packable(tag = ...)]` attribute or an explicitly set discriminant.",
-            )),
+                "The `unknown` variant of an enum that derives `Packable` must have exactly two fields: the tag and the payload.",
+            ));
         }
+
+        Ok(Self {
+            inner: RecordInfo::new(
+                parse_quote!(#enum_ident::#variant_ident),
+                &variant.fields,
+                default_unpack_error_with,
+            )?,
+        })
     }
 }
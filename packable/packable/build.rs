@@ -2,9 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 fn main() {
-    let ac = autocfg::new();
+    let mut ac = autocfg::new();
     ac.emit_has_type("i128");
     ac.emit_has_type("u128");
+    // Probes use the 2015 edition by default, where `core` paths cannot be used without `extern crate core`.
+    ac.set_edition(Some("2021".into()));
+    ac.emit_path_cfg("core::net::Ipv4Addr", "has_core_net");
 
     autocfg::rerun_path("build.rs");
 }
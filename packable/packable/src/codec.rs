@@ -0,0 +1,214 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A module that provides a length-delimited framing codec for types that implement [`Packable`].
+//!
+//! Each frame is made of a length prefix of type `B` followed by the packed value. The length prefix only accounts
+//! for the packed value and not for the prefix itself.
+
+extern crate std;
+
+use core::{convert::Infallible, fmt, marker::PhantomData};
+use std::io;
+
+use bytes::{Buf, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::{
+    bounded::Bounded,
+    error::{UnexpectedEOF, UnpackError},
+    unpacker::{BufUnpacker, CounterUnpacker, SliceUnpacker},
+    Packable, PackableExt,
+};
+
+/// Error type raised by a [`PackableCodec`].
+#[derive(Debug)]
+pub enum CodecError<T, P> {
+    /// An IO error produced by the underlying stream.
+    Io(io::Error),
+    /// The length of a frame exceeds the maximum frame length of the codec.
+    FrameTooLarge {
+        /// The length of the frame.
+        len: usize,
+        /// The maximum frame length of the codec.
+        max: usize,
+    },
+    /// Semantic error raised while unpacking the length prefix of a frame.
+    Prefix(P),
+    /// Semantic error raised while unpacking the value of a frame.
+    Packable(T),
+    /// The value of a frame requires more bytes than the ones declared by its length prefix.
+    Truncated(UnexpectedEOF),
+    /// The value of a frame does not use all the bytes declared by its length prefix.
+    TrailingBytes(usize),
+}
+
+impl<T, P> From<io::Error> for CodecError<T, P> {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl<T, P> From<Infallible> for CodecError<T, P> {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl<T: fmt::Display, P: fmt::Display> fmt::Display for CodecError<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "io error: {}", err),
+            Self::FrameTooLarge { len, max } => {
+                write!(f, "frame length {} exceeds the maximum frame length {}", len, max)
+            }
+            Self::Prefix(err) => write!(f, "cannot unpack frame prefix: {}", err),
+            Self::Packable(err) => write!(f, "cannot unpack frame: {}", err),
+            Self::Truncated(err) => write!(f, "truncated frame: {}", err),
+            Self::TrailingBytes(len) => write!(f, "frame has {} trailing bytes", len),
+        }
+    }
+}
+
+impl<T, P> std::error::Error for CodecError<T, P>
+where
+    T: fmt::Debug + fmt::Display,
+    P: fmt::Debug + fmt::Display,
+{
+}
+
+/// A length-delimited framing codec for values of type `T`, where the length prefix of each frame is of type `B`.
+///
+/// Frames whose length exceed the maximum frame length of the codec are rejected both when encoding and decoding.
+/// Partial frames are buffered until they are complete.
+pub struct PackableCodec<T: Packable, B = u32> {
+    max_frame_len: usize,
+    visitor: Option<T::UnpackVisitor>,
+    marker: PhantomData<fn() -> (T, B)>,
+}
+
+impl<T: Packable, B> PackableCodec<T, B> {
+    /// Creates a new [`PackableCodec`] that unpacks values doing syntactical checks with the given visitor.
+    pub fn verified(max_frame_len: usize, visitor: T::UnpackVisitor) -> Self {
+        Self {
+            max_frame_len,
+            visitor: Some(visitor),
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates a new [`PackableCodec`] that unpacks values without doing syntactical checks.
+    pub fn unverified(max_frame_len: usize) -> Self {
+        Self {
+            max_frame_len,
+            visitor: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the maximum frame length of this codec.
+    pub fn max_frame_len(&self) -> usize {
+        self.max_frame_len
+    }
+}
+
+impl<T, B> PackableCodec<T, B>
+where
+    T: Packable,
+    B: Bounded + Packable<UnpackVisitor = ()>,
+{
+    fn encode_frame(&self, item: &T, dst: &mut BytesMut) -> Result<(), CodecError<T::UnpackError, B::UnpackError>> {
+        let len = item.packed_len();
+
+        let prefix = match B::try_from(len) {
+            Ok(prefix) if len <= self.max_frame_len => prefix,
+            _ => {
+                return Err(CodecError::FrameTooLarge {
+                    len,
+                    max: self.max_frame_len,
+                });
+            }
+        };
+
+        dst.reserve(prefix.packed_len() + len);
+        prefix.pack(dst)?;
+        item.pack(dst)?;
+
+        Ok(())
+    }
+}
+
+impl<T, B> Encoder<T> for PackableCodec<T, B>
+where
+    T: Packable,
+    B: Bounded + Packable<UnpackVisitor = ()>,
+{
+    type Error = CodecError<T::UnpackError, B::UnpackError>;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.encode_frame(&item, dst)
+    }
+}
+
+impl<'a, T, B> Encoder<&'a T> for PackableCodec<T, B>
+where
+    T: Packable,
+    B: Bounded + Packable<UnpackVisitor = ()>,
+{
+    type Error = CodecError<T::UnpackError, B::UnpackError>;
+
+    fn encode(&mut self, item: &'a T, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.encode_frame(item, dst)
+    }
+}
+
+impl<T, B> Decoder for PackableCodec<T, B>
+where
+    T: Packable,
+    B: Bounded + Packable<UnpackVisitor = ()>,
+{
+    type Item = T;
+    type Error = CodecError<T::UnpackError, B::UnpackError>;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let mut unpacker = CounterUnpacker::new(SliceUnpacker::new(src));
+
+        let len = match B::unpack(&mut unpacker, self.visitor.as_ref().map(|_| &())) {
+            Ok(len) => len.into(),
+            // The length prefix is incomplete.
+            Err(UnpackError::Unpacker(_)) => return Ok(None),
+            Err(UnpackError::Packable(err)) => return Err(CodecError::Prefix(err)),
+        };
+        let prefix_len = unpacker.counter();
+
+        let len = match len.try_into() {
+            Ok(len) if len <= self.max_frame_len => len,
+            _ => {
+                return Err(CodecError::FrameTooLarge {
+                    len: len.try_into().unwrap_or(usize::MAX),
+                    max: self.max_frame_len,
+                });
+            }
+        };
+
+        if src.len() < prefix_len + len {
+            // The frame is incomplete.
+            src.reserve(prefix_len + len - src.len());
+            return Ok(None);
+        }
+
+        src.advance(prefix_len);
+
+        let mut unpacker = BufUnpacker::new(src.split_to(len));
+
+        let item = T::unpack(&mut unpacker, self.visitor.as_ref()).map_err(|err| match err {
+            UnpackError::Packable(err) => CodecError::Packable(err),
+            UnpackError::Unpacker(err) => CodecError::Truncated(err),
+        })?;
+
+        match unpacker.into_inner().remaining() {
+            0 => Ok(Some(item)),
+            trailing => Err(CodecError::TrailingBytes(trailing)),
+        }
+    }
+}
@@ -134,6 +134,93 @@ impl<T: fmt::Display> fmt::Display for UnknownTagError<T> {
     }
 }
 
+/// Error type raised when a [`StrictBool`](crate::StrictBool) is unpacked from a byte other than `0` or `1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidBoolError(pub u8);
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidBoolError {}
+
+impl From<Infallible> for InvalidBoolError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl fmt::Display for InvalidBoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid boolean byte {}", self.0)
+    }
+}
+
+/// Error type raised when a `u32` that is not a Unicode scalar value is unpacked as a `char`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidCharError(pub u32);
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidCharError {}
+
+impl From<Infallible> for InvalidCharError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl fmt::Display for InvalidCharError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid unicode scalar value {:#x}", self.0)
+    }
+}
+
+/// Error type raised when a [`Duration`](core::time::Duration) with one billion nanoseconds or more in its
+/// fractional part is unpacked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDurationError(pub u32);
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidDurationError {}
+
+impl From<Infallible> for InvalidDurationError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl fmt::Display for InvalidDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid number of subsecond nanoseconds {}", self.0)
+    }
+}
+
+/// Error type raised when the payload of a variant of an enum with an `unknown` variant or a field with a
+/// `length_prefixed` attribute does not have the length given by its prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DelimitedLenError {
+    /// The length of the payload given by its prefix.
+    pub expected: usize,
+    /// The number of bytes unpacked as the payload, or required to unpack it if it is longer than expected.
+    pub consumed: usize,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DelimitedLenError {}
+
+impl From<Infallible> for DelimitedLenError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl fmt::Display for DelimitedLenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "payload length mismatch, expected {} bytes but unpacked {}",
+            self.expected, self.consumed
+        )
+    }
+}
+
 /// Error type to be raised when `&[u8]` does not have enough bytes to unpack something or when
 /// [`SlicePacker`]('crate::packer::SlicePacker') does not have enough space to pack something.
 #[derive(Debug)]
@@ -152,3 +239,79 @@ impl fmt::Display for UnexpectedEOF {
         write!(f, "not enough bytes, required {} but had {}", self.required, self.had)
     }
 }
+
+/// Error type raised when unpacking a value from a sequence of bytes that must be consumed entirely.
+#[derive(Debug)]
+pub enum UnpackBytesError {
+    /// There were not enough bytes to unpack the value.
+    UnexpectedEOF(UnexpectedEOF),
+    /// Some bytes remained after unpacking the value.
+    TrailingBytes {
+        /// The number of remaining bytes.
+        remaining: usize,
+    },
+    /// Packing the unpacked value again does not produce the same bytes.
+    NonCanonical {
+        /// The offset of the first byte that differs.
+        offset: usize,
+    },
+}
+
+impl From<UnexpectedEOF> for UnpackBytesError {
+    fn from(err: UnexpectedEOF) -> Self {
+        Self::UnexpectedEOF(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnpackBytesError {}
+
+impl fmt::Display for UnpackBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEOF(err) => write!(f, "{err}"),
+            Self::TrailingBytes { remaining } => write!(f, "{remaining} trailing bytes after the unpacked value"),
+            Self::NonCanonical { offset } => write!(f, "non-canonical encoding at byte offset {offset}"),
+        }
+    }
+}
+
+/// Error type raised by a [`LimitedUnpacker`](crate::unpacker::LimitedUnpacker) when unpacking a value would exceed
+/// one of its limits.
+#[derive(Debug)]
+pub enum LimitError<E> {
+    /// The allocation budget would be exceeded.
+    AllocationLimit {
+        /// The number of bytes that were about to be allocated.
+        required: usize,
+        /// The number of bytes left in the allocation budget.
+        remaining: usize,
+    },
+    /// The maximum nesting depth would be exceeded.
+    DepthLimit(usize),
+    /// Error produced by the inner unpacker.
+    Unpacker(E),
+}
+
+impl<E> From<E> for LimitError<E> {
+    fn from(err: E) -> Self {
+        Self::Unpacker(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for LimitError<E> where E: std::error::Error {}
+
+impl<E: fmt::Display> fmt::Display for LimitError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AllocationLimit { required, remaining } => write!(
+                f,
+                "allocation limit exceeded, required {} bytes but had {}",
+                required, remaining
+            ),
+            Self::DepthLimit(max_depth) => write!(f, "nesting depth exceeds the maximum of {}", max_depth),
+            Self::Unpacker(err) => err.fmt(f),
+        }
+    }
+}
@@ -28,29 +28,88 @@
 //! Booleans are packed following Rust's data layout, meaning that `true` is packed as a `1` byte
 //! and `false` as a `0` byte. However, boolean unpacking is less strict and unpacks any non-zero
 //! byte as `true`.
+//! The [`StrictBool`] wrapper can be used instead to reject any byte other than `0` or `1`, and
+//! [`PackableExt::unpack_bytes_canonical`] rejects any sequence of bytes that is not the canonical
+//! encoding of the value unpacked from it.
 //!
 //! Types such as `Box<[T]>`, `[T; N]` and [`Option<T>`] implement [`Packable`] if `T` implements
 //! [`Packable`].
 //!
+//! Non-zero integers are packed like their underlying integer and reject zero, `char` is packed as
+//! its `u32` scalar value and rejects invalid scalar values, and `Duration` is packed as its `u64`
+//! seconds followed by its `u32` subsecond nanoseconds. [`Result<T, E>`] is tagged like
+//! [`Option<T>`], `Range<T>` is packed as its start followed by its end, and IP and socket
+//! addresses are packed as their octets, ports and, for IPv6 sockets, their flow information and
+//! scope ID. Wrappers such as `Wrapping<T>`, `Reverse<T>`, `Rc<T>`, `Arc<T>` and `Cow<T>` are
+//! packed exactly like the value they hold.
+//!
 //! This crate also provides bounded integers under the [`mod@bounded`] module which have additional
 //! syntactical checks to guarantee that the deserialized values are in-bounds. It is also possible
 //! to serialize and deserialize sequences of values by using the types provided in the [`prefix`]
 //! module, which represent linear sequences of values with a length prefix.
 //!
+//! Integers are encoded in little-endian order by default. The [`num`] module provides the
+//! [`BigEndian`](num::BigEndian) and [`VarInt`](num::VarInt) wrappers to use big-endian and
+//! variable-length encodings instead, either for values or for length prefixes.
+//!
+//! Sequences of concatenated values, such as append-only logs, can be written and read using the
+//! types provided in the [`stream`] module.
+//!
 //! Check the [`Packable`] `impl` section for further information.
 //!
 //! # Features
 //!
+//! ## `alloc`
+//!
+//! This feature is enabled by default and provides the implementations, types and methods that
+//! require an allocator, such as the [`Packable`] implementations for `Vec<T>` and `Box<T>`, the
+//! heap-allocated types of the [`prefix`] module and [`PackableExt::pack_to_vec`]. Disabling it
+//! allows using this crate in `no_std` environments without an allocator.
+//!
+//! Since these items used to be available without any feature, crates that depend on this one with
+//! `default-features = false` must now enable the `alloc` feature explicitly to keep using them.
+//!
+//! ## `arrayvec`
+//!
+//! This feature provides the [`ArrayVecPrefix`](prefix::ArrayVecPrefix) and
+//! [`ArrayStringPrefix`](prefix::ArrayStringPrefix) types which are packed exactly like
+//! [`VecPrefix`](prefix::VecPrefix) and [`StringPrefix`](prefix::StringPrefix) but store their
+//! values inline with a fixed capacity, without requiring the `alloc` feature.
+//!
+//! ## `bytes`
+//!
+//! This feature implements [`Packer`](packer::Packer) for `BytesMut` and provides the types
+//! [`BufMutPacker`](packer::BufMutPacker) and [`BufUnpacker`](unpacker::BufUnpacker) which allow
+//! packing and unpacking from values whose types implement `bytes`' `BufMut` and `Buf`
+//! respectively.
+//!
+//! ## `codec`
+//!
+//! This feature provides the [`PackableCodec`](codec::PackableCodec) type which implements
+//! `tokio-util`'s `Encoder` and `Decoder` traits to send and receive length-delimited frames of
+//! packed values.
+//!
+//! ## `digest`
+//!
+//! This feature provides the [`DigestPacker`](packer::DigestPacker) type and the
+//! [`PackableExt::hash_packed`] method which allow hashing packed values with any hasher
+//! implementing `digest`'s traits without allocating the packed bytes.
+//!
 //! ## `io`
 //!
 //! This feature provides the types [`IoPacker`](packer::IoPacker) and
 //! [`IoUnpacker`](unpacker::IoUnpacker) which allow packing and unpacking from values whose types
-//! implement [`Write`](std::io::Write) and [`Read`](std::io::Read) respectively.
+//! implement [`Write`](std::io::Write) and [`Read`](std::io::Read) respectively, and the
+//! [`BufferedIoUnpacker`](unpacker::BufferedIoUnpacker) type which can also look ahead.
 //!
 //! ## `primitive-types`
 //!
-//! This feature implements [`Packable`] for [`U256`](primitive_types::U256) encoding its values as
-//! arrays of bytes in little-endian order.
+//! This feature implements [`Packable`] for [`U128`](primitive_types::U128),
+//! [`U256`](primitive_types::U256) and [`U512`](primitive_types::U512) encoding their values as
+//! arrays of bytes in little-endian order, or in big-endian order when wrapped in
+//! [`BigEndian`](num::BigEndian) to match EVM encodings. It also implements [`Packable`] for
+//! [`H160`](primitive_types::H160), [`H256`](primitive_types::H256) and
+//! [`H512`](primitive_types::H512) encoding their values as raw bytes.
 //!
 //! ## `serde`
 //!
@@ -62,6 +121,13 @@
 //! This feature implements [`Error`](std::error::Error) for all the error types provided by this
 //! crate.
 //!
+//! ## `tokio`
+//!
+//! This feature provides the types [`AsyncPacker`](packer::AsyncPacker) and
+//! [`AsyncUnpacker`](unpacker::AsyncUnpacker) which allow packing and unpacking values
+//! asynchronously from values whose types implement `tokio`'s `AsyncWrite` and `AsyncRead`
+//! respectively.
+//!
 //! ## `usize`
 //!
 //! This feature implements [`Packable`] for [`usize`], [`isize`], [`Vec<T>`](std::vec::Vec),
@@ -77,8 +143,11 @@ extern crate std;
 
 mod packable;
 
+#[cfg(feature = "codec")]
+pub mod codec;
 pub mod error;
 pub mod packer;
+pub mod stream;
 pub mod unpacker;
 
 pub use crate::packable::*;
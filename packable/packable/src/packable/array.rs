@@ -3,12 +3,28 @@
 
 use core::{any::TypeId, mem::MaybeUninit};
 
-use crate::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable};
+use crate::{
+    error::UnpackError,
+    packer::Packer,
+    unpacker::{PathSegment, Unpacker},
+    Packable,
+};
 
 impl<T: Packable, const N: usize> Packable for [T; N] {
     type UnpackError = T::UnpackError;
     type UnpackVisitor = T::UnpackVisitor;
 
+    const PACKED_LEN: Option<usize> = match T::PACKED_LEN {
+        Some(len) => len.checked_mul(N),
+        None => None,
+    };
+
+    const MAX_PACKED_LEN: Option<usize> = match T::MAX_PACKED_LEN {
+        Some(0) => Some(0),
+        Some(len) => len.checked_mul(N),
+        None => None,
+    };
+
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
         if TypeId::of::<T>() == TypeId::of::<u8>() {
@@ -39,8 +55,10 @@ impl<T: Packable, const N: usize> Packable for [T; N] {
             // FIXME: replace with [`MaybeUninit::uninit_array`] when stabilized.
             let mut array = unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() };
 
-            for item in array.iter_mut() {
+            for (index, item) in array.iter_mut().enumerate() {
+                unpacker.push_path(PathSegment::Index(index));
                 let unpacked = T::unpack(unpacker, visitor)?;
+                unpacker.pop_path();
 
                 // Safety: each `item` is only visited once so we are never overwriting nor dropping values that are
                 // already initialized.
@@ -54,4 +72,20 @@ impl<T: Packable, const N: usize> Packable for [T; N] {
             Ok(unsafe { (&array as *const [MaybeUninit<T>; N] as *const Self).read() })
         }
     }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        if TypeId::of::<T>() == TypeId::of::<u8>() {
+            unpacker.skip_bytes(N)?;
+        } else {
+            for _ in 0..N {
+                T::skip(unpacker, visitor)?;
+            }
+        }
+
+        Ok(())
+    }
 }
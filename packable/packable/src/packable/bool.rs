@@ -4,7 +4,7 @@
 use core::convert::Infallible;
 
 use crate::{
-    error::{UnpackError, UnpackErrorExt},
+    error::{InvalidBoolError, UnpackError, UnpackErrorExt},
     packer::Packer,
     unpacker::Unpacker,
     Packable,
@@ -14,6 +14,8 @@ impl Packable for bool {
     type UnpackError = Infallible;
     type UnpackVisitor = ();
 
+    const PACKED_LEN: Option<usize> = Some(1);
+
     /// Booleans are packed as `u8` integers following Rust's data layout.
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
@@ -29,3 +31,56 @@ impl Packable for bool {
         Ok(u8::unpack(unpacker, visitor).coerce()? != 0)
     }
 }
+
+/// Wrapper type for a [`bool`] that only unpacks `0` as `false` and `1` as `true`.
+///
+/// This type is packed exactly like a [`bool`], but unpacking any other byte fails with [`InvalidBoolError`]. This
+/// guarantees that every value has a single encoding.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+#[repr(transparent)]
+pub struct StrictBool(pub bool);
+
+impl StrictBool {
+    /// Returns the wrapped boolean.
+    #[inline(always)]
+    pub fn into_inner(self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for StrictBool {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+impl From<StrictBool> for bool {
+    fn from(value: StrictBool) -> Self {
+        value.0
+    }
+}
+
+impl Packable for StrictBool {
+    type UnpackError = InvalidBoolError;
+    type UnpackVisitor = ();
+
+    const PACKED_LEN: Option<usize> = Some(1);
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.0.pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        match u8::unpack(unpacker, visitor).coerce()? {
+            0 => Ok(Self(false)),
+            1 => Ok(Self(true)),
+            byte => Err(UnpackError::Packable(InvalidBoolError(byte))),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{borrow::Borrow, convert::Infallible, fmt::Debug};
+
+use crate::{
+    error::{UnexpectedEOF, UnpackError},
+    packer::Packer,
+    unpacker::SliceUnpacker,
+    Packable,
+};
+
+/// A type that can be packed and unpacked by borrowing data from the input buffer.
+///
+/// This is the zero-copy companion of [`Packable`]: values are unpacked from a [`SliceUnpacker<'a>`] and can hold
+/// references into the unpacked bytes instead of copying them into owned values. Every [`Packable`] type implements
+/// this trait, which allows mixing owned and borrowed fields in the same type.
+///
+/// This trait is implemented for `&'a [u8; N]` and for the
+/// [`SlicePrefix`](crate::prefix::SlicePrefix) and [`StrPrefix`](crate::prefix::StrPrefix) types. `&'a [u8]` and
+/// `&'a str` implement it as well when the `usize` feature is enabled.
+///
+/// The [`Packable`](packable_derive::Packable) derive macro implements this trait instead of [`Packable`] for types
+/// with a lifetime parameter.
+///
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::{prefix::StrPrefix, Packable, UnpackBorrowed};
+///
+/// #[derive(Debug, PartialEq, Packable)]
+/// pub struct Message<'a> {
+///     text: StrPrefix<'a, u8>,
+///     id: u32,
+/// }
+///
+/// let bytes = [5, b'h', b'e', b'l', b'l', b'o', 7, 0, 0, 0];
+/// let message = Message::unpack_borrowed_bytes_verified(&bytes, &()).unwrap();
+///
+/// assert_eq!(message.id, 7);
+/// assert_eq!(&*message.text, "hello");
+/// ```
+pub trait UnpackBorrowed<'a>: Sized {
+    /// The error type that can be returned if some semantic error occurs while unpacking.
+    type UnpackError: Debug + From<Infallible>;
+    /// The visitor type used to make additional syntactic checks while unpacking.
+    type UnpackVisitor: Borrow<()>;
+
+    /// Packs this value into the given [`Packer`].
+    fn pack_borrowed<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error>;
+
+    /// Unpacks this value from the given [`SliceUnpacker`], borrowing data from it when possible. The optional
+    /// visitor can be used to make additional syntactic checks.
+    fn unpack_borrowed(
+        unpacker: &mut SliceUnpacker<'a>,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, UnexpectedEOF>>;
+
+    /// Unpacks this value from a slice of bytes doing syntactical checks.
+    #[inline]
+    fn unpack_borrowed_bytes_verified(
+        bytes: &'a [u8],
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, UnexpectedEOF>> {
+        Self::unpack_borrowed(&mut SliceUnpacker::new(bytes), Some(visitor))
+    }
+
+    /// Unpacks this value from a slice of bytes without doing syntactical checks.
+    #[inline]
+    fn unpack_borrowed_bytes_unverified(
+        bytes: &'a [u8],
+    ) -> Result<Self, UnpackError<Self::UnpackError, UnexpectedEOF>> {
+        Self::unpack_borrowed(&mut SliceUnpacker::new(bytes), None)
+    }
+}
+
+impl<'a, T: Packable> UnpackBorrowed<'a> for T {
+    type UnpackError = T::UnpackError;
+    type UnpackVisitor = T::UnpackVisitor;
+
+    #[inline]
+    fn pack_borrowed<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.pack(packer)
+    }
+
+    #[inline]
+    fn unpack_borrowed(
+        unpacker: &mut SliceUnpacker<'a>,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, UnexpectedEOF>> {
+        T::unpack(unpacker, visitor)
+    }
+}
+
+impl<'a, const N: usize> UnpackBorrowed<'a> for &'a [u8; N] {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    #[inline]
+    fn pack_borrowed<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        packer.pack_bytes(*self)
+    }
+
+    #[inline]
+    fn unpack_borrowed(
+        unpacker: &mut SliceUnpacker<'a>,
+        _visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, UnexpectedEOF>> {
+        // This unwrap is fine because the slice has exactly `N` bytes.
+        Ok(unpacker.unpack_slice(N)?.try_into().unwrap())
+    }
+}
+
+#[cfg(feature = "usize")]
+mod usize {
+    use core::str::Utf8Error;
+
+    use super::*;
+    use crate::{error::UnpackErrorExt, prefix::UnpackPrefixError};
+
+    fn unpack_len(
+        unpacker: &mut SliceUnpacker<'_>,
+    ) -> Result<usize, UnpackError<<usize as Packable>::UnpackError, UnexpectedEOF>> {
+        u64::unpack_unverified(unpacker)
+            .coerce()?
+            .try_into()
+            .map_err(UnpackError::Packable)
+    }
+
+    impl<'a> UnpackBorrowed<'a> for &'a [u8] {
+        type UnpackError = UnpackPrefixError<Infallible, <usize as Packable>::UnpackError>;
+        type UnpackVisitor = ();
+
+        #[inline]
+        fn pack_borrowed<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+            // This cast is fine because we know `usize` is not larger than `64` bits.
+            (self.len() as u64).pack(packer)?;
+            packer.pack_bytes(*self)
+        }
+
+        #[inline]
+        fn unpack_borrowed(
+            unpacker: &mut SliceUnpacker<'a>,
+            _visitor: Option<&Self::UnpackVisitor>,
+        ) -> Result<Self, UnpackError<Self::UnpackError, UnexpectedEOF>> {
+            let len = unpack_len(unpacker).map_packable_err(UnpackPrefixError::Prefix)?;
+
+            Ok(unpacker.unpack_slice(len)?)
+        }
+    }
+
+    impl<'a> UnpackBorrowed<'a> for &'a str {
+        type UnpackError = UnpackPrefixError<Utf8Error, <usize as Packable>::UnpackError>;
+        type UnpackVisitor = ();
+
+        #[inline]
+        fn pack_borrowed<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+            self.as_bytes().pack_borrowed(packer)
+        }
+
+        #[inline]
+        fn unpack_borrowed(
+            unpacker: &mut SliceUnpacker<'a>,
+            _visitor: Option<&Self::UnpackVisitor>,
+        ) -> Result<Self, UnpackError<Self::UnpackError, UnexpectedEOF>> {
+            let len = unpack_len(unpacker).map_packable_err(UnpackPrefixError::Prefix)?;
+
+            core::str::from_utf8(unpacker.unpack_slice(len)?)
+                .map_err(|err| UnpackError::Packable(UnpackPrefixError::Item(err)))
+        }
+    }
+}
@@ -15,6 +15,21 @@ use crate::Packable;
 pub trait Bounded: TryFrom<usize> + Into<Self::Bounds> {
     /// The type used to define the bounds.
     type Bounds: PartialOrd + TryInto<Self> + TryInto<usize> + Default + Copy;
+
+    /// The largest length that can be represented by this type, saturated to [`usize::MAX`].
+    ///
+    /// It is used to check at compile time that the length prefix of a field can represent the packed length of any
+    /// value of that field. Returning a length that cannot be represented is a logic error.
+    const MAX_LEN: usize = usize::MAX;
+}
+
+/// Returns `max` as a `usize`, saturated to [`usize::MAX`].
+pub(crate) const fn saturating_len(max: u128) -> usize {
+    if max > usize::MAX as u128 {
+        usize::MAX
+    } else {
+        max as usize
+    }
 }
 
 macro_rules! bounded {
@@ -27,6 +42,8 @@ macro_rules! bounded {
 
         impl<const MIN: $ty, const MAX: $ty> Bounded for $wrapper<MIN, MAX> {
             type Bounds = $ty;
+
+            const MAX_LEN: usize = saturating_len(MAX as u128);
         }
 
         impl<const MIN: $ty, const MAX: $ty> $wrapper<MIN, MAX> {
@@ -155,16 +172,24 @@ bounded!(BoundedU64, InvalidBoundedU64, TryIntoBoundedU64Error, u64);
 
 impl Bounded for u8 {
     type Bounds = Self;
+
+    const MAX_LEN: usize = saturating_len(Self::MAX as u128);
 }
 
 impl Bounded for u16 {
     type Bounds = Self;
+
+    const MAX_LEN: usize = saturating_len(Self::MAX as u128);
 }
 
 impl Bounded for u32 {
     type Bounds = Self;
+
+    const MAX_LEN: usize = saturating_len(Self::MAX as u128);
 }
 
 impl Bounded for u64 {
     type Bounds = Self;
+
+    const MAX_LEN: usize = saturating_len(Self::MAX as u128);
 }
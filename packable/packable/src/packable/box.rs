@@ -5,17 +5,23 @@ extern crate alloc;
 
 use alloc::boxed::Box;
 #[cfg(feature = "usize")]
-use alloc::{vec, vec::Vec};
+use alloc::vec::Vec;
 #[cfg(feature = "usize")]
 use core::any::TypeId;
 use core::ops::Deref;
 
+#[cfg(feature = "usize")]
+use crate::unpacker::PathSegment;
 use crate::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable};
 
 impl<T: Packable> Packable for Box<T> {
     type UnpackError = T::UnpackError;
     type UnpackVisitor = T::UnpackVisitor;
 
+    const PACKED_LEN: Option<usize> = T::PACKED_LEN;
+
+    const MAX_PACKED_LEN: Option<usize> = T::MAX_PACKED_LEN;
+
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
         self.deref().pack(packer)
@@ -26,7 +32,24 @@ impl<T: Packable> Packable for Box<T> {
         unpacker: &mut U,
         visitor: Option<&Self::UnpackVisitor>,
     ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
-        Ok(Box::new(T::unpack(unpacker, visitor)?))
+        unpacker.enter_nested()?;
+        unpacker.track_alloc(core::mem::size_of::<T>())?;
+        let inner = T::unpack(unpacker, visitor)?;
+        unpacker.exit_nested();
+
+        Ok(Box::new(inner))
+    }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        unpacker.enter_nested()?;
+        T::skip(unpacker, visitor)?;
+        unpacker.exit_nested();
+
+        Ok(())
     }
 }
 
@@ -66,19 +89,84 @@ impl<T: Packable> Packable for Box<[T]> {
             .map_err(|err| UnpackError::Packable(Self::UnpackError::Prefix(err)))?;
 
         if TypeId::of::<T>() == TypeId::of::<u8>() {
-            let mut bytes = vec![0u8; len].into_boxed_slice();
-            unpacker.unpack_bytes(&mut bytes)?;
+            let bytes = crate::packable::capacity::unpack_bytes(unpacker, len)?.into_boxed_slice();
             // Safety: `Self` is identical to `Box<[u8]>`.
             Ok(unsafe { core::mem::transmute::<Box<[u8]>, Self>(bytes) })
         } else {
-            let mut vec = Vec::with_capacity(len);
+            crate::packable::capacity::hint_items::<T, _>(unpacker, len);
 
-            for _ in 0..len {
+            let mut vec = Vec::with_capacity(crate::packable::capacity::initial_capacity::<T>(len));
+
+            unpacker.enter_nested()?;
+
+            for index in 0..len {
+                unpacker.track_alloc(core::mem::size_of::<T>())?;
+                unpacker.push_path(PathSegment::Index(index));
                 let item = T::unpack(unpacker, visitor).map_packable_err(Self::UnpackError::Item)?;
                 vec.push(item);
+                unpacker.pop_path();
             }
 
+            unpacker.exit_nested();
+
             Ok(vec.into_boxed_slice())
         }
     }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        use crate::error::UnpackErrorExt;
+
+        let len = u64::unpack_inner(unpacker, visitor)
+            .coerce()?
+            .try_into()
+            .map_err(|err| UnpackError::Packable(Self::UnpackError::Prefix(err)))?;
+
+        if TypeId::of::<T>() == TypeId::of::<u8>() {
+            unpacker.skip_bytes(len)?;
+        } else {
+            unpacker.enter_nested()?;
+
+            for _ in 0..len {
+                T::skip(unpacker, visitor).map_packable_err(Self::UnpackError::Item)?;
+            }
+
+            unpacker.exit_nested();
+        }
+
+        Ok(())
+    }
+}
+
+/// Boxed strings are packed exactly like a [`String`](alloc::string::String).
+#[cfg(feature = "usize")]
+impl Packable for Box<str> {
+    type UnpackError = <alloc::string::String as Packable>::UnpackError;
+    type UnpackVisitor = ();
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        // This cast is fine because we know `usize` is not larger than `64` bits.
+        (self.len() as u64).pack(packer)?;
+        packer.pack_bytes(self.as_bytes())
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        Ok(alloc::string::String::unpack(unpacker, visitor)?.into_boxed_str())
+    }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        alloc::string::String::skip(unpacker, visitor)
+    }
 }
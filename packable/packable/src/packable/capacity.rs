@@ -0,0 +1,53 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers to allocate memory for unpacked sequences without trusting their length prefix.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::{unpacker::Unpacker, Packable};
+
+/// The maximum number of bytes that are preallocated for a sequence before any of its items has been unpacked.
+pub(crate) const MAX_PREALLOCATION: usize = 4096;
+
+/// Returns the initial capacity of a sequence of `len` items of type `T`.
+///
+/// The capacity is capped so a malicious length prefix cannot force a huge allocation before any item has been read.
+#[inline]
+pub(crate) fn initial_capacity<T>(len: usize) -> usize {
+    match core::mem::size_of::<T>() {
+        0 => len,
+        size => len.min(MAX_PREALLOCATION / size),
+    }
+}
+
+/// Hints the unpacker about the number of bytes of a sequence of `len` items of type `T` before unpacking them.
+///
+/// This is only possible if all the values of `T` have the same packed length. It lets unpackers that buffer their
+/// input, like the `AsyncUnpacker`, read the whole sequence at once instead of running out of bytes on every item.
+#[inline]
+pub(crate) fn hint_items<T: Packable, U: Unpacker>(unpacker: &mut U, len: usize) {
+    if let Some(packed_len) = T::PACKED_LEN {
+        unpacker.hint_bytes(len.saturating_mul(packed_len));
+    }
+}
+
+/// Unpacks `len` bytes into a new `Vec<u8>`, growing it gradually instead of allocating `len` bytes upfront.
+pub(crate) fn unpack_bytes<U: Unpacker>(unpacker: &mut U, len: usize) -> Result<Vec<u8>, U::Error> {
+    unpacker.ensure_bytes(len)?;
+    unpacker.track_alloc(len)?;
+
+    let mut bytes = Vec::with_capacity(initial_capacity::<u8>(len));
+
+    while bytes.len() < len {
+        let start = bytes.len();
+        // Double the size of the buffer on each iteration so the number of reallocations stays logarithmic.
+        let chunk = (len - start).min(start.max(MAX_PREALLOCATION));
+        bytes.resize(start + chunk, 0);
+        unpacker.unpack_bytes(&mut bytes[start..])?;
+    }
+
+    Ok(bytes)
+}
@@ -0,0 +1,33 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    error::{InvalidCharError, UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable,
+};
+
+/// Characters are packed as the `u32` value of their Unicode scalar value. Unpacking fails with [`InvalidCharError`]
+/// if the value is not a Unicode scalar value.
+impl Packable for char {
+    type UnpackError = InvalidCharError;
+    type UnpackVisitor = ();
+
+    const PACKED_LEN: Option<usize> = Some(4);
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        u32::from(*self).pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let value = u32::unpack(unpacker, visitor).coerce()?;
+
+        char::from_u32(value).ok_or(UnpackError::Packable(InvalidCharError(value)))
+    }
+}
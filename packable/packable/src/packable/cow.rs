@@ -0,0 +1,107 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+#[cfg(feature = "usize")]
+use alloc::{string::String, vec::Vec};
+use core::ops::Deref;
+
+use crate::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable};
+
+/// Clone-on-write values are packed exactly like the value they hold and are always unpacked as owned values.
+impl<T: Packable + Clone> Packable for Cow<'static, T> {
+    type UnpackError = T::UnpackError;
+    type UnpackVisitor = T::UnpackVisitor;
+
+    const PACKED_LEN: Option<usize> = T::PACKED_LEN;
+
+    const MAX_PACKED_LEN: Option<usize> = T::MAX_PACKED_LEN;
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.deref().pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        Ok(Cow::Owned(T::unpack(unpacker, visitor)?))
+    }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        T::skip(unpacker, visitor)
+    }
+}
+
+/// Clone-on-write strings are packed exactly like a [`String`].
+#[cfg(feature = "usize")]
+impl Packable for Cow<'static, str> {
+    type UnpackError = <String as Packable>::UnpackError;
+    type UnpackVisitor = ();
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        // This cast is fine because we know `usize` is not larger than `64` bits.
+        (self.len() as u64).pack(packer)?;
+        packer.pack_bytes(self.as_bytes())
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        Ok(Cow::Owned(String::unpack(unpacker, visitor)?))
+    }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        String::skip(unpacker, visitor)
+    }
+}
+
+/// Clone-on-write slices are packed exactly like a [`Vec<T>`].
+#[cfg(feature = "usize")]
+impl<T: Packable + Clone> Packable for Cow<'static, [T]> {
+    type UnpackError = <Vec<T> as Packable>::UnpackError;
+    type UnpackVisitor = T::UnpackVisitor;
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        // This cast is fine because we know `usize` is not larger than `64` bits.
+        (self.len() as u64).pack(packer)?;
+
+        for item in self.iter() {
+            item.pack(packer)?;
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        Ok(Cow::Owned(Vec::unpack(unpacker, visitor)?))
+    }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        Vec::<T>::skip(unpacker, visitor)
+    }
+}
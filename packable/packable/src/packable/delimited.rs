@@ -0,0 +1,117 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Utilities used by the derive macro to pack and unpack values prefixed by their length, such as the variants of
+//! enums with an `unknown` variant and the fields with a `length_prefixed` attribute.
+
+use core::{convert::Infallible, fmt::Debug};
+
+use crate::{
+    error::{DelimitedLenError, UnpackError},
+    packer::{LenPacker, Packer},
+    unpacker::{DelimitedUnpacker, Unpacker},
+    Packable, PackableExt,
+};
+
+/// Packs the length of a delimited payload as a `u32` in little-endian order.
+///
+/// # Panics
+///
+/// Panics if `len` does not fit in a `u32`.
+pub fn pack_delimited_len<P: Packer>(len: usize, packer: &mut P) -> Result<(), P::Error> {
+    let len = u32::try_from(len).expect("the payload of a delimited variant cannot be longer than `u32::MAX` bytes");
+
+    packer.pack_bytes(len.to_le_bytes())
+}
+
+/// Unpacks the length of a delimited payload.
+pub fn unpack_delimited_len<U: Unpacker>(unpacker: &mut U) -> Result<usize, U::Error> {
+    let mut bytes = [0u8; 4];
+    unpacker.unpack_bytes(&mut bytes)?;
+
+    Ok(u32::from_le_bytes(bytes) as usize)
+}
+
+/// Returns the number of bytes written by `pack` when packing `value`, to compute the length of a delimited payload
+/// with fields that are packed by custom functions.
+pub fn packed_len_with<T>(value: &T, pack: fn(&T, &mut LenPacker) -> Result<(), Infallible>) -> usize {
+    let mut packer = LenPacker(0);
+
+    match pack(value, &mut packer) {
+        Ok(()) => packer.0,
+        Err(err) => match err {},
+    }
+}
+
+/// Packs the length of a length-prefixed value using the prefix type `B`.
+///
+/// The derive macro checks at compile time that `B` can represent the packed length of any value of the field, so
+/// `len` always fits in `B` unless the [`Packable::MAX_PACKED_LEN`] of the field is wrong, which is a logic error.
+pub fn pack_length_prefix<B, P>(len: usize, packer: &mut P) -> Result<(), P::Error>
+where
+    B: Packable + TryFrom<usize>,
+    B::Error: Debug,
+    P: Packer,
+{
+    B::try_from(len)
+        .expect("the maximum packed length of a length-prefixed value is smaller than its packed length")
+        .pack(packer)
+}
+
+/// Returns the packed length of a length-prefixed value whose packed length is `len`, including the prefix of type `B`.
+///
+/// As for [`pack_length_prefix`], `len` always fits in `B` unless the [`Packable::MAX_PACKED_LEN`] of the field is
+/// wrong, which is a logic error.
+pub fn length_prefixed_len<B>(len: usize) -> usize
+where
+    B: Packable + TryFrom<usize>,
+    B::Error: Debug,
+{
+    let prefix = B::try_from(len)
+        .expect("the maximum packed length of a length-prefixed value is smaller than its packed length");
+
+    PackableExt::packed_len(&prefix) + len
+}
+
+/// Unpacks a value with `unpack` confining it to exactly `len` bytes. The outer result fails if the value cannot be
+/// unpacked and the inner result fails if `unpack` does not use exactly `len` bytes, in which case no byte past them
+/// has been read.
+#[allow(clippy::type_complexity)]
+pub fn unpack_delimited<T, E, U, F>(
+    unpacker: &mut U,
+    len: usize,
+    unpack: F,
+) -> Result<Result<T, DelimitedLenError>, UnpackError<E, U::Error>>
+where
+    U: Unpacker,
+    F: FnOnce(&mut DelimitedUnpacker<'_, U>) -> Result<T, UnpackError<E, UnpackError<DelimitedLenError, U::Error>>>,
+{
+    let mut unpacker = DelimitedUnpacker::new(unpacker, len);
+
+    let unpacked = match unpack(&mut unpacker) {
+        Ok(unpacked) => unpacked,
+        Err(UnpackError::Packable(err)) => return Err(UnpackError::Packable(err)),
+        Err(UnpackError::Unpacker(UnpackError::Packable(err))) => return Ok(Err(err)),
+        Err(UnpackError::Unpacker(UnpackError::Unpacker(err))) => return Err(UnpackError::Unpacker(err)),
+    };
+
+    if unpacker.remaining() != 0 {
+        return Ok(Err(DelimitedLenError {
+            expected: len,
+            consumed: unpacker.consumed(),
+        }));
+    }
+
+    Ok(Ok(unpacked))
+}
+
+/// Unpacks a value that must use exactly `len` bytes. The outer result fails if the value cannot be unpacked and the
+/// inner result fails if the value does not use exactly `len` bytes.
+#[allow(clippy::type_complexity)]
+pub fn unpack_length_prefixed<T: Packable, U: Unpacker>(
+    unpacker: &mut U,
+    len: usize,
+    visitor: Option<&T::UnpackVisitor>,
+) -> Result<Result<T, DelimitedLenError>, UnpackError<T::UnpackError, U::Error>> {
+    unpack_delimited(unpacker, len, |unpacker| T::unpack(unpacker, visitor))
+}
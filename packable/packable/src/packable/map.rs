@@ -6,7 +6,7 @@
 extern crate alloc;
 
 #[cfg(feature = "usize")]
-use alloc::collections::BTreeMap;
+use alloc::{collections::BTreeMap, vec::Vec};
 #[cfg(feature = "usize")]
 use core::{borrow::Borrow, hash::Hash};
 use core::{convert::Infallible, fmt};
@@ -15,7 +15,12 @@ use core::{convert::Infallible, fmt};
 use hashbrown::HashMap;
 
 #[cfg(feature = "usize")]
-use crate::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable};
+use crate::{
+    error::UnpackError,
+    packer::Packer,
+    unpacker::{PathSegment, Unpacker},
+    Packable,
+};
 
 /// Error type raised when a semantic error occurs while unpacking a map.
 pub enum UnpackMapError<K, KE, VE, P> {
@@ -120,15 +125,21 @@ impl<K: Packable + Ord + Hash, V: Packable> Packable for HashMap<K, V>
 where
     V::UnpackVisitor: Borrow<K::UnpackVisitor>,
 {
-    type UnpackError = UnpackMapError<K, K::UnpackError, V::UnpackError, <usize as Packable>::UnpackError>;
+    type UnpackError = UnpackOrderedMapError<K, K::UnpackError, V::UnpackError, <usize as Packable>::UnpackError>;
     type UnpackVisitor = V::UnpackVisitor;
 
+    /// The entries are packed sorted by key so the encoding of a map is canonical.
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
         // This cast is fine because we know `usize` is not larger than `64` bits.
         (self.len() as u64).pack(packer)?;
 
-        for (k, v) in self.iter() {
+        // The iteration order of a `HashMap` is not deterministic, the entries are sorted by key to
+        // produce a canonical encoding.
+        let mut entries = self.iter().collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|(k, _)| *k);
+
+        for (k, v) in entries {
             k.pack(packer)?;
             v.pack(packer)?;
         }
@@ -146,27 +157,45 @@ where
         let len = u64::unpack_inner(unpacker, visitor)
             .coerce()?
             .try_into()
-            .map_err(|err| UnpackError::Packable(UnpackMapError::Prefix(err)))?;
+            .map_err(|err| UnpackError::Packable(UnpackMapError::Prefix(err).into()))?;
+
+        let mut entries = Vec::<(K, V)>::with_capacity(crate::packable::capacity::initial_capacity::<(K, V)>(len));
 
-        let mut map = HashMap::<K, V>::with_capacity(len);
+        unpacker.enter_nested()?;
 
-        for _ in 0..len {
+        for index in 0..len {
+            unpacker.track_alloc(core::mem::size_of::<(K, V)>())?;
+            unpacker.push_path(PathSegment::Index(index));
             let key = K::unpack_inner(unpacker, visitor)
                 .map_packable_err(UnpackMapError::Key)
                 .map_packable_err(Self::UnpackError::from)?;
 
-            if map.contains_key(&key) {
-                return Err(UnpackError::Packable(UnpackMapError::DuplicateKey(key)));
+            if let Some((last, _)) = entries.last() {
+                match last.cmp(&key) {
+                    core::cmp::Ordering::Equal => {
+                        return Err(UnpackError::Packable(Self::UnpackError::Map(
+                            UnpackMapError::DuplicateKey(key),
+                        )));
+                    }
+                    core::cmp::Ordering::Greater => {
+                        return Err(UnpackError::Packable(Self::UnpackError::Unordered));
+                    }
+                    core::cmp::Ordering::Less => (),
+                }
             }
 
             let value = V::unpack(unpacker, visitor)
                 .map_packable_err(UnpackMapError::Value)
                 .map_packable_err(Self::UnpackError::from)?;
 
-            map.insert(key, value);
+            entries.push((key, value));
+
+            unpacker.pop_path();
         }
 
-        Ok(map)
+        unpacker.exit_nested();
+
+        Ok(entries.into_iter().collect())
     }
 }
 
@@ -205,7 +234,11 @@ where
 
         let mut map = BTreeMap::<K, V>::new();
 
-        for _ in 0..len {
+        unpacker.enter_nested()?;
+
+        for index in 0..len {
+            unpacker.track_alloc(core::mem::size_of::<(K, V)>())?;
+            unpacker.push_path(PathSegment::Index(index));
             let key = K::unpack_inner(unpacker, visitor)
                 .map_packable_err(UnpackMapError::Key)
                 .map_packable_err(Self::UnpackError::from)?;
@@ -229,8 +262,12 @@ where
                 .map_packable_err(Self::UnpackError::from)?;
 
             map.insert(key, value);
+
+            unpacker.pop_path();
         }
 
+        unpacker.exit_nested();
+
         Ok(map)
     }
 }
@@ -3,38 +3,75 @@
 
 //! A module that provides a [`Packable`] trait to serialize and deserialize types.
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
 pub mod bounded;
+#[cfg(feature = "alloc")]
 pub mod map;
+pub mod num;
 pub mod option;
 pub mod prefix;
+pub mod result;
+#[cfg(feature = "alloc")]
 pub mod set;
 
 mod array;
 mod bool;
+mod borrowed;
+#[cfg(feature = "alloc")]
 mod r#box;
-mod num;
+#[cfg(feature = "alloc")]
+mod capacity;
+mod char;
+#[cfg(feature = "alloc")]
+mod cow;
+mod delimited;
+#[cfg(any(has_core_net, feature = "std"))]
+mod net;
+mod packed_len;
 #[cfg(feature = "primitive-types")]
 mod primitive_types;
-#[cfg(feature = "usize")]
+mod range;
+#[cfg(feature = "alloc")]
+mod rc;
+#[cfg(all(feature = "alloc", feature = "usize"))]
 mod string;
+mod time;
 mod tuple;
-#[cfg(feature = "usize")]
+mod unknown;
+#[cfg(all(feature = "alloc", feature = "usize"))]
 mod vec;
+mod wrapper;
 
+#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 use core::{
     borrow::Borrow,
     convert::{AsRef, Infallible},
     fmt::Debug,
+    marker::PhantomData,
 };
 
 pub use packable_derive::Packable;
 
+#[cfg(feature = "alloc")]
+pub use self::unknown::UnknownPayload;
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub use self::unknown::{pack_unknown_payload, unpack_unknown_payload};
+pub use self::{bool::StrictBool, borrowed::UnpackBorrowed, unknown::KnownTags};
+#[doc(hidden)]
+pub use self::{
+    delimited::{
+        length_prefixed_len, pack_delimited_len, pack_length_prefix, packed_len_with, unpack_delimited,
+        unpack_delimited_len, unpack_length_prefixed,
+    },
+    packed_len::{check_length_prefix, max_packed_len, prefixed_max_packed_len, same_packed_len, sum_packed_len},
+};
 use crate::{
-    error::{UnexpectedEOF, UnpackError},
-    packer::{LenPacker, Packer},
+    error::{UnexpectedEOF, UnpackBytesError, UnpackError},
+    packer::{ComparePacker, LenPacker, Packer, SlicePacker},
     unpacker::{SliceUnpacker, Unpacker},
 };
 
@@ -177,6 +214,219 @@ use crate::{
 /// where `F` is the type of the field being verified, `P` is the type of the `struct` or `enum`.
 /// This verification function will be run immediately after unpacking the field. The `visitor`
 /// param can be excluded if it is not needed for verification.
+///
+/// The `verify_with` attribute can also be applied to a `struct`, an `enum` or one of the variants
+/// of an `enum` to verify invariants involving several fields. In this case, the function receives
+/// the unpacked value of type `P` instead of a field and it is run immediately after unpacking the
+/// whole `struct` or `enum`, or the variant it is applied to. The `visitor` param is only passed if
+/// the `unpack_visitor` attribute is specified or, for an `enum`, if its first variant has fields.
+///
+/// ## Custom functions for fields
+///
+/// A field whose type does not implement [`Packable`], or that must be encoded differently, can be
+/// packed and unpacked by custom functions using the
+/// `#[packable(pack_with = ..., unpack_with = ...)]` attribute, which must receive valid Rust paths
+/// refering to functions with the signatures
+/// ```ignore
+/// fn<P: Packer>(field: &F, packer: &mut P) -> Result<(), P::Error>
+/// fn<U: Unpacker>(unpacker: &mut U, visitor: Option<&V>) -> Result<F, UnpackError<E, U::Error>>
+/// ```
+/// where `F` is the type of the field, `V` is a type that the `UnpackVisitor` of the `struct` or
+/// `enum` can be borrowed as and `E` is converted into its `UnpackError` following the same rules
+/// as the errors of the other fields. The `#[packable(with = ...)]` attribute is a shorthand
+/// receiving the path of a module with `pack` and `unpack` functions.
+///
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::{Packable, PackableExt};
+///
+/// mod be_u32 {
+///     use core::convert::Infallible;
+///
+///     use packable::{error::UnpackError, packer::Packer, unpacker::Unpacker};
+///
+///     pub fn pack<P: Packer>(value: &u32, packer: &mut P) -> Result<(), P::Error> {
+///         packer.pack_bytes(value.to_be_bytes())
+///     }
+///
+///     pub fn unpack<U: Unpacker>(
+///         unpacker: &mut U,
+///         _: Option<&()>,
+///     ) -> Result<u32, UnpackError<Infallible, U::Error>> {
+///         let mut bytes = [0u8; 4];
+///         unpacker.unpack_bytes(&mut bytes)?;
+///
+///         Ok(u32::from_be_bytes(bytes))
+///     }
+/// }
+///
+/// #[derive(Packable)]
+/// pub struct Header {
+///     version: u8,
+///     #[packable(with = be_u32)]
+///     len: u32,
+/// }
+///
+/// assert_eq!(Header { version: 1, len: 2 }.pack_to_vec(), [1, 0, 0, 0, 2]);
+/// ```
+///
+/// The default `UnpackError` and `UnpackVisitor` types are taken from the type of the first
+/// field, so they must be specified explicitly if this field does not implement [`Packable`].
+/// Fields with custom functions cannot be length-prefixed.
+///
+/// ## Skipped fields
+///
+/// A field with the `#[packable(skip)]` attribute, such as a cache or a value derived from the
+/// other fields, is not packed and it is set to its [`Default`] value when unpacking. The
+/// `#[packable(skip, default = ...)]` attribute can be used instead to set it to the value of a
+/// Rust expression. Skipped fields cannot have any other attribute.
+///
+/// The `#[packable(post_unpack = ...)]` attribute can be applied to a `struct` to compute its
+/// skipped fields from the unpacked ones. It must receive a valid Rust path refering to a function
+/// with the signature
+/// ```ignore
+/// fn<P: Packable>(unpacked: &mut P)
+/// ```
+/// which is run after all the fields have been unpacked and before any `verify_with` function of
+/// the `struct`.
+///
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::{Packable, PackableExt};
+///
+/// #[derive(Debug, PartialEq, Packable)]
+/// #[packable(post_unpack = Rectangle::compute_area)]
+/// pub struct Rectangle {
+///     width: u16,
+///     height: u16,
+///     #[packable(skip)]
+///     area: u32,
+/// }
+///
+/// impl Rectangle {
+///     fn compute_area(&mut self) {
+///         self.area = u32::from(self.width) * u32::from(self.height);
+///     }
+/// }
+///
+/// let rectangle = Rectangle::unpack_bytes_verified([2, 0, 3, 0], &()).unwrap();
+///
+/// assert_eq!(rectangle.area, 6);
+/// assert_eq!(rectangle.pack_to_vec(), [2, 0, 3, 0]);
+/// ```
+///
+/// ## Packed length
+///
+/// The derive macro computes [`Packable::PACKED_LEN`] from the lengths of the fields. For an
+/// `enum`, the length is only known if all of its variants have the same packed length. The length
+/// of fields with custom functions is never known. [`Packable::MAX_PACKED_LEN`] is computed in the
+/// same way from the largest lengths of the fields and variants.
+///
+/// ## Skipping values
+///
+/// The derive macro also implements [`Packable::skip`] by skipping every field except the ones
+/// with a `verify_with` attribute or custom functions, which are unpacked. Types with a
+/// `verify_with` attribute at the `struct`, `enum` or variant level are unpacked entirely.
+///
+/// ## Unknown variants
+///
+/// An `enum` can have a variant with the `#[packable(unknown)]` attribute to unpack the variants
+/// whose `tag` is not known, for example because they were added by a newer version of a protocol.
+/// This variant must have exactly two fields: the `tag`, whose type is the `tag_type` of the enum,
+/// and the payload, whose type must implement [`UnknownPayload`], such as `VecPrefix<u8, u32>`. It
+/// has no `tag` of its own and it is packed byte-identically to the bytes it was unpacked from.
+///
+/// The payloads of all the variants of such an `enum` are prefixed by their length as a `u32` in
+/// little-endian order, right after the `tag`, so they can be captured without knowing their
+/// layout. A known variant can only read the number of bytes given by its prefix and unpacking it
+/// fails with [`DelimitedLenError`](crate::error::DelimitedLenError) if it does not consume all of
+/// them, which is the default [`UnpackError`](Packable::UnpackError) type for such an `enum` as
+/// unknown `tag`s cannot be found. Packing a known variant whose payload is longer than `u32::MAX`
+/// bytes **panics**.
+///
+/// The bytes of a known `tag` are always unpacked as its known variant, so an `unknown` variant
+/// with a known `tag` cannot be unpacked back and packing it is a logic error. The derive macro
+/// implements [`KnownTags`] for such an `enum` so these values can be refused when they are built.
+///
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::{prefix::VecPrefix, KnownTags, Packable, PackableExt};
+///
+/// #[derive(Debug, PartialEq, Packable)]
+/// #[packable(tag_type = u8)]
+/// pub enum Message {
+///     #[packable(tag = 0)]
+///     Ping,
+///     #[packable(tag = 1)]
+///     Data(u32),
+///     #[packable(unknown)]
+///     Unknown {
+///         tag: u8,
+///         payload: VecPrefix<u8, u32>,
+///     },
+/// }
+///
+/// let unknown = Message::unpack_bytes_verified([2, 1, 0, 0, 0, 42], &()).unwrap();
+///
+/// assert_eq!(
+///     unknown,
+///     Message::Unknown {
+///         tag: 2,
+///         payload: vec![42].try_into().unwrap()
+///     }
+/// );
+/// assert_eq!(unknown.pack_to_vec(), [2, 1, 0, 0, 0, 42]);
+/// assert!(Message::is_known_tag(&1));
+/// ```
+///
+/// Enums with an `unknown` variant cannot have a lifetime parameter.
+///
+/// ## Length-prefixed fields
+///
+/// A field can have the `#[packable(length_prefixed = u16)]` or `#[packable(length_prefixed = u32)]`
+/// attribute to be prefixed by its packed length, so it can be skipped without knowing its layout.
+/// When unpacking, the field can only read the number of bytes given by its prefix and it fails
+/// with [`DelimitedLenError`](crate::error::DelimitedLenError) if it does not consume all of them,
+/// so the [`UnpackError`](Packable::UnpackError) of the record must implement
+/// `From<DelimitedLenError>`.
+///
+/// The prefix must be able to represent the packed length of any value of the field, so the
+/// [`Packable::MAX_PACKED_LEN`] of the field type must be known and fit in the prefix, otherwise
+/// the record fails to compile. Fields with an unbounded length, such as a `Vec<u8>`, can use a
+/// bounded prefixed type from the [`prefix`](crate::prefix) module instead, such as
+/// `VecPrefix<u8, BoundedU16<0, 1024>>`, so values that are too long are rejected when they are
+/// built.
+///
+/// ```rust
+/// # use packable as packable_crate;
+/// use packable::{error::DelimitedLenError, Packable, PackableExt};
+///
+/// #[derive(Debug, PartialEq, Packable)]
+/// #[packable(unpack_error = DelimitedLenError)]
+/// pub struct Envelope {
+///     version: u8,
+///     #[packable(length_prefixed = u16)]
+///     body: u32,
+/// }
+///
+/// let envelope = Envelope {
+///     version: 1,
+///     body: 42,
+/// };
+///
+/// assert_eq!(envelope.pack_to_vec(), [1, 4, 0, 42, 0, 0, 0]);
+/// ```
+///
+/// Types with a lifetime parameter cannot have length-prefixed fields, and a length-prefixed field
+/// cannot contain a value of its own type as each level of nesting is unpacked with a different
+/// unpacker type.
+///
+/// ## Types with a lifetime parameter
+///
+/// Types with a lifetime parameter cannot implement [`Packable`]. When the derive macro is used on
+/// a `struct` or an `enum` with exactly one lifetime parameter, it implements
+/// [`UnpackBorrowed`](crate::UnpackBorrowed) for that lifetime instead so the fields can borrow
+/// data from the unpacked bytes. All the attributes described above are supported in this case.
 pub trait Packable: Sized + 'static {
     /// The error type that can be returned if some semantic error occurs while unpacking.
     ///
@@ -186,6 +436,18 @@ pub trait Packable: Sized + 'static {
     /// FIXME: docs
     type UnpackVisitor: Borrow<()>;
 
+    /// The length in bytes of any value of this type after being packed, if it is the same for all of them.
+    ///
+    /// When this is `Some`, it is used by [`PackableExt::packed_len`] instead of packing the value and it allows
+    /// packing values into arrays using [`PackableExt::pack_to_array`]. Returning a wrong length is a logic error.
+    const PACKED_LEN: Option<usize> = None;
+
+    /// The largest length in bytes of any value of this type after being packed, if it is bounded.
+    ///
+    /// It is used to check at compile time that the length prefix of a field can represent the packed length of any
+    /// value of that field. Returning a length smaller than the packed length of some value is a logic error.
+    const MAX_PACKED_LEN: Option<usize> = Self::PACKED_LEN;
+
     /// Packs this value into the given [`Packer`].
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error>;
 
@@ -209,6 +471,19 @@ pub trait Packable: Sized + 'static {
         Self::unpack(unpacker, None)
     }
 
+    /// Moves past a packed value in the given [`Unpacker`] without materializing it. The optional visitor can be used
+    /// to make additional syntactic checks.
+    ///
+    /// Length prefixes and tags are still unpacked and validated but checks that require the whole value, such as the
+    /// UTF-8 validity of strings or the ordering of sets and maps, might be omitted. The default implementation unpacks
+    /// the value and drops it.
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        Self::unpack(unpacker, visitor).map(drop)
+    }
+
     /// Unpacks an inner value whose visitor type can be borrowed from the outer visitor.
     fn unpack_inner<V: Borrow<Self::UnpackVisitor>, U: Unpacker>(
         unpacker: &mut U,
@@ -218,15 +493,58 @@ pub trait Packable: Sized + 'static {
     }
 }
 
+/// Assertion evaluated at compile time that `P::PACKED_LEN` is `Some(N)`.
+struct AssertPackedLen<P, const N: usize>(PhantomData<P>);
+
+impl<P: Packable, const N: usize> AssertPackedLen<P, N> {
+    const OK: () = assert!(
+        matches!(P::PACKED_LEN, Some(len) if len == N),
+        "the packed length of the value is not known or does not match the length of the array"
+    );
+}
+
+/// Unpacks a value from `bytes` using `f` and fails if any bytes remain after it.
+#[inline]
+fn unpack_bytes_strict<P: Packable>(
+    bytes: &[u8],
+    f: impl FnOnce(&mut SliceUnpacker) -> Result<P, UnpackError<P::UnpackError, UnexpectedEOF>>,
+) -> Result<P, UnpackError<P::UnpackError, UnpackBytesError>> {
+    let mut unpacker = SliceUnpacker::new(bytes);
+
+    let unpacked = f(&mut unpacker).map_err(|err| match err {
+        UnpackError::Packable(err) => UnpackError::Packable(err),
+        UnpackError::Unpacker(err) => UnpackError::Unpacker(err.into()),
+    })?;
+
+    if unpacker.is_empty() {
+        Ok(unpacked)
+    } else {
+        Err(UnpackError::Unpacker(UnpackBytesError::TrailingBytes {
+            remaining: unpacker.remaining(),
+        }))
+    }
+}
+
 /// Extension trait for types that implement [`Packable`].
 pub trait PackableExt: Packable {
     /// Returns the length in bytes of the value after being packed. The returned value always matches the number of
     /// bytes written using `pack`.
     fn packed_len(&self) -> usize;
 
-    /// Convenience method that packs this value into a [`Vec<u8>`].
+    /// Convenience method that packs this value into a [`Vec<u8>`](alloc::vec::Vec).
+    #[cfg(feature = "alloc")]
     fn pack_to_vec(&self) -> Vec<u8>;
 
+    /// Convenience method that packs this value into an array without allocating.
+    ///
+    /// Using this method fails to compile if [`Packable::PACKED_LEN`] is not `Some(N)`.
+    fn pack_to_array<const N: usize>(&self) -> [u8; N];
+
+    /// Convenience method that hashes the packed bytes of this value with `D` without allocating them. The returned
+    /// value always matches the hash of the bytes returned by `pack_to_vec`.
+    #[cfg(feature = "digest")]
+    fn hash_packed<D: digest::Digest + digest::Update>(&self) -> digest::Output<D>;
+
     /// Unpacks this value from a sequence of bytes doing syntactical checks.
     fn unpack_bytes_verified<T: AsRef<[u8]>>(
         bytes: T,
@@ -237,11 +555,43 @@ pub trait PackableExt: Packable {
     fn unpack_bytes_unverified<T: AsRef<[u8]>>(
         bytes: T,
     ) -> Result<Self, UnpackError<<Self as Packable>::UnpackError, UnexpectedEOF>>;
+
+    /// Unpacks this value from a sequence of bytes doing syntactical checks and failing if any bytes remain after the
+    /// value.
+    fn unpack_bytes_strict_verified<T: AsRef<[u8]>>(
+        bytes: T,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<<Self as Packable>::UnpackError, UnpackBytesError>>;
+
+    /// Unpacks this value from a sequence of bytes without doing syntactical checks and failing if any bytes remain
+    /// after the value.
+    fn unpack_bytes_strict_unverified<T: AsRef<[u8]>>(
+        bytes: T,
+    ) -> Result<Self, UnpackError<<Self as Packable>::UnpackError, UnpackBytesError>>;
+
+    /// Unpacks this value from the beginning of a sequence of bytes doing syntactical checks and returns it together
+    /// with the bytes that remain after it.
+    #[allow(clippy::type_complexity)]
+    fn unpack_bytes_partial<'a>(
+        bytes: &'a [u8],
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<(Self, &'a [u8]), UnpackError<Self::UnpackError, UnexpectedEOF>>;
+
+    /// Unpacks this value from a sequence of bytes doing syntactical checks and failing if any bytes remain after the
+    /// value or if packing the value again does not produce the exact same bytes.
+    fn unpack_bytes_canonical<T: AsRef<[u8]>>(
+        bytes: T,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<<Self as Packable>::UnpackError, UnpackBytesError>>;
 }
 
 impl<P: Packable> PackableExt for P {
     #[inline]
     fn packed_len(&self) -> usize {
+        if let Some(len) = Self::PACKED_LEN {
+            return len;
+        }
+
         let mut packer = LenPacker(0);
 
         match self.pack(&mut packer) {
@@ -250,6 +600,7 @@ impl<P: Packable> PackableExt for P {
         }
     }
 
+    #[cfg(feature = "alloc")]
     #[inline]
     fn pack_to_vec(&self) -> Vec<u8> {
         let mut packer = Vec::with_capacity(self.packed_len());
@@ -260,6 +611,29 @@ impl<P: Packable> PackableExt for P {
         packer
     }
 
+    #[inline]
+    fn pack_to_array<const N: usize>(&self) -> [u8; N] {
+        let () = AssertPackedLen::<Self, N>::OK;
+
+        let mut array = [0u8; N];
+
+        // Packing to a `SlicePacker` with exactly `PACKED_LEN` bytes cannot fail.
+        self.pack(&mut SlicePacker::new(&mut array)).unwrap();
+
+        array
+    }
+
+    #[cfg(feature = "digest")]
+    #[inline]
+    fn hash_packed<D: digest::Digest + digest::Update>(&self) -> digest::Output<D> {
+        let mut packer = crate::packer::DigestPacker::new(D::new());
+
+        // Packing to a `DigestPacker` cannot fail.
+        self.pack(&mut packer).unwrap();
+
+        packer.into_inner().finalize()
+    }
+
     /// Unpacks this value from a type that implements [`AsRef<[u8]>`].
     #[inline]
     fn unpack_bytes_verified<T: AsRef<[u8]>>(
@@ -276,4 +650,49 @@ impl<P: Packable> PackableExt for P {
     ) -> Result<Self, UnpackError<<Self as Packable>::UnpackError, UnexpectedEOF>> {
         Self::unpack_unverified(&mut SliceUnpacker::new(bytes.as_ref()))
     }
+
+    #[inline]
+    fn unpack_bytes_strict_verified<T: AsRef<[u8]>>(
+        bytes: T,
+        visitor: &P::UnpackVisitor,
+    ) -> Result<Self, UnpackError<<Self as Packable>::UnpackError, UnpackBytesError>> {
+        unpack_bytes_strict(bytes.as_ref(), |unpacker| Self::unpack_verified(unpacker, visitor))
+    }
+
+    #[inline]
+    fn unpack_bytes_strict_unverified<T: AsRef<[u8]>>(
+        bytes: T,
+    ) -> Result<Self, UnpackError<<Self as Packable>::UnpackError, UnpackBytesError>> {
+        unpack_bytes_strict(bytes.as_ref(), |unpacker| Self::unpack_unverified(unpacker))
+    }
+
+    #[inline]
+    fn unpack_bytes_partial<'a>(
+        bytes: &'a [u8],
+        visitor: &P::UnpackVisitor,
+    ) -> Result<(Self, &'a [u8]), UnpackError<Self::UnpackError, UnexpectedEOF>> {
+        let mut unpacker = SliceUnpacker::new(bytes);
+        let unpacked = Self::unpack_verified(&mut unpacker, visitor)?;
+
+        Ok((unpacked, unpacker.into_remaining()))
+    }
+
+    #[inline]
+    fn unpack_bytes_canonical<T: AsRef<[u8]>>(
+        bytes: T,
+        visitor: &P::UnpackVisitor,
+    ) -> Result<Self, UnpackError<<Self as Packable>::UnpackError, UnpackBytesError>> {
+        let bytes = bytes.as_ref();
+        let unpacked = Self::unpack_bytes_strict_verified(bytes, visitor)?;
+
+        let mut packer = ComparePacker::new(bytes);
+
+        // Packing to a `ComparePacker` cannot fail.
+        unpacked.pack(&mut packer).unwrap();
+
+        match packer.finish() {
+            None => Ok(unpacked),
+            Some(offset) => Err(UnpackError::Unpacker(UnpackBytesError::NonCanonical { offset })),
+        }
+    }
 }
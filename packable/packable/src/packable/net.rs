@@ -0,0 +1,182 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::Infallible;
+#[cfg(has_core_net)]
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+#[cfg(not(has_core_net))]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::{
+    error::{UnknownTagError, UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable,
+};
+
+/// IPv4 addresses are packed as their 4 octets in network order.
+impl Packable for Ipv4Addr {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    const PACKED_LEN: Option<usize> = Some(4);
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.octets().pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        <[u8; 4]>::unpack(unpacker, visitor).map(Self::from)
+    }
+}
+
+/// IPv6 addresses are packed as their 16 octets in network order.
+impl Packable for Ipv6Addr {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    const PACKED_LEN: Option<usize> = Some(16);
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.octets().pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        <[u8; 16]>::unpack(unpacker, visitor).map(Self::from)
+    }
+}
+
+/// IP addresses are packed and unpacked using `0u8` as the prefix for IPv4 and `1u8` as the prefix for IPv6.
+impl Packable for IpAddr {
+    type UnpackError = UnknownTagError<u8>;
+    type UnpackVisitor = ();
+
+    const MAX_PACKED_LEN: Option<usize> = crate::sum_packed_len(&[
+        Some(1),
+        crate::max_packed_len(&[Ipv4Addr::PACKED_LEN, Ipv6Addr::PACKED_LEN]),
+    ]);
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        match self {
+            Self::V4(addr) => {
+                0u8.pack(packer)?;
+                addr.pack(packer)
+            }
+            Self::V6(addr) => {
+                1u8.pack(packer)?;
+                addr.pack(packer)
+            }
+        }
+    }
+
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        match u8::unpack(unpacker, visitor).coerce()? {
+            0 => Ok(Self::V4(Ipv4Addr::unpack(unpacker, visitor).coerce()?)),
+            1 => Ok(Self::V6(Ipv6Addr::unpack(unpacker, visitor).coerce()?)),
+            tag => Err(UnpackError::Packable(UnknownTagError(tag))),
+        }
+    }
+}
+
+/// IPv4 socket addresses are packed as their IP address followed by their `u16` port.
+impl Packable for SocketAddrV4 {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    const PACKED_LEN: Option<usize> = Some(6);
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.ip().pack(packer)?;
+        self.port().pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let ip = Ipv4Addr::unpack(unpacker, visitor)?;
+        let port = u16::unpack(unpacker, visitor)?;
+
+        Ok(Self::new(ip, port))
+    }
+}
+
+/// IPv6 socket addresses are packed as their IP address followed by their `u16` port, their `u32` flow information
+/// and their `u32` scope ID.
+impl Packable for SocketAddrV6 {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    const PACKED_LEN: Option<usize> = Some(26);
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.ip().pack(packer)?;
+        self.port().pack(packer)?;
+        self.flowinfo().pack(packer)?;
+        self.scope_id().pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let ip = Ipv6Addr::unpack(unpacker, visitor)?;
+        let port = u16::unpack(unpacker, visitor)?;
+        let flowinfo = u32::unpack(unpacker, visitor)?;
+        let scope_id = u32::unpack(unpacker, visitor)?;
+
+        Ok(Self::new(ip, port, flowinfo, scope_id))
+    }
+}
+
+/// Socket addresses are packed and unpacked using `0u8` as the prefix for IPv4 and `1u8` as the prefix for IPv6.
+impl Packable for SocketAddr {
+    type UnpackError = UnknownTagError<u8>;
+    type UnpackVisitor = ();
+
+    const MAX_PACKED_LEN: Option<usize> = crate::sum_packed_len(&[
+        Some(1),
+        crate::max_packed_len(&[SocketAddrV4::PACKED_LEN, SocketAddrV6::PACKED_LEN]),
+    ]);
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        match self {
+            Self::V4(addr) => {
+                0u8.pack(packer)?;
+                addr.pack(packer)
+            }
+            Self::V6(addr) => {
+                1u8.pack(packer)?;
+                addr.pack(packer)
+            }
+        }
+    }
+
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        match u8::unpack(unpacker, visitor).coerce()? {
+            0 => Ok(Self::V4(SocketAddrV4::unpack(unpacker, visitor).coerce()?)),
+            1 => Ok(Self::V6(SocketAddrV6::unpack(unpacker, visitor).coerce()?)),
+            tag => Err(UnpackError::Packable(UnknownTagError(tag))),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{convert::Infallible, num::TryFromIntError};
+
+use crate::{
+    bounded::{saturating_len, Bounded},
+    error::UnpackError,
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable,
+};
+
+/// Wrapper type for an integer that is packed as an array of bytes in big-endian order.
+///
+/// This type implements [`Bounded`], so it can be used as the length prefix of the types provided in the
+/// [`prefix`](crate::prefix) module.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+#[repr(transparent)]
+pub struct BigEndian<T>(pub T);
+
+impl<T> BigEndian<T> {
+    /// Returns the wrapped integer.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+macro_rules! impl_big_endian {
+    ($ty:ty) => {
+        impl Packable for BigEndian<$ty> {
+            type UnpackError = Infallible;
+            type UnpackVisitor = ();
+
+            const PACKED_LEN: Option<usize> = Some(core::mem::size_of::<$ty>());
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                packer.pack_bytes(&self.0.to_be_bytes())
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker>(
+                unpacker: &mut U,
+                _: Option<&Self::UnpackVisitor>,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                let mut bytes = [0u8; core::mem::size_of::<$ty>()];
+                unpacker.unpack_bytes(&mut bytes)?;
+                Ok(Self(<$ty>::from_be_bytes(bytes)))
+            }
+
+            #[inline]
+            fn skip<U: Unpacker>(
+                unpacker: &mut U,
+                _: Option<&Self::UnpackVisitor>,
+            ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+                Ok(unpacker.skip_bytes(core::mem::size_of::<$ty>())?)
+            }
+        }
+
+        impl Bounded for BigEndian<$ty> {
+            type Bounds = $ty;
+
+            const MAX_LEN: usize = saturating_len(<$ty>::MAX as u128);
+        }
+
+        impl From<$ty> for BigEndian<$ty> {
+            fn from(value: $ty) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<BigEndian<$ty>> for $ty {
+            fn from(value: BigEndian<$ty>) -> Self {
+                value.0
+            }
+        }
+
+        impl TryFrom<usize> for BigEndian<$ty> {
+            type Error = TryFromIntError;
+
+            fn try_from(value: usize) -> Result<Self, Self::Error> {
+                <$ty>::try_from(value).map(Self)
+            }
+        }
+    };
+}
+
+impl_big_endian!(u16);
+impl_big_endian!(u32);
+impl_big_endian!(u64);
+#[cfg(has_u128)]
+impl_big_endian!(u128);
+
+impl_big_endian!(i16);
+impl_big_endian!(i32);
+impl_big_endian!(i64);
+#[cfg(has_i128)]
+impl_big_endian!(i128);
@@ -1,8 +1,19 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+//! Types and utilities used to pack and unpack integers with alternative encodings.
+
+mod big_endian;
+mod non_zero;
+mod varint;
+
 use core::convert::Infallible;
 
+pub use self::{
+    big_endian::BigEndian,
+    non_zero::UnpackNonZeroError,
+    varint::{UnpackVarIntError, VarInt},
+};
 use crate::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable};
 
 macro_rules! impl_packable_for_num {
@@ -11,6 +22,8 @@ macro_rules! impl_packable_for_num {
             type UnpackError = Infallible;
             type UnpackVisitor = ();
 
+            const PACKED_LEN: Option<usize> = Some(core::mem::size_of::<Self>());
+
             #[inline]
             fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
                 packer.pack_bytes(&self.to_le_bytes())
@@ -25,6 +38,14 @@ macro_rules! impl_packable_for_num {
                 unpacker.unpack_bytes(&mut bytes)?;
                 Ok(Self::from_le_bytes(bytes))
             }
+
+            #[inline]
+            fn skip<U: Unpacker>(
+                unpacker: &mut U,
+                _: Option<&Self::UnpackVisitor>,
+            ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+                Ok(unpacker.skip_bytes(core::mem::size_of::<Self>())?)
+            }
         }
     };
 }
@@ -51,6 +72,9 @@ impl Packable for usize {
     type UnpackError = core::num::TryFromIntError;
     type UnpackVisitor = ();
 
+    // Values are always packed as `u64`, whatever the pointer size of the platform is.
+    const PACKED_LEN: Option<usize> = Some(8);
+
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
         const _: () = {
@@ -77,6 +101,9 @@ impl Packable for isize {
     type UnpackError = core::num::TryFromIntError;
     type UnpackVisitor = ();
 
+    // Values are always packed as `i64`, whatever the pointer size of the platform is.
+    const PACKED_LEN: Option<usize> = Some(8);
+
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
         (*self as i64).pack(packer)
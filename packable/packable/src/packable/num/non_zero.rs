@@ -0,0 +1,69 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(has_u128)]
+use core::num::NonZeroU128;
+use core::{
+    convert::Infallible,
+    fmt,
+    num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8},
+};
+
+use crate::{
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable,
+};
+
+/// Error type raised when a zero value is unpacked as a non-zero integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnpackNonZeroError;
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnpackNonZeroError {}
+
+impl From<Infallible> for UnpackNonZeroError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl fmt::Display for UnpackNonZeroError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "zero value for a non-zero integer")
+    }
+}
+
+macro_rules! impl_packable_for_non_zero {
+    ($non_zero:ty, $ty:ty) => {
+        /// Non-zero integers are packed like their underlying integer type. Unpacking fails with
+        /// [`UnpackNonZeroError`] if the value is zero.
+        impl Packable for $non_zero {
+            type UnpackError = UnpackNonZeroError;
+            type UnpackVisitor = ();
+
+            const PACKED_LEN: Option<usize> = <$ty as Packable>::PACKED_LEN;
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                self.get().pack(packer)
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker>(
+                unpacker: &mut U,
+                visitor: Option<&Self::UnpackVisitor>,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                Self::new(<$ty>::unpack(unpacker, visitor).coerce()?).ok_or(UnpackError::Packable(UnpackNonZeroError))
+            }
+        }
+    };
+}
+
+impl_packable_for_non_zero!(NonZeroU8, u8);
+impl_packable_for_non_zero!(NonZeroU16, u16);
+impl_packable_for_non_zero!(NonZeroU32, u32);
+impl_packable_for_non_zero!(NonZeroU64, u64);
+#[cfg(has_u128)]
+impl_packable_for_non_zero!(NonZeroU128, u128);
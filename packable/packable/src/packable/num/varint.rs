@@ -0,0 +1,209 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{convert::Infallible, fmt, num::TryFromIntError};
+
+use crate::{
+    bounded::{saturating_len, Bounded},
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable,
+};
+
+/// Wrapper type for an integer that is packed as a variable-length integer.
+///
+/// Unsigned integers are packed using the unsigned LEB128 encoding: seven bits of the value are stored in each byte,
+/// least significant group first, and the most significant bit of each byte is set if more bytes follow. Signed
+/// integers are zigzag-encoded first, so values close to zero use few bytes regardless of their sign.
+///
+/// Only the shortest encoding of each value is accepted while unpacking.
+///
+/// This type implements [`Bounded`], so it can be used as the length prefix of the types provided in the
+/// [`prefix`](crate::prefix) module.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+#[repr(transparent)]
+pub struct VarInt<T>(pub T);
+
+impl<T> VarInt<T> {
+    /// Returns the wrapped integer.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// Error type raised when a variable-length integer cannot be unpacked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnpackVarIntError {
+    /// The encoding uses more bytes than required to represent any value of the integer type or its value does not
+    /// fit in the integer type.
+    Overlong,
+    /// The encoding is not the shortest one for its value.
+    NonMinimal,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnpackVarIntError {}
+
+impl fmt::Display for UnpackVarIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overlong => write!(f, "the variable-length integer is too long for its type"),
+            Self::NonMinimal => write!(f, "the variable-length integer is not minimally encoded"),
+        }
+    }
+}
+
+impl From<Infallible> for UnpackVarIntError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+macro_rules! impl_varint_common {
+    ($ty:ty) => {
+        impl Bounded for VarInt<$ty> {
+            type Bounds = $ty;
+
+            const MAX_LEN: usize = saturating_len(<$ty>::MAX as u128);
+        }
+
+        impl From<$ty> for VarInt<$ty> {
+            fn from(value: $ty) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<VarInt<$ty>> for $ty {
+            fn from(value: VarInt<$ty>) -> Self {
+                value.0
+            }
+        }
+
+        impl TryFrom<usize> for VarInt<$ty> {
+            type Error = TryFromIntError;
+
+            fn try_from(value: usize) -> Result<Self, Self::Error> {
+                <$ty>::try_from(value).map(Self)
+            }
+        }
+    };
+}
+
+macro_rules! impl_varint_unsigned {
+    ($ty:ty) => {
+        impl_varint_common!($ty);
+
+        impl Packable for VarInt<$ty> {
+            type UnpackError = UnpackVarIntError;
+            type UnpackVisitor = ();
+
+            const MAX_PACKED_LEN: Option<usize> = Some(<$ty>::BITS.div_ceil(7) as usize);
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                const MAX_LEN: usize = <$ty>::BITS.div_ceil(7) as usize;
+
+                let mut bytes = [0u8; MAX_LEN];
+                let mut value = self.0;
+                let mut len = 0;
+
+                loop {
+                    let byte = (value & 0x7f) as u8;
+                    value >>= 7;
+
+                    if value == 0 {
+                        bytes[len] = byte;
+                        len += 1;
+                        break;
+                    }
+
+                    bytes[len] = byte | 0x80;
+                    len += 1;
+                }
+
+                packer.pack_bytes(&bytes[..len])
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker>(
+                unpacker: &mut U,
+                visitor: Option<&Self::UnpackVisitor>,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                const MAX_LEN: u32 = <$ty>::BITS.div_ceil(7);
+
+                let mut value: $ty = 0;
+
+                for index in 0..MAX_LEN {
+                    let byte = u8::unpack(unpacker, visitor).coerce()?;
+                    let group = (byte & 0x7f) as $ty;
+                    let shift = index * 7;
+
+                    // The last byte can only hold the bits that are left in the integer type.
+                    if index == MAX_LEN - 1 && (group >> (<$ty>::BITS - shift)) != 0 {
+                        return Err(UnpackError::Packable(UnpackVarIntError::Overlong));
+                    }
+
+                    value |= group << shift;
+
+                    if byte & 0x80 == 0 {
+                        // A trailing zero group could have been omitted.
+                        if byte == 0 && index != 0 {
+                            return Err(UnpackError::Packable(UnpackVarIntError::NonMinimal));
+                        }
+
+                        return Ok(Self(value));
+                    }
+                }
+
+                Err(UnpackError::Packable(UnpackVarIntError::Overlong))
+            }
+        }
+    };
+}
+
+macro_rules! impl_varint_signed {
+    ($ty:ty, $unsigned:ty) => {
+        impl_varint_common!($ty);
+
+        impl Packable for VarInt<$ty> {
+            type UnpackError = UnpackVarIntError;
+            type UnpackVisitor = ();
+
+            const MAX_PACKED_LEN: Option<usize> = <VarInt<$unsigned> as Packable>::MAX_PACKED_LEN;
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                let zigzag = ((self.0 << 1) ^ (self.0 >> (<$ty>::BITS - 1))) as $unsigned;
+
+                VarInt(zigzag).pack(packer)
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker>(
+                unpacker: &mut U,
+                visitor: Option<&Self::UnpackVisitor>,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                let zigzag = VarInt::<$unsigned>::unpack(unpacker, visitor)?.0;
+
+                Ok(Self((zigzag >> 1) as $ty ^ -((zigzag & 1) as $ty)))
+            }
+        }
+    };
+}
+
+impl_varint_unsigned!(u8);
+impl_varint_unsigned!(u16);
+impl_varint_unsigned!(u32);
+impl_varint_unsigned!(u64);
+#[cfg(has_u128)]
+impl_varint_unsigned!(u128);
+
+impl_varint_signed!(i8, u8);
+impl_varint_signed!(i16, u16);
+impl_varint_signed!(i32, u32);
+impl_varint_signed!(i64, u64);
+#[cfg(has_i128)]
+impl_varint_signed!(i128, u128);
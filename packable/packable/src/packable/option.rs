@@ -44,6 +44,8 @@ impl<T: Packable> Packable for Option<T> {
     type UnpackError = UnpackOptionError<T::UnpackError>;
     type UnpackVisitor = T::UnpackVisitor;
 
+    const MAX_PACKED_LEN: Option<usize> = crate::sum_packed_len(&[Some(1), T::MAX_PACKED_LEN]);
+
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
         match self {
             None => 0u8.pack(packer),
@@ -66,4 +68,15 @@ impl<T: Packable> Packable for Option<T> {
             n => Err(UnpackError::Packable(Self::UnpackError::UnknownTag(n))),
         }
     }
+
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        match u8::unpack_inner(unpacker, visitor).coerce()? {
+            0 => Ok(()),
+            1 => T::skip(unpacker, visitor).map_packable_err(UnpackOptionError::Inner),
+            n => Err(UnpackError::Packable(Self::UnpackError::UnknownTag(n))),
+        }
+    }
 }
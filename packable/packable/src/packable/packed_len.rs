@@ -0,0 +1,87 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Utilities used to compute [`Packable::PACKED_LEN`](crate::Packable::PACKED_LEN) and
+//! [`Packable::MAX_PACKED_LEN`](crate::Packable::MAX_PACKED_LEN) at compile time.
+
+/// Returns the packed length of a sequence of values whose packed lengths are `lens`, if all of them are known.
+pub const fn sum_packed_len(lens: &[Option<usize>]) -> Option<usize> {
+    let mut total = 0usize;
+    let mut index = 0;
+
+    while index < lens.len() {
+        total = match lens[index] {
+            Some(len) => match total.checked_add(len) {
+                Some(total) => total,
+                None => return None,
+            },
+            None => return None,
+        };
+        index += 1;
+    }
+
+    Some(total)
+}
+
+/// Returns the packed length shared by all the alternatives whose packed lengths are `lens`, if all of them are known
+/// and equal.
+pub const fn same_packed_len(lens: &[Option<usize>]) -> Option<usize> {
+    let mut shared = None;
+    let mut index = 0;
+
+    while index < lens.len() {
+        shared = match (shared, lens[index]) {
+            (_, None) => return None,
+            (None, Some(len)) => Some(len),
+            (Some(shared), Some(len)) if shared == len => Some(shared),
+            (Some(_), Some(_)) => return None,
+        };
+        index += 1;
+    }
+
+    shared
+}
+
+/// Returns the largest of the packed lengths `lens` of a set of alternatives, if all of them are bounded.
+pub const fn max_packed_len(lens: &[Option<usize>]) -> Option<usize> {
+    let mut max = 0;
+    let mut index = 0;
+
+    while index < lens.len() {
+        max = match lens[index] {
+            Some(len) if len > max => len,
+            Some(_) => max,
+            None => return None,
+        };
+        index += 1;
+    }
+
+    Some(max)
+}
+
+/// Returns the largest packed length of a sequence prefixed by a length whose largest packed length is `prefix_len`,
+/// holding at most `max_len` items whose largest packed length is `item_len`, if all of them are bounded.
+pub const fn prefixed_max_packed_len(
+    prefix_len: Option<usize>,
+    max_len: usize,
+    item_len: Option<usize>,
+) -> Option<usize> {
+    let items_len = match item_len {
+        Some(0) => Some(0),
+        Some(len) => len.checked_mul(max_len),
+        None => None,
+    };
+
+    sum_packed_len(&[prefix_len, items_len])
+}
+
+/// Asserts that a length prefix whose largest representable length is `prefix_max_len` can represent the packed
+/// length of any value whose largest packed length is `max_packed_len`.
+///
+/// This is meant to be evaluated at compile time by the code generated by the derive macro.
+pub const fn check_length_prefix(max_packed_len: Option<usize>, prefix_max_len: usize) {
+    assert!(
+        matches!(max_packed_len, Some(len) if len <= prefix_max_len),
+        "the maximum packed length of a length-prefixed value is unbounded or does not fit in its prefix"
+    );
+}
@@ -0,0 +1,141 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, Range},
+    str::Utf8Error,
+};
+
+use arrayvec::ArrayString;
+
+use crate::{
+    bounded::Bounded,
+    error::UnpackError,
+    packable::Packable,
+    packer::Packer,
+    prefix::{array_vec::unpack_len, UnpackArrayPrefixError},
+    unpacker::Unpacker,
+};
+
+/// Wrapper type for [`ArrayString<CAP>`] with a length prefix.
+/// The [`ArrayString<CAP>`]'s prefix bounds are provided by `B`, where `B` is a [`Bounded`] type. The prefix
+/// type is the `Bounds` type associated with `B`.
+///
+/// This type does not allocate and it is packed exactly like a
+/// [`StringPrefix<B>`](crate::prefix::StringPrefix) with the same contents.
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct ArrayStringPrefix<B: Bounded, const CAP: usize> {
+    inner: ArrayString<CAP>,
+    bounded: PhantomData<B>,
+}
+
+impl<B: Bounded, const CAP: usize> fmt::Debug for ArrayStringPrefix<B, CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{:#?}", self.inner)
+        } else {
+            write!(f, "{:?}", self.inner)
+        }
+    }
+}
+
+impl<B: Bounded, const CAP: usize> Default for ArrayStringPrefix<B, CAP> {
+    fn default() -> Self {
+        Self {
+            inner: ArrayString::new(),
+            bounded: PhantomData,
+        }
+    }
+}
+
+impl<B: Bounded, const CAP: usize> Deref for ArrayStringPrefix<B, CAP> {
+    type Target = ArrayString<CAP>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<B: Bounded, const CAP: usize> From<ArrayStringPrefix<B, CAP>> for ArrayString<CAP> {
+    fn from(prefix: ArrayStringPrefix<B, CAP>) -> Self {
+        prefix.inner
+    }
+}
+
+impl<B, const CAP: usize> TryFrom<ArrayString<CAP>> for ArrayStringPrefix<B, CAP>
+where
+    B: Bounded,
+{
+    type Error = <B as TryFrom<usize>>::Error;
+
+    fn try_from(string: ArrayString<CAP>) -> Result<Self, Self::Error> {
+        B::try_from(string.len())?;
+
+        Ok(Self {
+            inner: string,
+            bounded: PhantomData,
+        })
+    }
+}
+
+impl<B, const CAP: usize> Packable for ArrayStringPrefix<B, CAP>
+where
+    B: Bounded + Packable<UnpackVisitor = ()>,
+    <B::Bounds as TryInto<B>>::Error: fmt::Debug,
+    <B as TryFrom<usize>>::Error: fmt::Debug,
+    Range<B::Bounds>: Iterator<Item = B::Bounds>,
+{
+    type UnpackError = UnpackArrayPrefixError<Utf8Error, B::UnpackError>;
+    type UnpackVisitor = ();
+
+    const MAX_PACKED_LEN: Option<usize> = crate::prefixed_max_packed_len(
+        B::MAX_PACKED_LEN,
+        if B::MAX_LEN < CAP { B::MAX_LEN } else { CAP },
+        Some(1),
+    );
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        // The length of any dynamically-sized sequence must be prefixed. This unwrap is fine since
+        // the length of the inner `ArrayString` has been validated while creating this `ArrayStringPrefix`.
+        B::try_from(self.len()).unwrap().pack(packer)?;
+
+        packer.pack_bytes(self.inner.as_bytes())?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let len = unpack_len::<B, CAP, _, _, _>(unpacker, visitor)?;
+
+        let mut bytes = [0u8; CAP];
+        unpacker.unpack_bytes(&mut bytes[..len])?;
+
+        let string =
+            core::str::from_utf8(&bytes[..len]).map_err(|e| UnpackError::Packable(UnpackArrayPrefixError::Item(e)))?;
+
+        Ok(Self {
+            // This unwrap is fine because `len` is not larger than `CAP`.
+            inner: ArrayString::from(string).unwrap(),
+            bounded: PhantomData,
+        })
+    }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        let len = unpack_len::<B, CAP, _, _, _>(unpacker, visitor)?;
+
+        Ok(unpacker.skip_bytes(len)?)
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{
+    any::TypeId,
+    borrow::Borrow,
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, Range},
+};
+
+use arrayvec::ArrayVec;
+
+use crate::{
+    error::{UnpackError, UnpackErrorExt},
+    packable::bounded::Bounded,
+    packer::Packer,
+    prefix::UnpackArrayPrefixError,
+    unpacker::{PathSegment, Unpacker},
+    Packable,
+};
+
+/// Wrapper type for [`ArrayVec<T, CAP>`] with a length prefix.
+/// The [`ArrayVec<T, CAP>`]'s prefix bounds are provided by `B`, where `B` is a [`Bounded`] type.
+/// The prefix type is the `Bounds` type associated with `B`.
+///
+/// This type does not allocate and it is packed exactly like a [`VecPrefix<T, B>`](crate::prefix::VecPrefix) with
+/// the same items.
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct ArrayVecPrefix<T, B: Bounded, const CAP: usize> {
+    inner: ArrayVec<T, CAP>,
+    bounded: PhantomData<B>,
+}
+
+impl<T: fmt::Debug, B: Bounded, const CAP: usize> fmt::Debug for ArrayVecPrefix<T, B, CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{:#?}", self.inner)
+        } else {
+            write!(f, "{:?}", self.inner)
+        }
+    }
+}
+
+impl<T, B: Bounded, const CAP: usize> Default for ArrayVecPrefix<T, B, CAP> {
+    fn default() -> Self {
+        Self {
+            inner: ArrayVec::new(),
+            bounded: PhantomData,
+        }
+    }
+}
+
+impl<T, B: Bounded, const CAP: usize> Deref for ArrayVecPrefix<T, B, CAP> {
+    type Target = ArrayVec<T, CAP>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, B: Bounded, const CAP: usize> From<ArrayVecPrefix<T, B, CAP>> for ArrayVec<T, CAP> {
+    fn from(prefix: ArrayVecPrefix<T, B, CAP>) -> Self {
+        prefix.inner
+    }
+}
+
+impl<T, B, const CAP: usize> TryFrom<ArrayVec<T, CAP>> for ArrayVecPrefix<T, B, CAP>
+where
+    B: Bounded,
+{
+    type Error = <B as TryFrom<usize>>::Error;
+
+    fn try_from(vec: ArrayVec<T, CAP>) -> Result<Self, Self::Error> {
+        B::try_from(vec.len())?;
+
+        Ok(Self {
+            inner: vec,
+            bounded: PhantomData,
+        })
+    }
+}
+
+impl<T, B, const CAP: usize> Packable for ArrayVecPrefix<T, B, CAP>
+where
+    T: Packable,
+    B: Bounded + Packable<UnpackVisitor = ()>,
+    <B::Bounds as TryInto<B>>::Error: fmt::Debug,
+    <B as TryFrom<usize>>::Error: fmt::Debug,
+    Range<B::Bounds>: Iterator<Item = B::Bounds>,
+{
+    type UnpackError = UnpackArrayPrefixError<T::UnpackError, B::UnpackError>;
+    type UnpackVisitor = T::UnpackVisitor;
+
+    const MAX_PACKED_LEN: Option<usize> = crate::prefixed_max_packed_len(
+        B::MAX_PACKED_LEN,
+        if B::MAX_LEN < CAP { B::MAX_LEN } else { CAP },
+        T::MAX_PACKED_LEN,
+    );
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        // The length of any dynamically-sized sequence must be prefixed. This unwrap is fine since
+        // the length of the inner `ArrayVec` has been validated while creating this `ArrayVecPrefix`.
+        B::try_from(self.len()).unwrap().pack(packer)?;
+
+        if TypeId::of::<T>() == TypeId::of::<u8>() {
+            // Safety: `T` is identical to `u8`.
+            let bytes = unsafe { &*(self.inner.as_slice() as *const [T] as *const [u8]) };
+            packer.pack_bytes(bytes)?;
+        } else {
+            for item in self.iter() {
+                item.pack(packer)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let len = unpack_len::<B, CAP, _, _, _>(unpacker, visitor)?;
+        let mut inner = ArrayVec::new();
+
+        unpacker.enter_nested()?;
+
+        for index in 0..len {
+            unpacker.push_path(PathSegment::Index(index));
+            let item = T::unpack(unpacker, visitor).map_packable_err(Self::UnpackError::Item)?;
+            // This cannot fail because `len` is not larger than `CAP`.
+            inner.push(item);
+            unpacker.pop_path();
+        }
+
+        unpacker.exit_nested();
+
+        Ok(Self {
+            inner,
+            bounded: PhantomData,
+        })
+    }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        let len = unpack_len::<B, CAP, _, _, _>(unpacker, visitor)?;
+
+        if TypeId::of::<T>() == TypeId::of::<u8>() {
+            unpacker.skip_bytes(len)?;
+        } else {
+            unpacker.enter_nested()?;
+
+            for _ in 0..len {
+                T::skip(unpacker, visitor).map_packable_err(Self::UnpackError::Item)?;
+            }
+
+            unpacker.exit_nested();
+        }
+
+        Ok(())
+    }
+}
+
+/// Unpacks a length prefix of type `B` and checks that it does not exceed `CAP`.
+#[inline]
+#[allow(clippy::type_complexity)]
+pub(super) fn unpack_len<B, const CAP: usize, T, U, V>(
+    unpacker: &mut U,
+    visitor: Option<&V>,
+) -> Result<usize, UnpackError<UnpackArrayPrefixError<T, B::UnpackError>, U::Error>>
+where
+    B: Bounded + Packable<UnpackVisitor = ()>,
+    U: Unpacker,
+    V: Borrow<()>,
+{
+    // The length of any dynamically-sized sequence must be prefixed.
+    let len = B::unpack_inner(unpacker, visitor)
+        .map_packable_err(UnpackArrayPrefixError::Prefix)?
+        .into();
+
+    match len.try_into() {
+        Ok(len) if len <= CAP => Ok(len),
+        _ => Err(UnpackError::Packable(UnpackArrayPrefixError::Capacity(CAP))),
+    }
+}
@@ -12,8 +12,12 @@ use core::{
 };
 
 use crate::{
-    error::UnpackError, packable::bounded::Bounded, packer::Packer, prefix::vec::VecPrefix, unpacker::Unpacker,
-    Packable,
+    error::UnpackError,
+    packable::{bounded::Bounded, unknown::sealed},
+    packer::Packer,
+    prefix::vec::VecPrefix,
+    unpacker::Unpacker,
+    Packable, UnknownPayload,
 };
 
 /// Wrapper type for `Box<[T]>` with a length prefix.
@@ -83,6 +87,21 @@ where
     }
 }
 
+impl sealed::Sealed for BoxedSlicePrefix<u8, u32> {
+    fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self {
+            inner: bytes.into_boxed_slice(),
+            bounded: PhantomData,
+        }
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.inner
+    }
+}
+
+impl UnknownPayload for BoxedSlicePrefix<u8, u32> {}
+
 impl<T, B> Packable for BoxedSlicePrefix<T, B>
 where
     T: Packable,
@@ -94,6 +113,8 @@ where
     type UnpackError = <VecPrefix<T, B> as Packable>::UnpackError;
     type UnpackVisitor = T::UnpackVisitor;
 
+    const MAX_PACKED_LEN: Option<usize> = <VecPrefix<T, B> as Packable>::MAX_PACKED_LEN;
+
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
         // The length of any dynamically-sized sequence must be prefixed. This unwrap is fine since
@@ -125,4 +146,12 @@ where
             bounded: PhantomData,
         })
     }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        VecPrefix::<T, B>::skip(unpacker, visitor)
+    }
 }
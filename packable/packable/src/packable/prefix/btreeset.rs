@@ -3,8 +3,9 @@
 
 extern crate alloc;
 
-use alloc::collections::BTreeSet;
+use alloc::{collections::BTreeSet, vec::Vec};
 use core::{
+    borrow::Borrow,
     fmt,
     marker::PhantomData,
     ops::{Deref, Range},
@@ -17,7 +18,7 @@ use crate::{
         set::{UnpackOrderedSetError, UnpackSetError},
     },
     packer::Packer,
-    unpacker::Unpacker,
+    unpacker::{PathSegment, Unpacker},
     Packable,
 };
 
@@ -81,6 +82,57 @@ where
     }
 }
 
+impl<T: Ord, B: Bounded> BTreeSetPrefix<T, B> {
+    /// Adds a value to the set if the new length is within the bounds of `B`. Returns whether the value was newly
+    /// inserted.
+    pub fn try_insert(&mut self, value: T) -> Result<bool, <B as TryFrom<usize>>::Error> {
+        if !self.inner.contains(&value) {
+            B::try_from(self.len() + 1)?;
+        }
+
+        Ok(self.inner.insert(value))
+    }
+
+    /// Adds all the values of an iterator to the set if the new length is within the bounds of `B`. No value is added
+    /// otherwise.
+    pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), <B as TryFrom<usize>>::Error> {
+        let mut values = iter.into_iter().collect::<BTreeSet<_>>();
+
+        B::try_from(self.len().saturating_add(values.difference(&self.inner).count()))?;
+        self.inner.append(&mut values);
+
+        Ok(())
+    }
+
+    /// Removes a value from the set if the new length is within the bounds of `B`. Returns whether the value was
+    /// present in the set.
+    pub fn remove<Q>(&mut self, value: &Q) -> Result<bool, <B as TryFrom<usize>>::Error>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        if self.inner.contains(value) {
+            B::try_from(self.len() - 1)?;
+        }
+
+        Ok(self.inner.remove(value))
+    }
+
+    /// Retains only the values specified by the predicate if the new length is within the bounds of `B`. No value is
+    /// removed otherwise.
+    pub fn retain(&mut self, f: impl FnMut(&T) -> bool) -> Result<(), <B as TryFrom<usize>>::Error> {
+        let keep = self.inner.iter().map(f).collect::<Vec<_>>();
+
+        B::try_from(keep.iter().filter(|keep| **keep).count())?;
+
+        let mut keep = keep.into_iter();
+        // This unwrap is fine since `keep` has exactly one item per value, visited in the same order.
+        self.inner.retain(|_| keep.next().unwrap());
+
+        Ok(())
+    }
+}
+
 impl<T: Ord, B> Packable for BTreeSetPrefix<T, B>
 where
     T: Packable,
@@ -92,6 +144,9 @@ where
     type UnpackError = UnpackOrderedSetError<T, T::UnpackError, B::UnpackError>;
     type UnpackVisitor = T::UnpackVisitor;
 
+    const MAX_PACKED_LEN: Option<usize> =
+        crate::prefixed_max_packed_len(B::MAX_PACKED_LEN, B::MAX_LEN, T::MAX_PACKED_LEN);
+
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
         // The length of any dynamically-sized sequence must be prefixed. This unwrap is fine since
@@ -120,7 +175,11 @@ where
 
         let mut set = BTreeSet::<T>::new();
 
-        for _ in B::Bounds::default()..len {
+        unpacker.enter_nested()?;
+
+        for (index, _) in (B::Bounds::default()..len).enumerate() {
+            unpacker.track_alloc(core::mem::size_of::<T>())?;
+            unpacker.push_path(PathSegment::Index(index));
             let item = T::unpack(unpacker, visitor)
                 .map_packable_err(UnpackSetError::Item)
                 .map_packable_err(Self::UnpackError::from)?;
@@ -140,8 +199,12 @@ where
             }
 
             set.insert(item);
+
+            unpacker.pop_path();
         }
 
+        unpacker.exit_nested();
+
         Ok(Self {
             inner: set,
             bounded: PhantomData,
@@ -3,7 +3,7 @@
 
 extern crate alloc;
 
-use alloc::collections::BTreeMap;
+use alloc::{collections::BTreeMap, vec::Vec};
 use core::{
     borrow::Borrow,
     fmt,
@@ -19,13 +19,16 @@ use crate::{
     map::{UnpackMapError, UnpackOrderedMapError},
     packable::bounded::Bounded,
     packer::Packer,
-    unpacker::Unpacker,
+    unpacker::{PathSegment, Unpacker},
     Packable,
 };
 
-/// Wrapper type for `HashMapPrefix<K, V>` with a length prefix.
+/// Wrapper type for `HashMap<K, V>` with a length prefix.
 /// The set's prefix bounds are provided by `B`, where `B` is a [`Bounded`] type.
 /// The prefix type is the `Bounds` type associated with `B`.
+///
+/// The entries are always packed sorted by key so the encoding of a map is canonical. Unpacking fails if the keys
+/// are not strictly increasing.
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
@@ -83,9 +86,77 @@ where
     }
 }
 
+impl<K: Eq + Hash, V: PartialEq, B: Bounded> HashMapPrefix<K, V, B> {
+    /// Inserts a key-value pair into the map if the new length is within the bounds of `B`. Returns the previous
+    /// value of the key, if any.
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, <B as TryFrom<usize>>::Error> {
+        if !self.inner.contains_key(&key) {
+            B::try_from(self.len() + 1)?;
+        }
+
+        Ok(self.inner.insert(key, value))
+    }
+
+    /// Inserts all the key-value pairs of an iterator into the map if the new length is within the bounds of `B`. No
+    /// pair is inserted otherwise.
+    pub fn try_extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) -> Result<(), <B as TryFrom<usize>>::Error> {
+        let entries = iter.into_iter().collect::<HashMap<_, _>>();
+        let added = entries.keys().filter(|key| !self.inner.contains_key(*key)).count();
+
+        B::try_from(self.len().saturating_add(added))?;
+        self.inner.extend(entries);
+
+        Ok(())
+    }
+
+    /// Removes a key from the map if the new length is within the bounds of `B`. Returns the value of the key, if
+    /// any.
+    pub fn remove<Q>(&mut self, key: &Q) -> Result<Option<V>, <B as TryFrom<usize>>::Error>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        if self.inner.contains_key(key) {
+            B::try_from(self.len() - 1)?;
+        }
+
+        Ok(self.inner.remove(key))
+    }
+
+    /// Retains only the entries specified by the predicate if the new length is within the bounds of `B`. No entry is
+    /// removed otherwise.
+    ///
+    /// The predicate is called on every entry before checking the new length, so the values it mutates stay mutated
+    /// even if no entry is removed.
+    pub fn retain(&mut self, mut f: impl FnMut(&K, &mut V) -> bool) -> Result<(), <B as TryFrom<usize>>::Error> {
+        let keep = self
+            .inner
+            .iter_mut()
+            .map(|(key, value)| f(key, value))
+            .collect::<Vec<_>>();
+
+        B::try_from(keep.iter().filter(|keep| **keep).count())?;
+
+        let mut keep = keep.into_iter();
+        // This unwrap is fine since `keep` has exactly one item per entry, visited in the same order.
+        self.inner.retain(|_, _| keep.next().unwrap());
+
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the value of a key. The length of the map cannot be changed through it.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.inner.get_mut(key)
+    }
+}
+
 impl<K, V, B> Packable for HashMapPrefix<K, V, B>
 where
-    K: Packable + Eq + Hash,
+    K: Packable + Ord + Hash,
     V: Packable + PartialEq,
     B: Bounded + Packable<UnpackVisitor = ()>,
     <B::Bounds as TryInto<B>>::Error: fmt::Debug,
@@ -93,16 +164,27 @@ where
     Range<B::Bounds>: Iterator<Item = B::Bounds>,
     V::UnpackVisitor: Borrow<K::UnpackVisitor>,
 {
-    type UnpackError = UnpackMapError<K, K::UnpackError, V::UnpackError, B::UnpackError>;
+    type UnpackError = UnpackOrderedMapError<K, K::UnpackError, V::UnpackError, B::UnpackError>;
     type UnpackVisitor = V::UnpackVisitor;
 
+    const MAX_PACKED_LEN: Option<usize> = crate::prefixed_max_packed_len(
+        B::MAX_PACKED_LEN,
+        B::MAX_LEN,
+        crate::sum_packed_len(&[K::MAX_PACKED_LEN, V::MAX_PACKED_LEN]),
+    );
+
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
         // The length of any dynamically-sized sequence must be prefixed. This unwrap is fine since
         // the length of the inner slice has been validated while creating this `HashMapPrefix`.
         B::try_from(self.len()).unwrap().pack(packer)?;
 
-        for (k, v) in self.iter() {
+        // The iteration order of a `HashMap` is not deterministic, the entries are sorted by key to
+        // produce a canonical encoding.
+        let mut entries = self.iter().collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|(k, _)| *k);
+
+        for (k, v) in entries {
             k.pack(packer)?;
             v.pack(packer)?;
         }
@@ -123,26 +205,44 @@ where
             .map_packable_err(Self::UnpackError::from)?
             .into();
 
-        let mut map = HashMap::<K, V>::new();
+        let mut entries = Vec::<(K, V)>::new();
 
-        for _ in B::Bounds::default()..len {
+        unpacker.enter_nested()?;
+
+        for (index, _) in (B::Bounds::default()..len).enumerate() {
+            unpacker.track_alloc(core::mem::size_of::<(K, V)>())?;
+            unpacker.push_path(PathSegment::Index(index));
             let key = K::unpack_inner(unpacker, visitor)
                 .map_packable_err(UnpackMapError::Key)
                 .map_packable_err(Self::UnpackError::from)?;
 
-            if map.contains_key(&key) {
-                return Err(UnpackError::Packable(UnpackMapError::DuplicateKey(key)));
+            if let Some((last, _)) = entries.last() {
+                match last.cmp(&key) {
+                    core::cmp::Ordering::Equal => {
+                        return Err(UnpackError::Packable(Self::UnpackError::Map(
+                            UnpackMapError::DuplicateKey(key),
+                        )));
+                    }
+                    core::cmp::Ordering::Greater => {
+                        return Err(UnpackError::Packable(Self::UnpackError::Unordered));
+                    }
+                    core::cmp::Ordering::Less => (),
+                }
             }
 
             let value = V::unpack(unpacker, visitor)
                 .map_packable_err(UnpackMapError::Value)
                 .map_packable_err(Self::UnpackError::from)?;
 
-            map.insert(key, value);
+            entries.push((key, value));
+
+            unpacker.pop_path();
         }
 
+        unpacker.exit_nested();
+
         Ok(Self {
-            inner: map,
+            inner: entries.into_iter().collect(),
             bounded: PhantomData,
         })
     }
@@ -208,6 +308,74 @@ where
     }
 }
 
+impl<K: Ord, V, B: Bounded> BTreeMapPrefix<K, V, B> {
+    /// Inserts a key-value pair into the map if the new length is within the bounds of `B`. Returns the previous
+    /// value of the key, if any.
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, <B as TryFrom<usize>>::Error> {
+        if !self.inner.contains_key(&key) {
+            B::try_from(self.len() + 1)?;
+        }
+
+        Ok(self.inner.insert(key, value))
+    }
+
+    /// Inserts all the key-value pairs of an iterator into the map if the new length is within the bounds of `B`. No
+    /// pair is inserted otherwise.
+    pub fn try_extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) -> Result<(), <B as TryFrom<usize>>::Error> {
+        let entries = iter.into_iter().collect::<BTreeMap<_, _>>();
+        let added = entries.keys().filter(|key| !self.inner.contains_key(*key)).count();
+
+        B::try_from(self.len().saturating_add(added))?;
+        self.inner.extend(entries);
+
+        Ok(())
+    }
+
+    /// Removes a key from the map if the new length is within the bounds of `B`. Returns the value of the key, if
+    /// any.
+    pub fn remove<Q>(&mut self, key: &Q) -> Result<Option<V>, <B as TryFrom<usize>>::Error>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        if self.inner.contains_key(key) {
+            B::try_from(self.len() - 1)?;
+        }
+
+        Ok(self.inner.remove(key))
+    }
+
+    /// Retains only the entries specified by the predicate if the new length is within the bounds of `B`. No entry is
+    /// removed otherwise.
+    ///
+    /// The predicate is called on every entry before checking the new length, so the values it mutates stay mutated
+    /// even if no entry is removed.
+    pub fn retain(&mut self, mut f: impl FnMut(&K, &mut V) -> bool) -> Result<(), <B as TryFrom<usize>>::Error> {
+        let keep = self
+            .inner
+            .iter_mut()
+            .map(|(key, value)| f(key, value))
+            .collect::<Vec<_>>();
+
+        B::try_from(keep.iter().filter(|keep| **keep).count())?;
+
+        let mut keep = keep.into_iter();
+        // This unwrap is fine since `keep` has exactly one item per entry, visited in the same order.
+        self.inner.retain(|_, _| keep.next().unwrap());
+
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the value of a key. The length of the map cannot be changed through it.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.inner.get_mut(key)
+    }
+}
+
 impl<K: Ord, V, B> Packable for BTreeMapPrefix<K, V, B>
 where
     K: Packable,
@@ -221,6 +389,12 @@ where
     type UnpackError = UnpackOrderedMapError<K, K::UnpackError, V::UnpackError, B::UnpackError>;
     type UnpackVisitor = V::UnpackVisitor;
 
+    const MAX_PACKED_LEN: Option<usize> = crate::prefixed_max_packed_len(
+        B::MAX_PACKED_LEN,
+        B::MAX_LEN,
+        crate::sum_packed_len(&[K::MAX_PACKED_LEN, V::MAX_PACKED_LEN]),
+    );
+
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
         // The length of any dynamically-sized sequence must be prefixed. This unwrap is fine since
@@ -250,7 +424,11 @@ where
 
         let mut map = BTreeMap::<K, V>::new();
 
-        for _ in B::Bounds::default()..len {
+        unpacker.enter_nested()?;
+
+        for (index, _) in (B::Bounds::default()..len).enumerate() {
+            unpacker.track_alloc(core::mem::size_of::<(K, V)>())?;
+            unpacker.push_path(PathSegment::Index(index));
             let key = K::unpack_inner(unpacker, visitor)
                 .map_packable_err(UnpackMapError::Key)
                 .map_packable_err(Self::UnpackError::from)?;
@@ -274,8 +452,12 @@ where
                 .map_packable_err(Self::UnpackError::from)?;
 
             map.insert(key, value);
+
+            unpacker.pop_path();
         }
 
+        unpacker.exit_nested();
+
         Ok(Self {
             inner: map,
             bounded: PhantomData,
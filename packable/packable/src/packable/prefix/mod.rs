@@ -3,18 +3,44 @@
 
 //! Types and utilities used to pack and unpack dynamic sequences of values with restricted length prefixes.
 
+#[cfg(feature = "arrayvec")]
+mod array_string;
+#[cfg(feature = "arrayvec")]
+mod array_vec;
+#[cfg(feature = "alloc")]
 mod boxed;
+#[cfg(feature = "alloc")]
 mod btreeset;
+#[cfg(feature = "alloc")]
 mod map;
+mod slice;
+#[cfg(feature = "alloc")]
+mod sorted_vec;
+mod str;
+#[cfg(feature = "alloc")]
 mod string;
+#[cfg(feature = "alloc")]
 mod vec;
 
 use core::{convert::Infallible, fmt};
 
+#[cfg(feature = "arrayvec")]
+pub use array_string::ArrayStringPrefix;
+#[cfg(feature = "arrayvec")]
+pub use array_vec::ArrayVecPrefix;
+#[cfg(feature = "alloc")]
 pub use boxed::BoxedSlicePrefix;
+#[cfg(feature = "alloc")]
 pub use btreeset::BTreeSetPrefix;
+#[cfg(feature = "alloc")]
 pub use map::{BTreeMapPrefix, HashMapPrefix};
+pub use slice::SlicePrefix;
+#[cfg(feature = "alloc")]
+pub use sorted_vec::{ItemKey, SortKey, SortedVecPrefix};
+pub use str::StrPrefix;
+#[cfg(feature = "alloc")]
 pub use string::StringPrefix;
+#[cfg(feature = "alloc")]
 pub use vec::VecPrefix;
 
 /// Semantic error raised while unpacking dynamically-sized sequences.
@@ -86,3 +112,114 @@ impl<T, E> From<Infallible> for UnpackPrefixError<T, E> {
         match err {}
     }
 }
+
+/// Semantic error raised while unpacking sequences of values with a fixed capacity.
+#[derive(Debug)]
+pub enum UnpackArrayPrefixError<T, E> {
+    /// Semantic error raised while unpacking an item of the sequence. Typically this is
+    /// [`Packable::UnpackError`](crate::Packable::UnpackError).
+    Item(T),
+    /// Semantic error raised when the length prefix cannot be unpacked.
+    Prefix(E),
+    /// The length prefix exceeds the capacity of the sequence.
+    Capacity(usize),
+}
+
+impl<T: fmt::Display, E: fmt::Display> fmt::Display for UnpackArrayPrefixError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Item(err) => write!(f, "cannot unpack item: {}", err),
+            Self::Prefix(err) => write!(f, "cannot unpack prefix: {}", err),
+            Self::Capacity(capacity) => write!(f, "the length prefix exceeds the capacity {}", capacity),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, E> std::error::Error for UnpackArrayPrefixError<T, E>
+where
+    T: std::error::Error,
+    E: std::error::Error,
+{
+}
+
+impl<T, E> From<UnpackPrefixError<T, E>> for UnpackArrayPrefixError<T, E> {
+    fn from(err: UnpackPrefixError<T, E>) -> Self {
+        match err {
+            UnpackPrefixError::Item(err) => Self::Item(err),
+            UnpackPrefixError::Prefix(err) => Self::Prefix(err),
+        }
+    }
+}
+
+impl<T, E> From<Infallible> for UnpackArrayPrefixError<T, E> {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+/// Semantic error raised while unpacking sequences of values that must be strictly sorted.
+#[derive(Debug)]
+pub enum UnpackSortedPrefixError<T, E> {
+    /// Semantic error raised while unpacking an item of the sequence. Typically this is
+    /// [`Packable::UnpackError`](crate::Packable::UnpackError).
+    Item(T),
+    /// Semantic error raised when the length prefix cannot be unpacked.
+    Prefix(E),
+    /// The item at the given index is not strictly greater than the previous one.
+    Unsorted(usize),
+}
+
+impl<T: fmt::Display, E: fmt::Display> fmt::Display for UnpackSortedPrefixError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Item(err) => write!(f, "cannot unpack item: {}", err),
+            Self::Prefix(err) => write!(f, "cannot unpack prefix: {}", err),
+            Self::Unsorted(index) => write!(f, "item {} is not strictly sorted", index),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, E> std::error::Error for UnpackSortedPrefixError<T, E>
+where
+    T: std::error::Error,
+    E: std::error::Error,
+{
+}
+
+impl<T, E> From<UnpackPrefixError<T, E>> for UnpackSortedPrefixError<T, E> {
+    fn from(err: UnpackPrefixError<T, E>) -> Self {
+        match err {
+            UnpackPrefixError::Item(err) => Self::Item(err),
+            UnpackPrefixError::Prefix(err) => Self::Prefix(err),
+        }
+    }
+}
+
+impl<T, E> From<Infallible> for UnpackSortedPrefixError<T, E> {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+/// Error type raised when creating a sequence of values that must be strictly sorted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TryIntoSortedPrefixError<E> {
+    /// The length of the sequence is not within the bounds of its prefix.
+    Invalid(E),
+    /// The item at the given index is not strictly greater than the previous one.
+    Unsorted(usize),
+}
+
+impl<E: fmt::Display> fmt::Display for TryIntoSortedPrefixError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(err) => err.fmt(f),
+            Self::Unsorted(index) => write!(f, "item {} is not strictly sorted", index),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for TryIntoSortedPrefixError<E> where E: std::error::Error {}
@@ -0,0 +1,115 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{convert::Infallible, fmt, marker::PhantomData, ops::Deref};
+
+use crate::{
+    bounded::Bounded,
+    error::{UnexpectedEOF, UnpackError, UnpackErrorExt},
+    packable::{borrowed::UnpackBorrowed, Packable},
+    packer::Packer,
+    prefix::UnpackPrefixError,
+    unpacker::SliceUnpacker,
+};
+
+/// Wrapper type for a borrowed `&[u8]` with a length prefix.
+/// The slice's prefix bounds are provided by `B`, where `B` is a [`Bounded`] type. The prefix
+/// type is the `Bounds` type associated with `B`.
+///
+/// This type implements [`UnpackBorrowed`] and borrows the unpacked bytes from the input buffer.
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct SlicePrefix<'a, B: Bounded> {
+    inner: &'a [u8],
+    bounded: PhantomData<B>,
+}
+
+impl<'a, B: Bounded> fmt::Debug for SlicePrefix<'a, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{:#?}", self.inner)
+        } else {
+            write!(f, "{:?}", self.inner)
+        }
+    }
+}
+
+impl<'a, B: Bounded> Default for SlicePrefix<'a, B> {
+    fn default() -> Self {
+        Self {
+            inner: &[],
+            bounded: PhantomData,
+        }
+    }
+}
+
+impl<'a, B: Bounded> Deref for SlicePrefix<'a, B> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+
+impl<'a, B: Bounded> From<SlicePrefix<'a, B>> for &'a [u8] {
+    fn from(prefix: SlicePrefix<'a, B>) -> &'a [u8] {
+        prefix.inner
+    }
+}
+
+impl<'a, B> TryFrom<&'a [u8]> for SlicePrefix<'a, B>
+where
+    B: Bounded,
+{
+    type Error = <B as TryFrom<usize>>::Error;
+
+    fn try_from(slice: &'a [u8]) -> Result<Self, Self::Error> {
+        B::try_from(slice.len())?;
+
+        Ok(Self {
+            inner: slice,
+            bounded: PhantomData,
+        })
+    }
+}
+
+impl<'a, B> UnpackBorrowed<'a> for SlicePrefix<'a, B>
+where
+    B: Bounded + Packable<UnpackVisitor = ()>,
+    <B as TryFrom<usize>>::Error: fmt::Debug,
+{
+    type UnpackError = UnpackPrefixError<Infallible, B::UnpackError>;
+    type UnpackVisitor = ();
+
+    #[inline]
+    fn pack_borrowed<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        // The length of any dynamically-sized sequence must be prefixed. This unwrap is fine since
+        // the length of the inner slice has been validated while creating this `SlicePrefix`.
+        B::try_from(self.len()).unwrap().pack(packer)?;
+
+        packer.pack_bytes(self.inner)
+    }
+
+    #[inline]
+    fn unpack_borrowed(
+        unpacker: &mut SliceUnpacker<'a>,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, UnexpectedEOF>> {
+        // The length of any dynamically-sized sequence must be prefixed.
+        let len = B::unpack(unpacker, visitor)
+            .map_packable_err(UnpackPrefixError::Prefix)?
+            .into();
+
+        // If `len` does not fit in a `usize`, we panic. There is no way this sequence will fit in memory anyway.
+        let len = len
+            .try_into()
+            .ok()
+            .expect("the length prefix exceeds the pointer length of this platform");
+
+        Ok(Self {
+            inner: unpacker.unpack_slice(len)?,
+            bounded: PhantomData,
+        })
+    }
+}
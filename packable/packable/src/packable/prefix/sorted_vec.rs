@@ -0,0 +1,246 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{Deref, Range},
+};
+
+use crate::{
+    error::{UnpackError, UnpackErrorExt},
+    packable::bounded::Bounded,
+    packer::Packer,
+    prefix::{TryIntoSortedPrefixError, UnpackSortedPrefixError, VecPrefix},
+    unpacker::Unpacker,
+    Packable,
+};
+
+/// Trait used to extract the key by which the items of a [`SortedVecPrefix`] are sorted.
+///
+/// This trait is meant to be implemented by marker types, which allows sorting the same item type by different keys.
+pub trait SortKey<T> {
+    /// The type of the key.
+    type Key: Ord + ?Sized;
+
+    /// Returns the key of an item.
+    fn key(item: &T) -> &Self::Key;
+}
+
+/// A [`SortKey`] that sorts items by themselves.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ItemKey;
+
+impl<T: Ord> SortKey<T> for ItemKey {
+    type Key = T;
+
+    fn key(item: &T) -> &Self::Key {
+        item
+    }
+}
+
+/// Wrapper type for [`Vec<T>`] with a length prefix whose items are strictly sorted by the key provided by `K`, where
+/// `K` is a [`SortKey`] type. By default, items are sorted by themselves.
+/// The [`Vec<T>`]'s prefix bounds are provided by `B`, where `B` is a [`Bounded`] type.
+/// The prefix type is the `Bounds` type associated with `B`.
+///
+/// This type is packed exactly like a [`VecPrefix<T, B>`] with the same items. Since the keys are strictly increasing,
+/// they are also unique. Creating or unpacking a [`SortedVecPrefix`] fails with the index of the first item that
+/// breaks this order.
+pub struct SortedVecPrefix<T, B: Bounded, K = ItemKey> {
+    inner: VecPrefix<T, B>,
+    key: PhantomData<K>,
+}
+
+impl<T: Clone, B: Bounded + Clone, K> Clone for SortedVecPrefix<T, B, K> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            key: PhantomData,
+        }
+    }
+}
+
+impl<T: PartialEq, B: Bounded, K> PartialEq for SortedVecPrefix<T, B, K> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq, B: Bounded, K> Eq for SortedVecPrefix<T, B, K> {}
+
+impl<T: PartialOrd, B: Bounded, K> PartialOrd for SortedVecPrefix<T, B, K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Ord, B: Bounded, K> Ord for SortedVecPrefix<T, B, K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: Hash, B: Bounded, K> Hash for SortedVecPrefix<T, B, K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl<T: fmt::Debug, B: Bounded, K> fmt::Debug for SortedVecPrefix<T, B, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<T, B: Bounded, K> Default for SortedVecPrefix<T, B, K> {
+    fn default() -> Self {
+        Self {
+            inner: VecPrefix::default(),
+            key: PhantomData,
+        }
+    }
+}
+
+impl<T, B: Bounded, K> Deref for SortedVecPrefix<T, B, K> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, B: Bounded, K> From<SortedVecPrefix<T, B, K>> for Vec<T> {
+    fn from(prefix: SortedVecPrefix<T, B, K>) -> Self {
+        prefix.inner.into()
+    }
+}
+
+impl<T, B: Bounded, K> From<SortedVecPrefix<T, B, K>> for VecPrefix<T, B> {
+    fn from(prefix: SortedVecPrefix<T, B, K>) -> Self {
+        prefix.inner
+    }
+}
+
+impl<T, B: Bounded, K: SortKey<T>> TryFrom<VecPrefix<T, B>> for SortedVecPrefix<T, B, K> {
+    type Error = TryIntoSortedPrefixError<<B as TryFrom<usize>>::Error>;
+
+    fn try_from(vec: VecPrefix<T, B>) -> Result<Self, Self::Error> {
+        Self::from_vec_prefix(vec).map_err(TryIntoSortedPrefixError::Unsorted)
+    }
+}
+
+impl<T, B: Bounded, K: SortKey<T>> TryFrom<Vec<T>> for SortedVecPrefix<T, B, K> {
+    type Error = TryIntoSortedPrefixError<<B as TryFrom<usize>>::Error>;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        VecPrefix::try_from(vec)
+            .map_err(TryIntoSortedPrefixError::Invalid)?
+            .try_into()
+    }
+}
+
+impl<T, B: Bounded, K: SortKey<T>> SortedVecPrefix<T, B, K> {
+    // Fails with the index of the first item whose key is not strictly greater than the key of the previous item.
+    fn from_vec_prefix(inner: VecPrefix<T, B>) -> Result<Self, usize> {
+        match inner.windows(2).position(|pair| K::key(&pair[0]) >= K::key(&pair[1])) {
+            Some(index) => Err(index + 1),
+            None => Ok(Self {
+                inner,
+                key: PhantomData,
+            }),
+        }
+    }
+
+    /// Binary searches for the item with the given key. Returns the index of the item if it is found, or the index
+    /// where an item with that key could be inserted otherwise.
+    pub fn binary_search_key(&self, key: &K::Key) -> Result<usize, usize> {
+        self.inner.binary_search_by(|item| K::key(item).cmp(key))
+    }
+
+    /// Returns the item with the given key, if any.
+    pub fn find(&self, key: &K::Key) -> Option<&T> {
+        self.binary_search_key(key).ok().map(|index| &self.inner[index])
+    }
+
+    /// Returns `true` if there is an item with the given key.
+    pub fn contains_key(&self, key: &K::Key) -> bool {
+        self.binary_search_key(key).is_ok()
+    }
+
+    /// Inserts an item at the position given by its key if the new length is within the bounds of `B`. If there
+    /// already is an item with the same key, it is replaced and returned instead.
+    pub fn try_insert(&mut self, item: T) -> Result<Option<T>, <B as TryFrom<usize>>::Error> {
+        match self.binary_search_key(K::key(&item)) {
+            Ok(index) => Ok(Some(core::mem::replace(&mut self.inner.as_mut_slice()[index], item))),
+            Err(index) => {
+                self.inner.try_insert(index, item)?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Removes the item with the given key, if any, if the new length is within the bounds of `B`.
+    pub fn remove_key(&mut self, key: &K::Key) -> Result<Option<T>, <B as TryFrom<usize>>::Error> {
+        match self.binary_search_key(key) {
+            Ok(index) => self.inner.remove(index).map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+impl<T, B, K> Packable for SortedVecPrefix<T, B, K>
+where
+    T: Packable,
+    B: Bounded + Packable<UnpackVisitor = ()>,
+    <B::Bounds as TryInto<B>>::Error: fmt::Debug,
+    <B as TryFrom<usize>>::Error: fmt::Debug,
+    Range<B::Bounds>: Iterator<Item = B::Bounds>,
+    K: SortKey<T> + 'static,
+{
+    type UnpackError = UnpackSortedPrefixError<T::UnpackError, B::UnpackError>;
+    type UnpackVisitor = T::UnpackVisitor;
+
+    const MAX_PACKED_LEN: Option<usize> =
+        crate::prefixed_max_packed_len(B::MAX_PACKED_LEN, B::MAX_LEN, T::MAX_PACKED_LEN);
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.inner.pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let inner = VecPrefix::<T, B>::unpack(unpacker, visitor).coerce()?;
+
+        Self::from_vec_prefix(inner).map_err(|index| UnpackError::Packable(UnpackSortedPrefixError::Unsorted(index)))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, B: Bounded, K> serde::Serialize for SortedVecPrefix<T, B, K> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, B, K> serde::Deserialize<'de> for SortedVecPrefix<T, B, K>
+where
+    T: serde::Deserialize<'de>,
+    B: Bounded,
+    K: SortKey<T>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::from_vec_prefix(VecPrefix::deserialize(deserializer)?)
+            .map_err(|index| serde::de::Error::custom(format_args!("item {} is not strictly sorted", index)))
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{fmt, marker::PhantomData, ops::Deref, str::Utf8Error};
+
+use crate::{
+    bounded::Bounded,
+    error::{UnexpectedEOF, UnpackError, UnpackErrorExt},
+    packable::{borrowed::UnpackBorrowed, Packable},
+    packer::Packer,
+    prefix::UnpackPrefixError,
+    unpacker::SliceUnpacker,
+};
+
+/// Wrapper type for a borrowed `&str` with a length prefix.
+/// The string's prefix bounds are provided by `B`, where `B` is a [`Bounded`] type. The prefix
+/// type is the `Bounds` type associated with `B`.
+///
+/// This type implements [`UnpackBorrowed`] and borrows the unpacked string from the input buffer.
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct StrPrefix<'a, B: Bounded> {
+    inner: &'a str,
+    bounded: PhantomData<B>,
+}
+
+impl<'a, B: Bounded> fmt::Debug for StrPrefix<'a, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{:#?}", self.inner)
+        } else {
+            write!(f, "{:?}", self.inner)
+        }
+    }
+}
+
+impl<'a, B: Bounded> Default for StrPrefix<'a, B> {
+    fn default() -> Self {
+        Self {
+            inner: "",
+            bounded: PhantomData,
+        }
+    }
+}
+
+impl<'a, B: Bounded> Deref for StrPrefix<'a, B> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+
+impl<'a, B: Bounded> From<StrPrefix<'a, B>> for &'a str {
+    fn from(prefix: StrPrefix<'a, B>) -> &'a str {
+        prefix.inner
+    }
+}
+
+impl<'a, B> TryFrom<&'a str> for StrPrefix<'a, B>
+where
+    B: Bounded,
+{
+    type Error = <B as TryFrom<usize>>::Error;
+
+    fn try_from(string: &'a str) -> Result<Self, Self::Error> {
+        B::try_from(string.len())?;
+
+        Ok(Self {
+            inner: string,
+            bounded: PhantomData,
+        })
+    }
+}
+
+impl<'a, B> UnpackBorrowed<'a> for StrPrefix<'a, B>
+where
+    B: Bounded + Packable<UnpackVisitor = ()>,
+    <B as TryFrom<usize>>::Error: fmt::Debug,
+{
+    type UnpackError = UnpackPrefixError<Utf8Error, B::UnpackError>;
+    type UnpackVisitor = ();
+
+    #[inline]
+    fn pack_borrowed<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        // The length of any dynamically-sized sequence must be prefixed. This unwrap is fine since
+        // the length of the inner `&str` has been validated while creating this `StrPrefix`.
+        B::try_from(self.len()).unwrap().pack(packer)?;
+
+        packer.pack_bytes(self.inner.as_bytes())
+    }
+
+    #[inline]
+    fn unpack_borrowed(
+        unpacker: &mut SliceUnpacker<'a>,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, UnexpectedEOF>> {
+        // The length of any dynamically-sized sequence must be prefixed.
+        let len = B::unpack(unpacker, visitor)
+            .map_packable_err(UnpackPrefixError::Prefix)?
+            .into();
+
+        // If `len` does not fit in a `usize`, we panic. There is no way this sequence will fit in memory anyway.
+        let len = len
+            .try_into()
+            .ok()
+            .expect("the length prefix exceeds the pointer length of this platform");
+
+        let inner = core::str::from_utf8(unpacker.unpack_slice(len)?)
+            .map_err(|err| UnpackError::Packable(UnpackPrefixError::Item(err)))?;
+
+        Ok(Self {
+            inner,
+            bounded: PhantomData,
+        })
+    }
+}
@@ -13,7 +13,7 @@ use core::{
 use crate::{
     bounded::Bounded,
     error::{UnpackError, UnpackErrorExt},
-    packable::Packable,
+    packable::{capacity, Packable},
     packer::Packer,
     prefix::UnpackPrefixError,
     unpacker::Unpacker,
@@ -79,6 +79,49 @@ where
     }
 }
 
+impl<B: Bounded> StringPrefix<B> {
+    /// Appends a character to the end of the string if the new length in bytes is within the bounds of `B`.
+    pub fn try_push(&mut self, ch: char) -> Result<(), <B as TryFrom<usize>>::Error> {
+        B::try_from(self.len() + ch.len_utf8())?;
+        self.inner.push(ch);
+
+        Ok(())
+    }
+
+    /// Appends a string slice to the end of the string if the new length in bytes is within the bounds of `B`.
+    pub fn try_push_str(&mut self, string: &str) -> Result<(), <B as TryFrom<usize>>::Error> {
+        B::try_from(self.len().saturating_add(string.len()))?;
+        self.inner.push_str(string);
+
+        Ok(())
+    }
+
+    /// Removes the last character of the string and returns it, or `None` if it is empty, if the new length in bytes
+    /// is within the bounds of `B`.
+    pub fn pop(&mut self) -> Result<Option<char>, <B as TryFrom<usize>>::Error> {
+        if let Some(ch) = self.chars().next_back() {
+            B::try_from(self.len() - ch.len_utf8())?;
+        }
+
+        Ok(self.inner.pop())
+    }
+
+    /// Shortens the string to `len` bytes if `len` is within the bounds of `B`. This has no effect if `len` is
+    /// greater than or equal to the current length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` does not lie on a [`char`] boundary.
+    pub fn truncate(&mut self, len: usize) -> Result<(), <B as TryFrom<usize>>::Error> {
+        if len < self.len() {
+            B::try_from(len)?;
+            self.inner.truncate(len);
+        }
+
+        Ok(())
+    }
+}
+
 impl<B> Packable for StringPrefix<B>
 where
     B: Bounded + Packable<UnpackVisitor = ()>,
@@ -89,6 +132,8 @@ where
     type UnpackError = UnpackPrefixError<FromUtf8Error, B::UnpackError>;
     type UnpackVisitor = ();
 
+    const MAX_PACKED_LEN: Option<usize> = crate::prefixed_max_packed_len(B::MAX_PACKED_LEN, B::MAX_LEN, Some(1));
+
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
         // The length of any dynamically-sized sequence must be prefixed. This unwrap is fine since
@@ -116,8 +161,7 @@ where
             .ok()
             .expect("the length prefix exceeds the pointer length of this platform");
 
-        let mut bytes = alloc::vec![0u8; len];
-        unpacker.unpack_bytes(&mut bytes)?;
+        let bytes = capacity::unpack_bytes(unpacker, len)?;
 
         let inner = String::from_utf8(bytes).map_err(|e| UnpackError::Packable(UnpackPrefixError::Item(e)))?;
 
@@ -126,4 +170,23 @@ where
             bounded: PhantomData,
         })
     }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        // The length of any dynamically-sized sequence must be prefixed.
+        let len = B::unpack(unpacker, visitor)
+            .map_packable_err(UnpackPrefixError::Prefix)?
+            .into();
+
+        // If `len` does not fit in a `usize`, we panic. There is no way this sequence will fit in memory anyway.
+        let len = len
+            .try_into()
+            .ok()
+            .expect("the length prefix exceeds the pointer length of this platform");
+
+        Ok(unpacker.skip_bytes(len)?)
+    }
 }
@@ -13,11 +13,11 @@ use core::{
 
 use crate::{
     error::{UnpackError, UnpackErrorExt},
-    packable::bounded::Bounded,
+    packable::{bounded::Bounded, capacity, unknown::sealed},
     packer::Packer,
     prefix::UnpackPrefixError,
-    unpacker::Unpacker,
-    Packable,
+    unpacker::{PathSegment, Unpacker},
+    Packable, UnknownPayload,
 };
 
 /// Wrapper type for [`Vec<T>`] with a length prefix.
@@ -80,6 +80,105 @@ where
     }
 }
 
+impl sealed::Sealed for VecPrefix<u8, u32> {
+    fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self {
+            inner: bytes,
+            bounded: PhantomData,
+        }
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.inner
+    }
+}
+
+impl UnknownPayload for VecPrefix<u8, u32> {}
+
+impl<T, B: Bounded> VecPrefix<T, B> {
+    /// Appends an element to the back of the vector if the new length is within the bounds of `B`.
+    pub fn try_push(&mut self, value: T) -> Result<(), <B as TryFrom<usize>>::Error> {
+        B::try_from(self.len() + 1)?;
+        self.inner.push(value);
+
+        Ok(())
+    }
+
+    /// Inserts an element at position `index` if the new length is within the bounds of `B`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), <B as TryFrom<usize>>::Error> {
+        B::try_from(self.len() + 1)?;
+        self.inner.insert(index, value);
+
+        Ok(())
+    }
+
+    /// Appends all the elements of an iterator if the new length is within the bounds of `B`. No element is appended
+    /// otherwise.
+    pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), <B as TryFrom<usize>>::Error> {
+        let items = iter.into_iter().collect::<Vec<_>>();
+
+        B::try_from(self.len().saturating_add(items.len()))?;
+        self.inner.extend(items);
+
+        Ok(())
+    }
+
+    /// Removes the last element of the vector and returns it, or `None` if it is empty, if the new length is within
+    /// the bounds of `B`.
+    pub fn pop(&mut self) -> Result<Option<T>, <B as TryFrom<usize>>::Error> {
+        if !self.is_empty() {
+            B::try_from(self.len() - 1)?;
+        }
+
+        Ok(self.inner.pop())
+    }
+
+    /// Removes and returns the element at position `index` if the new length is within the bounds of `B`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Result<T, <B as TryFrom<usize>>::Error> {
+        B::try_from(self.len().saturating_sub(1))?;
+
+        Ok(self.inner.remove(index))
+    }
+
+    /// Shortens the vector to `len` elements if `len` is within the bounds of `B`. This has no effect if `len` is
+    /// greater than or equal to the current length.
+    pub fn truncate(&mut self, len: usize) -> Result<(), <B as TryFrom<usize>>::Error> {
+        if len < self.len() {
+            B::try_from(len)?;
+            self.inner.truncate(len);
+        }
+
+        Ok(())
+    }
+
+    /// Retains only the elements specified by the predicate if the new length is within the bounds of `B`. No element
+    /// is removed otherwise.
+    pub fn retain(&mut self, f: impl FnMut(&T) -> bool) -> Result<(), <B as TryFrom<usize>>::Error> {
+        let keep = self.inner.iter().map(f).collect::<Vec<_>>();
+
+        B::try_from(keep.iter().filter(|keep| **keep).count())?;
+
+        let mut keep = keep.into_iter();
+        // This unwrap is fine since `keep` has exactly one item per element.
+        self.inner.retain(|_| keep.next().unwrap());
+
+        Ok(())
+    }
+
+    /// Returns a mutable slice of the elements. The length of the vector cannot be changed through it.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.inner
+    }
+}
+
 impl<T, B> Packable for VecPrefix<T, B>
 where
     T: Packable,
@@ -91,6 +190,9 @@ where
     type UnpackError = UnpackPrefixError<T::UnpackError, B::UnpackError>;
     type UnpackVisitor = T::UnpackVisitor;
 
+    const MAX_PACKED_LEN: Option<usize> =
+        crate::prefixed_max_packed_len(B::MAX_PACKED_LEN, B::MAX_LEN, T::MAX_PACKED_LEN);
+
     #[inline]
     fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
         // The length of any dynamically-sized sequence must be prefixed. This unwrap is fine since
@@ -126,31 +228,72 @@ where
                 .ok()
                 .expect("the length prefix exceeds the pointer length of this platform");
 
-            let mut bytes = alloc::vec![0u8; len];
-            unpacker.unpack_bytes(&mut bytes)?;
+            let bytes = capacity::unpack_bytes(unpacker, len)?;
             // Safety: `Self` is identical to `VecPrefix<u8, B>` which has the same layout as
             // `Vec<u8>` thanks to `#[repr(transparent)]`.
             Ok(unsafe { core::mem::transmute::<Vec<u8>, Self>(bytes) })
         } else {
-            // If `len` fits in a `usize`, we use it as the capacity of the inner `Vec` to avoid extra
-            // allocations.
+            if let Ok(len) = len.try_into() {
+                capacity::hint_items::<T, _>(unpacker, len);
+            }
+
+            // If `len` fits in a `usize`, we use it to compute the initial capacity of the inner `Vec` to avoid
+            // extra allocations. The capacity is capped because `len` has not been validated against the input yet.
             //
             // If that is not the case, we avoid assuming anything about the memory capacity of the
             // current platform and initialize `inner` with capacity zero. Most of the time this will
             // cause the program to panic due to memory exhaustion or capacity overflow while calling
             // `inner.push` but that is a platform limitation and not an error that the `Packable`
             // infrastructure should handle.
-            let mut inner = Vec::with_capacity(len.try_into().unwrap_or(0));
+            let mut inner = Vec::with_capacity(len.try_into().map_or(0, capacity::initial_capacity::<T>));
 
-            for _ in B::Bounds::default()..len {
+            unpacker.enter_nested()?;
+
+            for (index, _) in (B::Bounds::default()..len).enumerate() {
+                unpacker.track_alloc(core::mem::size_of::<T>())?;
+                unpacker.push_path(PathSegment::Index(index));
                 let item = T::unpack(unpacker, visitor).map_packable_err(Self::UnpackError::Item)?;
                 inner.push(item);
+                unpacker.pop_path();
             }
 
+            unpacker.exit_nested();
+
             Ok(VecPrefix {
                 inner,
                 bounded: PhantomData,
             })
         }
     }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        // The length of any dynamically-sized sequence must be prefixed.
+        let len = B::unpack_inner(unpacker, visitor)
+            .map_packable_err(UnpackPrefixError::Prefix)?
+            .into();
+
+        if TypeId::of::<T>() == TypeId::of::<u8>() {
+            // If `len` does not fit in a `usize`, we panic. There is no way this sequence will fit in memory anyway.
+            let len = len
+                .try_into()
+                .ok()
+                .expect("the length prefix exceeds the pointer length of this platform");
+
+            unpacker.skip_bytes(len)?;
+        } else {
+            unpacker.enter_nested()?;
+
+            for _ in B::Bounds::default()..len {
+                T::skip(unpacker, visitor).map_packable_err(Self::UnpackError::Item)?;
+            }
+
+            unpacker.exit_nested();
+        }
+
+        Ok(())
+    }
 }
@@ -3,24 +3,115 @@
 
 use core::convert::Infallible;
 
-use primitive_types::U256;
-
-use crate::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable};
-
-impl Packable for U256 {
-    type UnpackError = Infallible;
-    type UnpackVisitor = ();
-
-    #[inline]
-    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
-        self.0.pack(packer)
-    }
-
-    #[inline]
-    fn unpack<U: Unpacker>(
-        unpacker: &mut U,
-        visitor: Option<&Self::UnpackVisitor>,
-    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
-        <[u64; 4]>::unpack(unpacker, visitor).map(Self)
-    }
+use primitive_types::{H160, H256, H512, U128, U256, U512};
+
+use crate::{error::UnpackError, num::BigEndian, packer::Packer, unpacker::Unpacker, Packable};
+
+macro_rules! impl_packable_for_uint {
+    ($ty:ident, $limbs:literal) => {
+        /// Packed as an array of bytes in little-endian order.
+        impl Packable for $ty {
+            type UnpackError = Infallible;
+            type UnpackVisitor = ();
+
+            const PACKED_LEN: Option<usize> = <[u64; $limbs]>::PACKED_LEN;
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                self.0.pack(packer)
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker>(
+                unpacker: &mut U,
+                visitor: Option<&Self::UnpackVisitor>,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                <[u64; $limbs]>::unpack(unpacker, visitor).map(Self)
+            }
+
+            #[inline]
+            fn skip<U: Unpacker>(
+                unpacker: &mut U,
+                visitor: Option<&Self::UnpackVisitor>,
+            ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+                <[u64; $limbs]>::skip(unpacker, visitor)
+            }
+        }
+
+        /// Packed as an array of bytes in big-endian order, as used by EVM encodings.
+        impl Packable for BigEndian<$ty> {
+            type UnpackError = Infallible;
+            type UnpackVisitor = ();
+
+            const PACKED_LEN: Option<usize> = Some($limbs * 8);
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                let mut bytes = [0u8; $limbs * 8];
+                self.0.to_big_endian(&mut bytes);
+                packer.pack_bytes(&bytes)
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker>(
+                unpacker: &mut U,
+                _: Option<&Self::UnpackVisitor>,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                let mut bytes = [0u8; $limbs * 8];
+                unpacker.unpack_bytes(&mut bytes)?;
+                Ok(Self($ty::from_big_endian(&bytes)))
+            }
+
+            #[inline]
+            fn skip<U: Unpacker>(
+                unpacker: &mut U,
+                _: Option<&Self::UnpackVisitor>,
+            ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+                Ok(unpacker.skip_bytes($limbs * 8)?)
+            }
+        }
+    };
 }
+
+impl_packable_for_uint!(U128, 2);
+impl_packable_for_uint!(U256, 4);
+impl_packable_for_uint!(U512, 8);
+
+macro_rules! impl_packable_for_hash {
+    ($ty:ident, $len:literal) => {
+        /// Packed as its raw bytes.
+        impl Packable for $ty {
+            type UnpackError = Infallible;
+            type UnpackVisitor = ();
+
+            const PACKED_LEN: Option<usize> = Some($len);
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                packer.pack_bytes(self.as_bytes())
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker>(
+                unpacker: &mut U,
+                _: Option<&Self::UnpackVisitor>,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                let mut bytes = [0u8; $len];
+                unpacker.unpack_bytes(&mut bytes)?;
+                Ok(Self(bytes))
+            }
+
+            #[inline]
+            fn skip<U: Unpacker>(
+                unpacker: &mut U,
+                _: Option<&Self::UnpackVisitor>,
+            ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+                Ok(unpacker.skip_bytes($len)?)
+            }
+        }
+    };
+}
+
+impl_packable_for_hash!(H160, 20);
+impl_packable_for_hash!(H256, 32);
+impl_packable_for_hash!(H512, 64);
@@ -0,0 +1,43 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::ops::Range;
+
+use crate::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable};
+
+/// Ranges are packed as their start bound followed by their end bound. No ordering between the bounds is enforced, as
+/// a [`Range`] whose end is not greater than its start is valid and empty.
+impl<T: Packable> Packable for Range<T> {
+    type UnpackError = T::UnpackError;
+    type UnpackVisitor = T::UnpackVisitor;
+
+    const PACKED_LEN: Option<usize> = crate::sum_packed_len(&[T::PACKED_LEN, T::PACKED_LEN]);
+
+    const MAX_PACKED_LEN: Option<usize> = crate::sum_packed_len(&[T::MAX_PACKED_LEN, T::MAX_PACKED_LEN]);
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.start.pack(packer)?;
+        self.end.pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let start = T::unpack(unpacker, visitor)?;
+        let end = T::unpack(unpacker, visitor)?;
+
+        Ok(start..end)
+    }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        T::skip(unpacker, visitor)?;
+        T::skip(unpacker, visitor)
+    }
+}
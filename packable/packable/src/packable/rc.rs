@@ -0,0 +1,65 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+extern crate alloc;
+
+use alloc::rc::Rc;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use core::ops::Deref;
+
+use crate::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable};
+
+macro_rules! impl_packable_for_rc {
+    ($rc:ident, $doc:literal) => {
+        #[doc = $doc]
+        impl<T: Packable> Packable for $rc<T> {
+            type UnpackError = T::UnpackError;
+            type UnpackVisitor = T::UnpackVisitor;
+
+            const PACKED_LEN: Option<usize> = T::PACKED_LEN;
+
+            const MAX_PACKED_LEN: Option<usize> = T::MAX_PACKED_LEN;
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                self.deref().pack(packer)
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker>(
+                unpacker: &mut U,
+                visitor: Option<&Self::UnpackVisitor>,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                unpacker.enter_nested()?;
+                unpacker.track_alloc(core::mem::size_of::<T>())?;
+                let inner = T::unpack(unpacker, visitor)?;
+                unpacker.exit_nested();
+
+                Ok($rc::new(inner))
+            }
+
+            #[inline]
+            fn skip<U: Unpacker>(
+                unpacker: &mut U,
+                visitor: Option<&Self::UnpackVisitor>,
+            ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+                unpacker.enter_nested()?;
+                T::skip(unpacker, visitor)?;
+                unpacker.exit_nested();
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_packable_for_rc!(
+    Rc,
+    "Reference-counted values are packed exactly like the value they point to."
+);
+#[cfg(target_has_atomic = "ptr")]
+impl_packable_for_rc!(
+    Arc,
+    "Atomically reference-counted values are packed exactly like the value they point to."
+);
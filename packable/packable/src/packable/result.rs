@@ -0,0 +1,97 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types and utilities related to packing and unpacking [`Result`] values.
+
+use core::{borrow::Borrow, convert::Infallible, fmt};
+
+use crate::{
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable,
+};
+
+/// Error type raised when a semantic error occurs while unpacking a result.
+#[derive(Debug)]
+pub enum UnpackResultError<T, E> {
+    /// The tag found while unpacking is not valid.
+    UnknownTag(u8),
+    /// A semantic error for the type of the `Ok` variant was raised.
+    Ok(T),
+    /// A semantic error for the type of the `Err` variant was raised.
+    Err(E),
+}
+
+#[cfg(feature = "std")]
+impl<T, E> std::error::Error for UnpackResultError<T, E>
+where
+    T: std::error::Error,
+    E: std::error::Error,
+{
+}
+
+impl<T, E> From<Infallible> for UnpackResultError<T, E> {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl<T: fmt::Display, E: fmt::Display> fmt::Display for UnpackResultError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownTag(tag) => write!(f, "unknown tag value {} for result", tag),
+            Self::Ok(err) => write!(f, "cannot unpack ok variant: {}", err),
+            Self::Err(err) => write!(f, "cannot unpack err variant: {}", err),
+        }
+    }
+}
+
+/// Results are packed and unpacked using `0u8` as the prefix for `Ok` and `1u8` as the prefix for `Err`.
+impl<T: Packable, E: Packable> Packable for Result<T, E>
+where
+    T::UnpackVisitor: Borrow<E::UnpackVisitor>,
+{
+    type UnpackError = UnpackResultError<T::UnpackError, E::UnpackError>;
+    type UnpackVisitor = T::UnpackVisitor;
+
+    const MAX_PACKED_LEN: Option<usize> =
+        crate::sum_packed_len(&[Some(1), crate::max_packed_len(&[T::MAX_PACKED_LEN, E::MAX_PACKED_LEN])]);
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        match self {
+            Ok(value) => {
+                0u8.pack(packer)?;
+                value.pack(packer)
+            }
+            Err(err) => {
+                1u8.pack(packer)?;
+                err.pack(packer)
+            }
+        }
+    }
+
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        match u8::unpack_inner(unpacker, visitor).coerce()? {
+            0 => Ok(Ok(T::unpack(unpacker, visitor).map_packable_err(UnpackResultError::Ok)?)),
+            1 => Ok(Err(
+                E::unpack_inner(unpacker, visitor).map_packable_err(UnpackResultError::Err)?
+            )),
+            n => Err(UnpackError::Packable(Self::UnpackError::UnknownTag(n))),
+        }
+    }
+
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        match u8::unpack_inner(unpacker, visitor).coerce()? {
+            0 => T::skip(unpacker, visitor).map_packable_err(UnpackResultError::Ok),
+            1 => E::skip(unpacker, visitor.map(Borrow::borrow)).map_packable_err(UnpackResultError::Err),
+            n => Err(UnpackError::Packable(Self::UnpackError::UnknownTag(n))),
+        }
+    }
+}
@@ -103,7 +103,12 @@ mod btreeset {
     use alloc::collections::BTreeSet;
 
     use super::*;
-    use crate::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable};
+    use crate::{
+        error::UnpackError,
+        packer::Packer,
+        unpacker::{PathSegment, Unpacker},
+        Packable,
+    };
 
     impl<T: Packable + Ord> Packable for BTreeSet<T> {
         type UnpackError = UnpackOrderedSetError<T, T::UnpackError, <usize as Packable>::UnpackError>;
@@ -135,7 +140,11 @@ mod btreeset {
 
             let mut set = BTreeSet::<T>::new();
 
-            for _ in 0..len {
+            unpacker.enter_nested()?;
+
+            for index in 0..len {
+                unpacker.track_alloc(core::mem::size_of::<T>())?;
+                unpacker.push_path(PathSegment::Index(index));
                 let item = T::unpack(unpacker, visitor)
                     .map_packable_err(UnpackSetError::Item)
                     .map_packable_err(Self::UnpackError::from)?;
@@ -155,8 +164,12 @@ mod btreeset {
                 }
 
                 set.insert(item);
+
+                unpacker.pop_path();
             }
 
+            unpacker.exit_nested();
+
             Ok(set)
         }
     }
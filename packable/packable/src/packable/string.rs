@@ -41,4 +41,12 @@ impl Packable for String {
 
         String::from_utf8(bytes).map_err(|e| UnpackError::Packable(Self::UnpackError::Item(e)))
     }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        Vec::<u8>::skip(unpacker, visitor).map_packable_err(|err| UnpackPrefixError::Prefix(err.into_prefix_err()))
+    }
 }
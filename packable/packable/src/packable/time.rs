@@ -0,0 +1,52 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::time::Duration;
+
+use crate::{
+    error::{InvalidDurationError, UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable,
+};
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// Durations are packed as the `u64` number of whole seconds followed by the `u32` number of nanoseconds of the
+/// fractional part. Unpacking fails with [`InvalidDurationError`] if the number of nanoseconds is not lower than one
+/// billion.
+impl Packable for Duration {
+    type UnpackError = InvalidDurationError;
+    type UnpackVisitor = ();
+
+    const PACKED_LEN: Option<usize> = Some(12);
+
+    #[inline]
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.as_secs().pack(packer)?;
+        self.subsec_nanos().pack(packer)
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let secs = u64::unpack(unpacker, visitor).coerce()?;
+        let nanos = u32::unpack(unpacker, visitor).coerce()?;
+
+        if nanos >= NANOS_PER_SEC {
+            return Err(UnpackError::Packable(InvalidDurationError(nanos)));
+        }
+
+        Ok(Duration::new(secs, nanos))
+    }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        Self::unpack(unpacker, visitor).map(drop)
+    }
+}
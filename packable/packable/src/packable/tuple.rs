@@ -21,6 +21,10 @@ macro_rules! tuple_impls {
                 type UnpackError = $FirstT::UnpackError;
                 type UnpackVisitor = $FirstT::UnpackVisitor;
 
+                const PACKED_LEN: Option<usize> = crate::sum_packed_len(&[$FirstT::PACKED_LEN, $($T::PACKED_LEN),*]);
+
+                const MAX_PACKED_LEN: Option<usize> = crate::sum_packed_len(&[$FirstT::MAX_PACKED_LEN, $($T::MAX_PACKED_LEN),*]);
+
                 fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
                     self.$first_idx.pack(packer)?;
                     $( self.$idx.pack(packer)?; )*
@@ -37,6 +41,16 @@ macro_rules! tuple_impls {
                             $( (<$T>::unpack_inner(unpacker, visitor).map_packable_err(Into::into))?,)*
                        ))
                 }
+
+                fn skip<U: Unpacker>(
+                    unpacker: &mut U,
+                    visitor: Option<&Self::UnpackVisitor>,
+                ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+                    <$FirstT>::skip(unpacker, visitor)?;
+                    $( <$T>::skip(unpacker, visitor.map(Borrow::borrow)).map_packable_err(Into::into)?; )*
+
+                    Ok(())
+                }
             }
         )*
     };
@@ -0,0 +1,73 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types and utilities related to the `unknown` variant of enums that derive [`Packable`](crate::Packable).
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::{packer::Packer, unpacker::Unpacker};
+
+/// Trait implemented by the derive macro for enums with an `unknown` variant, providing the tags of their known
+/// variants.
+///
+/// An `unknown` variant whose tag is one of these tags is packed as the known variant with that tag, so it cannot be
+/// unpacked back. Such values are never produced by unpacking and should be refused when they are built, for example
+/// using [`KnownTags::is_known_tag`].
+pub trait KnownTags {
+    /// The type of the tags.
+    type Tag: 'static;
+
+    /// The tags of the known variants.
+    const KNOWN_TAGS: &'static [Self::Tag];
+
+    /// Returns whether `tag` is the tag of a known variant.
+    fn is_known_tag(tag: &Self::Tag) -> bool
+    where
+        Self::Tag: PartialEq,
+    {
+        Self::KNOWN_TAGS.contains(tag)
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) mod sealed {
+    use super::Vec;
+
+    pub trait Sealed {
+        /// Builds the payload from bytes whose length fits in a `u32`.
+        fn from_bytes(bytes: Vec<u8>) -> Self;
+
+        /// Returns the bytes of the payload, whose length fits in a `u32`.
+        fn as_bytes(&self) -> &[u8];
+    }
+}
+
+/// A type that can hold the payload of the `unknown` variant of an enum that derives [`Packable`](crate::Packable).
+///
+/// The length of these payloads always fits in the `u32` prefix they are packed with. This trait is sealed and it is
+/// only implemented for [`VecPrefix<u8, u32>`](crate::prefix::VecPrefix) and
+/// [`BoxedSlicePrefix<u8, u32>`](crate::prefix::BoxedSlicePrefix).
+#[cfg(feature = "alloc")]
+pub trait UnknownPayload: sealed::Sealed {}
+
+/// Packs the payload of an `unknown` variant prefixed by its length as a `u32` in little-endian order.
+#[cfg(feature = "alloc")]
+pub fn pack_unknown_payload<T: UnknownPayload, P: Packer>(payload: &T, packer: &mut P) -> Result<(), P::Error> {
+    let payload = payload.as_bytes();
+
+    // This cannot truncate because the length of an `UnknownPayload` always fits in a `u32`.
+    packer.pack_bytes((payload.len() as u32).to_le_bytes())?;
+    packer.pack_bytes(payload)
+}
+
+/// Unpacks the payload of an `unknown` variant of `len` bytes without interpreting it, where `len` has been unpacked
+/// from a `u32` prefix.
+#[cfg(feature = "alloc")]
+pub fn unpack_unknown_payload<T: UnknownPayload, U: Unpacker>(unpacker: &mut U, len: usize) -> Result<T, U::Error> {
+    crate::packable::capacity::unpack_bytes(unpacker, len).map(T::from_bytes)
+}
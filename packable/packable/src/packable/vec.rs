@@ -3,14 +3,15 @@
 
 extern crate alloc;
 
-use alloc::{vec, vec::Vec};
+use alloc::vec::Vec;
 use core::any::TypeId;
 
 use crate::{
     error::{UnpackError, UnpackErrorExt},
+    packable::capacity,
     packer::Packer,
     prefix::UnpackPrefixError,
-    unpacker::Unpacker,
+    unpacker::{PathSegment, Unpacker},
     Packable,
 };
 
@@ -50,19 +51,52 @@ where
             .map_err(|err| UnpackError::Packable(UnpackPrefixError::Prefix(err)))?;
 
         if TypeId::of::<T>() == TypeId::of::<u8>() {
-            let mut bytes = vec![0u8; len];
-            unpacker.unpack_bytes(&mut bytes)?;
+            let bytes = capacity::unpack_bytes(unpacker, len)?;
             // Safety: `Self` is identical to `Vec<u8>`.
             Ok(unsafe { core::mem::transmute::<Vec<u8>, Self>(bytes) })
         } else {
-            let mut vec = Vec::with_capacity(len);
+            capacity::hint_items::<T, _>(unpacker, len);
 
-            for _ in 0..len {
+            let mut vec = Vec::with_capacity(capacity::initial_capacity::<T>(len));
+
+            unpacker.enter_nested()?;
+
+            for index in 0..len {
+                unpacker.track_alloc(core::mem::size_of::<T>())?;
+                unpacker.push_path(PathSegment::Index(index));
                 let item = T::unpack(unpacker, visitor).map_packable_err(Self::UnpackError::Item)?;
                 vec.push(item);
+                unpacker.pop_path();
             }
 
+            unpacker.exit_nested();
+
             Ok(vec)
         }
     }
+
+    #[inline]
+    fn skip<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+        let len = u64::unpack_inner(unpacker, visitor)
+            .coerce()?
+            .try_into()
+            .map_err(|err| UnpackError::Packable(UnpackPrefixError::Prefix(err)))?;
+
+        if TypeId::of::<T>() == TypeId::of::<u8>() {
+            unpacker.skip_bytes(len)?;
+        } else {
+            unpacker.enter_nested()?;
+
+            for _ in 0..len {
+                T::skip(unpacker, visitor).map_packable_err(Self::UnpackError::Item)?;
+            }
+
+            unpacker.exit_nested();
+        }
+
+        Ok(())
+    }
 }
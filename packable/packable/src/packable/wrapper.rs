@@ -0,0 +1,65 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{cmp::Reverse, convert::Infallible, marker::PhantomData, num::Wrapping};
+
+use crate::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable};
+
+/// Phantom data is packed as zero bytes.
+impl<T: ?Sized + 'static> Packable for PhantomData<T> {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    const PACKED_LEN: Option<usize> = Some(0);
+
+    #[inline]
+    fn pack<P: Packer>(&self, _packer: &mut P) -> Result<(), P::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn unpack<U: Unpacker>(
+        _unpacker: &mut U,
+        _visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        Ok(PhantomData)
+    }
+}
+
+macro_rules! impl_packable_for_wrapper {
+    ($wrapper:ident, $doc:literal) => {
+        #[doc = $doc]
+        impl<T: Packable> Packable for $wrapper<T> {
+            type UnpackError = T::UnpackError;
+            type UnpackVisitor = T::UnpackVisitor;
+
+            const PACKED_LEN: Option<usize> = T::PACKED_LEN;
+
+            const MAX_PACKED_LEN: Option<usize> = T::MAX_PACKED_LEN;
+
+            #[inline]
+            fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+                self.0.pack(packer)
+            }
+
+            #[inline]
+            fn unpack<U: Unpacker>(
+                unpacker: &mut U,
+                visitor: Option<&Self::UnpackVisitor>,
+            ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+                Ok($wrapper(T::unpack(unpacker, visitor)?))
+            }
+
+            #[inline]
+            fn skip<U: Unpacker>(
+                unpacker: &mut U,
+                visitor: Option<&Self::UnpackVisitor>,
+            ) -> Result<(), UnpackError<Self::UnpackError, U::Error>> {
+                T::skip(unpacker, visitor)
+            }
+        }
+    };
+}
+
+impl_packable_for_wrapper!(Wrapping, "Wrapping values are packed exactly like the value they wrap.");
+impl_packable_for_wrapper!(Reverse, "Reversed values are packed exactly like the value they wrap.");
@@ -0,0 +1,50 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+extern crate alloc;
+extern crate std;
+
+use alloc::vec::Vec;
+use std::io;
+
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::Packable;
+
+/// An asynchronous packer backed by [`AsyncWrite`].
+///
+/// Values are packed into an internal buffer which is then written into the underlying writer, producing exactly the
+/// same bytes as packing with any other [`Packer`](crate::packer::Packer).
+pub struct AsyncPacker<W: AsyncWrite + Unpin> {
+    writer: W,
+    buffer: Vec<u8>,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncPacker<W> {
+    /// Creates a new [`AsyncPacker`] from a value that implements [`AsyncWrite`].
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Packs a value and writes it into the underlying writer.
+    pub async fn pack<P: Packable>(&mut self, packable: &P) -> io::Result<()> {
+        self.buffer.clear();
+        // Packing to a `Vec<u8>` cannot fail.
+        packable.pack(&mut self.buffer).unwrap();
+
+        self.writer.write_all(&self.buffer).await
+    }
+
+    /// Flushes the underlying writer.
+    pub async fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush().await
+    }
+
+    /// Consumes the value to return the inner value that implements [`AsyncWrite`].
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
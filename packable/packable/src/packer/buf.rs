@@ -0,0 +1,69 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::Infallible;
+
+use bytes::{BufMut, BytesMut};
+
+use crate::{error::UnexpectedEOF, packer::Packer};
+
+impl Packer for BytesMut {
+    type Error = Infallible;
+
+    #[inline]
+    fn pack_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        self.extend_from_slice(bytes.as_ref());
+        Ok(())
+    }
+
+    #[inline]
+    fn written_bytes(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+/// A [`Packer`] backed by any type that implements [`BufMut`].
+pub struct BufMutPacker<B: BufMut> {
+    buf: B,
+    written: usize,
+}
+
+impl<B: BufMut> BufMutPacker<B> {
+    /// Creates a new [`BufMutPacker`] from a value that implements [`BufMut`].
+    pub fn new(buf: B) -> Self {
+        Self { buf, written: 0 }
+    }
+
+    /// Consumes the value to return the inner value that implements [`BufMut`].
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+}
+
+impl<B: BufMut> Packer for BufMutPacker<B> {
+    type Error = UnexpectedEOF;
+
+    #[inline]
+    fn pack_bytes<T: AsRef<[u8]>>(&mut self, bytes: T) -> Result<(), Self::Error> {
+        let bytes = bytes.as_ref();
+        let len = bytes.len();
+        let remaining = self.buf.remaining_mut();
+
+        if remaining < len {
+            return Err(UnexpectedEOF {
+                required: len,
+                had: remaining,
+            });
+        }
+
+        self.buf.put_slice(bytes);
+        self.written += len;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn written_bytes(&self) -> Option<usize> {
+        Some(self.written)
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::packer::Packer;
+
+/// A [`Packer`] that compares the packed bytes against an expected sequence of bytes instead of storing them.
+pub(crate) struct ComparePacker<'a> {
+    expected: &'a [u8],
+    offset: usize,
+    mismatch: Option<usize>,
+}
+
+impl<'a> ComparePacker<'a> {
+    pub(crate) fn new(expected: &'a [u8]) -> Self {
+        Self {
+            expected,
+            offset: 0,
+            mismatch: None,
+        }
+    }
+
+    /// Returns the offset of the first byte that differs from the expected ones, if any.
+    pub(crate) fn finish(self) -> Option<usize> {
+        match self.mismatch {
+            Some(offset) => Some(offset),
+            // Fewer bytes than expected were packed.
+            None if self.offset < self.expected.len() => Some(self.offset),
+            None => None,
+        }
+    }
+}
+
+impl<'a> Packer for ComparePacker<'a> {
+    type Error = core::convert::Infallible;
+
+    #[inline]
+    fn pack_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        let bytes = bytes.as_ref();
+
+        if self.mismatch.is_none() {
+            let expected = self.expected.get(self.offset..).unwrap_or_default();
+
+            // A mismatch past the end of the expected bytes means that more bytes than expected were packed.
+            if let Some(index) = (0..bytes.len()).find(|&index| expected.get(index) != Some(&bytes[index])) {
+                self.mismatch = Some(self.offset + index);
+            }
+        }
+
+        self.offset += bytes.len();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::Infallible;
+
+use digest::Update;
+
+use crate::packer::Packer;
+
+/// A [`Packer`] that feeds the packed bytes into a hasher instead of storing them.
+pub struct DigestPacker<D: Update> {
+    digest: D,
+    written: usize,
+}
+
+impl<D: Update> DigestPacker<D> {
+    /// Creates a new [`DigestPacker`] from a value that implements [`Update`].
+    pub fn new(digest: D) -> Self {
+        Self { digest, written: 0 }
+    }
+
+    /// Consumes the value to return the inner value that implements [`Update`].
+    pub fn into_inner(self) -> D {
+        self.digest
+    }
+}
+
+impl<D: Update> Packer for DigestPacker<D> {
+    type Error = Infallible;
+
+    #[inline]
+    fn pack_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        let bytes = bytes.as_ref();
+
+        self.digest.update(bytes);
+        self.written += bytes.len();
+
+        Ok(())
+    }
+
+    #[inline]
+    fn written_bytes(&self) -> Option<usize> {
+        Some(self.written)
+    }
+}
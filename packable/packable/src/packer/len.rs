@@ -3,8 +3,9 @@
 
 use crate::packer::Packer;
 
+/// A [`Packer`] that only counts the number of bytes written into it.
 #[repr(transparent)]
-pub(crate) struct LenPacker(pub(crate) usize);
+pub struct LenPacker(pub(crate) usize);
 
 impl Packer for LenPacker {
     type Error = core::convert::Infallible;
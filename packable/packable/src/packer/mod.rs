@@ -6,17 +6,33 @@
 //! The [`Packer`] trait represents types that can be used to write bytes into it. It can be thought as a `no_std`
 //! friendly alternative to the [`Write`](std::io::Write) trait.
 
+#[cfg(feature = "tokio")]
+mod async_io;
+#[cfg(feature = "bytes")]
+mod buf;
+mod compare;
 mod counter;
+#[cfg(feature = "digest")]
+mod digest;
 #[cfg(feature = "io")]
 mod io;
 mod len;
 mod slice;
+#[cfg(feature = "alloc")]
 mod vec;
 
+#[cfg(feature = "tokio")]
+pub use async_io::AsyncPacker;
+#[cfg(feature = "bytes")]
+pub use buf::BufMutPacker;
+pub(crate) use compare::ComparePacker;
 pub use counter::CounterPacker;
+#[cfg(feature = "digest")]
+pub use digest::DigestPacker;
 #[cfg(feature = "io")]
 pub use io::IoPacker;
-pub(crate) use len::LenPacker;
+#[doc(hidden)]
+pub use len::LenPacker;
 pub use slice::SlicePacker;
 
 /// A type that can pack any value that implements [`Packable`](crate::Packable).
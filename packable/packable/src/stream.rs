@@ -0,0 +1,169 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A module to pack and unpack streams of concatenated values that implement [`Packable`].
+//!
+//! A stream is made of packed values written one after the other without any delimiter. [`PackIter`] appends values
+//! to a stream through any [`Packer`] and [`UnpackIter`] iterates over the values of a stream until its
+//! [`ExhaustibleUnpacker`] is cleanly exhausted.
+
+use core::{fmt, iter::FusedIterator, marker::PhantomData};
+
+use crate::{error::UnpackError, packer::Packer, unpacker::ExhaustibleUnpacker, Packable};
+
+/// Error type raised by an [`UnpackIter`].
+#[derive(Debug)]
+pub enum UnpackIterError<T, U> {
+    /// Semantic error raised while unpacking a value. Typically this is
+    /// [`Packable::UnpackError`].
+    Packable(T),
+    /// Error produced by the unpacker. Typically this is [`Unpacker::Error`](crate::unpacker::Unpacker::Error).
+    Unpacker(U),
+    /// The stream ended in the middle of a value.
+    Truncated(U),
+}
+
+impl<T, U> From<UnpackError<T, U>> for UnpackIterError<T, U> {
+    fn from(err: UnpackError<T, U>) -> Self {
+        match err {
+            UnpackError::Packable(err) => Self::Packable(err),
+            UnpackError::Unpacker(err) => Self::Unpacker(err),
+        }
+    }
+}
+
+impl<T: fmt::Display, U: fmt::Display> fmt::Display for UnpackIterError<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Packable(err) => write!(f, "packable error while unpacking: {}", err),
+            Self::Unpacker(err) => write!(f, "unpacker error while unpacking: {}", err),
+            Self::Truncated(err) => write!(f, "truncated value at the end of the stream: {}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, U> std::error::Error for UnpackIterError<T, U>
+where
+    T: std::error::Error,
+    U: std::error::Error,
+{
+}
+
+/// An [`Iterator`] that unpacks values of type `T` from a stream of concatenated values.
+///
+/// The iterator stops once the [`ExhaustibleUnpacker`] has no bytes left between two values. If the stream ends in
+/// the middle of a value, [`UnpackIterError::Truncated`] is yielded instead. The iterator also stops after yielding
+/// any error, as the position of the unpacker in the stream is unknown at that point.
+pub struct UnpackIter<T: Packable, U: ExhaustibleUnpacker> {
+    unpacker: U,
+    visitor: Option<T::UnpackVisitor>,
+    done: bool,
+}
+
+impl<T: Packable, U: ExhaustibleUnpacker> UnpackIter<T, U> {
+    /// Creates a new [`UnpackIter`] that unpacks and verifies values using the given visitor.
+    #[inline]
+    pub fn new_verified(unpacker: U, visitor: T::UnpackVisitor) -> Self {
+        Self {
+            unpacker,
+            visitor: Some(visitor),
+            done: false,
+        }
+    }
+
+    /// Creates a new [`UnpackIter`] that unpacks values without verifying them.
+    #[inline]
+    pub fn new_unverified(unpacker: U) -> Self {
+        Self {
+            unpacker,
+            visitor: None,
+            done: false,
+        }
+    }
+
+    /// Consumes the value to return the inner [`Unpacker`](crate::unpacker::Unpacker).
+    #[inline]
+    pub fn into_inner(self) -> U {
+        self.unpacker
+    }
+}
+
+impl<T: Packable, U: ExhaustibleUnpacker> Iterator for UnpackIter<T, U> {
+    type Item = Result<T, UnpackIterError<T::UnpackError, U::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = match self.unpacker.is_exhausted() {
+            Ok(true) => {
+                self.done = true;
+                return None;
+            }
+            Ok(false) => T::unpack(&mut self.unpacker, self.visitor.as_ref()).map_err(|err| match err {
+                // A value has been started, so running out of bytes means that it was truncated.
+                UnpackError::Unpacker(err) if U::is_eof(&err) => UnpackIterError::Truncated(err),
+                err => err.into(),
+            }),
+            Err(err) => Err(UnpackIterError::Unpacker(err)),
+        };
+
+        self.done = result.is_err();
+
+        Some(result)
+    }
+}
+
+impl<T: Packable, U: ExhaustibleUnpacker> FusedIterator for UnpackIter<T, U> {}
+
+/// A writer that packs values of type `T` one after the other through a [`Packer`], producing a stream that can be
+/// read back using an [`UnpackIter`].
+pub struct PackIter<T: Packable, P: Packer> {
+    packer: P,
+    count: usize,
+    marker: PhantomData<fn(&T)>,
+}
+
+impl<T: Packable, P: Packer> PackIter<T, P> {
+    /// Creates a new [`PackIter`] that appends values to the given [`Packer`].
+    #[inline]
+    pub fn new(packer: P) -> Self {
+        Self {
+            packer,
+            count: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Packs a value at the end of the stream.
+    #[inline]
+    pub fn push(&mut self, value: &T) -> Result<(), P::Error> {
+        value.pack(&mut self.packer)?;
+        self.count += 1;
+
+        Ok(())
+    }
+
+    /// Packs every value of an [`Iterator`] at the end of the stream, stopping at the first error.
+    pub fn extend<'a, I>(&mut self, values: I) -> Result<(), P::Error>
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: 'a,
+    {
+        values.into_iter().try_for_each(|value| self.push(value))
+    }
+
+    /// Returns the number of values that have been packed.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Consumes the value to return the inner [`Packer`].
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.packer
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+extern crate alloc;
+extern crate std;
+
+use alloc::vec::Vec;
+use std::io;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::{
+    error::{UnexpectedEOF, UnpackError},
+    unpacker::{SliceUnpacker, Unpacker},
+    Packable,
+};
+
+/// The maximum number of bytes the buffer of an [`AsyncUnpacker`] grows by before reading from the underlying reader.
+const READ_CHUNK_LEN: usize = 8 * 1024;
+
+/// An asynchronous unpacker backed by [`AsyncRead`].
+///
+/// Bytes are read into an internal buffer until a complete value can be unpacked from it. Any bytes read past the end
+/// of a value are kept in the buffer and used to unpack the next one, so the same [`AsyncUnpacker`] should be used to
+/// unpack every value from the underlying reader.
+///
+/// The buffer only grows as bytes are read, so a length prefix cannot make it allocate more memory than the bytes
+/// that were actually received. The maximum length of a value can be limited with [`AsyncUnpacker::with_max_len`].
+///
+/// Unpacking restarts from the first byte of the value whenever the buffer runs out of bytes. The bytes hinted with
+/// [`Unpacker::hint_bytes`], like the ones of a sequence of items with a fixed [`Packable::PACKED_LEN`], are read at
+/// once, but other values may be unpacked again after each read.
+pub struct AsyncUnpacker<R: AsyncRead + Unpin> {
+    reader: R,
+    buffer: Vec<u8>,
+    max_len: usize,
+}
+
+impl<R: AsyncRead + Unpin> AsyncUnpacker<R> {
+    /// Creates a new [`AsyncUnpacker`] from a value that implements [`AsyncRead`].
+    pub fn new(reader: R) -> Self {
+        Self::with_max_len(usize::MAX, reader)
+    }
+
+    /// Creates a new [`AsyncUnpacker`] from a value that implements [`AsyncRead`] that fails with an
+    /// [`InvalidData`](io::ErrorKind::InvalidData) error instead of buffering more than `max_len` bytes to unpack a
+    /// value.
+    pub fn with_max_len(max_len: usize, reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            max_len,
+        }
+    }
+
+    /// Returns the maximum number of bytes that can be buffered to unpack a value.
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Reads and unpacks a value doing syntactical checks.
+    pub async fn unpack_verified<P: Packable>(
+        &mut self,
+        visitor: &P::UnpackVisitor,
+    ) -> Result<P, UnpackError<P::UnpackError, io::Error>> {
+        self.unpack(Some(visitor)).await
+    }
+
+    /// Reads and unpacks a value without doing syntactical checks.
+    pub async fn unpack_unverified<P: Packable>(&mut self) -> Result<P, UnpackError<P::UnpackError, io::Error>> {
+        self.unpack(None).await
+    }
+
+    async fn unpack<P: Packable>(
+        &mut self,
+        visitor: Option<&P::UnpackVisitor>,
+    ) -> Result<P, UnpackError<P::UnpackError, io::Error>> {
+        loop {
+            let mut unpacker = HintUnpacker {
+                inner: SliceUnpacker::new(&self.buffer),
+                hinted_len: 0,
+            };
+
+            match P::unpack(&mut unpacker, visitor) {
+                Ok(packable) => {
+                    let len = unpacker.read_len();
+                    self.buffer.drain(..len);
+
+                    return Ok(packable);
+                }
+                Err(UnpackError::Packable(err)) => return Err(UnpackError::Packable(err)),
+                // The buffer does not hold a complete value yet, read at least the missing and hinted bytes and try
+                // again.
+                Err(UnpackError::Unpacker(UnexpectedEOF { required, had })) => {
+                    let missing = (required - had).max(unpacker.hinted_len.saturating_sub(self.buffer.len()));
+                    self.fill_buffer(missing).await.map_err(UnpackError::Unpacker)?;
+                }
+            }
+        }
+    }
+
+    async fn fill_buffer(&mut self, missing: usize) -> io::Result<()> {
+        let len = self.buffer.len().saturating_add(missing);
+
+        if len > self.max_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the value exceeds the maximum length of the unpacker",
+            ));
+        }
+
+        while self.buffer.len() < len {
+            // Only reserve a bounded chunk at a time, so the buffer grows with the bytes that are actually read.
+            self.buffer.reserve((len - self.buffer.len()).min(READ_CHUNK_LEN));
+
+            if self.reader.read_buf(&mut self.buffer).await? == 0 {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the bytes that have been read from the underlying reader but not unpacked yet.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Consumes the value to return the inner value that implements [`AsyncRead`]. Any buffered bytes are discarded.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+/// A [`SliceUnpacker`] over the buffer of an [`AsyncUnpacker`] that records the bytes hinted while unpacking.
+struct HintUnpacker<'u> {
+    inner: SliceUnpacker<'u>,
+    /// The length of the buffer prefix that is known to be needed to unpack the value.
+    hinted_len: usize,
+}
+
+impl<'u> HintUnpacker<'u> {
+    fn read_len(&self) -> usize {
+        // A `SliceUnpacker` always knows the number of read bytes.
+        self.inner.read_bytes().unwrap_or_default()
+    }
+}
+
+impl<'u> Unpacker for HintUnpacker<'u> {
+    type Error = UnexpectedEOF;
+
+    #[inline]
+    fn unpack_bytes<B: AsMut<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        self.inner.unpack_bytes(bytes)
+    }
+
+    #[inline]
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        self.inner.skip_bytes(len)
+    }
+
+    #[inline]
+    fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
+        self.inner.ensure_bytes(len)
+    }
+
+    #[inline]
+    fn hint_bytes(&mut self, len: usize) {
+        self.hinted_len = self.hinted_len.max(self.read_len().saturating_add(len));
+    }
+
+    #[inline]
+    fn read_bytes(&self) -> Option<usize> {
+        self.inner.read_bytes()
+    }
+
+    #[inline]
+    fn peek_bytes(&mut self, len: usize) -> Result<Option<&[u8]>, Self::Error> {
+        self.inner.peek_bytes(len)
+    }
+}
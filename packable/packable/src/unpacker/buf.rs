@@ -0,0 +1,91 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bytes::Buf;
+
+use crate::{
+    error::UnexpectedEOF,
+    unpacker::{ExhaustibleUnpacker, Unpacker},
+};
+
+/// An [`Unpacker`] backed by any type that implements [`Buf`], including non-contiguous buffers.
+pub struct BufUnpacker<B: Buf> {
+    buf: B,
+    start: usize,
+}
+
+impl<B: Buf> BufUnpacker<B> {
+    /// Creates a new [`BufUnpacker`] from a value that implements [`Buf`].
+    pub fn new(buf: B) -> Self {
+        Self {
+            start: buf.remaining(),
+            buf,
+        }
+    }
+
+    /// Consumes the value to return the inner value that implements [`Buf`].
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+}
+
+impl<B: Buf> Unpacker for BufUnpacker<B> {
+    type Error = UnexpectedEOF;
+
+    #[inline]
+    fn unpack_bytes<T: AsMut<[u8]>>(&mut self, mut bytes: T) -> Result<(), Self::Error> {
+        let bytes = bytes.as_mut();
+
+        self.ensure_bytes(bytes.len())?;
+        self.buf.copy_to_slice(bytes);
+
+        Ok(())
+    }
+
+    #[inline]
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        self.ensure_bytes(len)?;
+        self.buf.advance(len);
+
+        Ok(())
+    }
+
+    #[inline]
+    fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
+        let remaining = self.buf.remaining();
+
+        if remaining < len {
+            Err(UnexpectedEOF {
+                required: len,
+                had: remaining,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn read_bytes(&self) -> Option<usize> {
+        Some(self.start - self.buf.remaining())
+    }
+
+    #[inline]
+    fn peek_bytes(&mut self, len: usize) -> Result<Option<&[u8]>, Self::Error> {
+        self.ensure_bytes(len)?;
+
+        // Only the current chunk can be borrowed, non-contiguous bytes cannot be peeked at.
+        Ok(self.buf.chunk().get(..len))
+    }
+}
+
+impl<B: Buf> ExhaustibleUnpacker for BufUnpacker<B> {
+    #[inline]
+    fn is_exhausted(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.buf.has_remaining())
+    }
+
+    #[inline]
+    fn is_eof(_err: &Self::Error) -> bool {
+        true
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+extern crate alloc;
+extern crate std;
+
+use alloc::vec::Vec;
+use std::io::{self, Read};
+
+use crate::unpacker::{ExhaustibleUnpacker, Unpacker};
+
+const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+/// An [`Unpacker`] backed by [`Read`] that reads bytes into an internal buffer.
+///
+/// Unlike [`IoUnpacker`](crate::unpacker::IoUnpacker), this unpacker can look ahead with
+/// [`Unpacker::peek_bytes`], which allows custom [`Packable`](crate::Packable) implementations to inspect a tag
+/// before deciding how to unpack a value. Any bytes read past the end of a value are kept in the buffer, so the same
+/// [`BufferedIoUnpacker`] should be used to unpack every value from the underlying reader.
+pub struct BufferedIoUnpacker<R: Read> {
+    reader: R,
+    buffer: Vec<u8>,
+    pos: usize,
+    capacity: usize,
+    read: usize,
+}
+
+impl<R: Read> BufferedIoUnpacker<R> {
+    /// Creates a new [`BufferedIoUnpacker`] from a value that implements [`Read`] with a default buffer capacity.
+    pub fn new(reader: R) -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY, reader)
+    }
+
+    /// Creates a new [`BufferedIoUnpacker`] from a value that implements [`Read`] with the given buffer capacity.
+    ///
+    /// The buffer never grows past its capacity, so [`Unpacker::peek_bytes`] returns `Ok(None)` when asked for more
+    /// bytes than that.
+    pub fn with_capacity(capacity: usize, reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            pos: 0,
+            capacity: capacity.max(1),
+            read: 0,
+        }
+    }
+
+    /// Returns the bytes that have been read from the underlying reader but not unpacked yet.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buffer[self.pos..]
+    }
+
+    /// Consumes the value to return the inner value that implements [`Read`]. Any buffered bytes are discarded.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn consume(&mut self, len: usize) {
+        self.pos += len;
+        self.read += len;
+
+        if self.pos == self.buffer.len() {
+            self.buffer.clear();
+            self.pos = 0;
+        }
+    }
+
+    // Reads from the underlying reader until at least `len` bytes are buffered, where `len` must not exceed the
+    // capacity. Returns `false` if the reader reached its end before that.
+    fn fill_buffer(&mut self, len: usize) -> io::Result<bool> {
+        debug_assert!(len <= self.capacity);
+
+        if self.buffer.len() - self.pos >= len {
+            return Ok(true);
+        }
+
+        self.buffer.drain(..self.pos);
+        self.pos = 0;
+
+        // The buffer is zero-filled up to its capacity once and truncated to the bytes that were read afterwards.
+        let mut filled = self.buffer.len();
+        self.buffer.resize(self.capacity, 0);
+
+        let result = loop {
+            if filled >= len {
+                break Ok(true);
+            }
+
+            match self.reader.read(&mut self.buffer[filled..]) {
+                Ok(0) => break Ok(false),
+                Ok(read) => filled += read,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => break Err(err),
+            }
+        };
+
+        self.buffer.truncate(filled);
+
+        result
+    }
+}
+
+impl<R: Read> Unpacker for BufferedIoUnpacker<R> {
+    type Error = io::Error;
+
+    fn unpack_bytes<B: AsMut<[u8]>>(&mut self, mut bytes: B) -> Result<(), Self::Error> {
+        let bytes = bytes.as_mut();
+        let buffered = (self.buffer.len() - self.pos).min(bytes.len());
+        let (head, tail) = bytes.split_at_mut(buffered);
+
+        head.copy_from_slice(&self.buffer[self.pos..self.pos + buffered]);
+        self.consume(buffered);
+
+        if tail.len() >= self.capacity {
+            // Large reads bypass the buffer.
+            self.reader.read_exact(tail)?;
+            self.read += tail.len();
+        } else if !tail.is_empty() {
+            if !self.fill_buffer(tail.len())? {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+
+            tail.copy_from_slice(&self.buffer[..tail.len()]);
+            self.consume(tail.len());
+        }
+
+        Ok(())
+    }
+
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        let buffered = (self.buffer.len() - self.pos).min(len);
+        self.consume(buffered);
+
+        let len = len - buffered;
+        let skipped = io::copy(&mut self.reader.by_ref().take(len as u64), &mut io::sink())?;
+        self.read += skipped as usize;
+
+        if skipped == len as u64 {
+            Ok(())
+        } else {
+            Err(io::ErrorKind::UnexpectedEof.into())
+        }
+    }
+
+    #[inline]
+    fn read_bytes(&self) -> Option<usize> {
+        Some(self.read)
+    }
+
+    fn peek_bytes(&mut self, len: usize) -> Result<Option<&[u8]>, Self::Error> {
+        if len > self.capacity {
+            return Ok(None);
+        }
+
+        if !self.fill_buffer(len)? {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        Ok(Some(&self.buffer[self.pos..self.pos + len]))
+    }
+}
+
+impl<R: Read> ExhaustibleUnpacker for BufferedIoUnpacker<R> {
+    #[inline]
+    fn is_exhausted(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.fill_buffer(1)?)
+    }
+
+    #[inline]
+    fn is_eof(err: &Self::Error) -> bool {
+        err.kind() == io::ErrorKind::UnexpectedEof
+    }
+}
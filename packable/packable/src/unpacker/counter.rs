@@ -1,7 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::unpacker::Unpacker;
+use crate::unpacker::{ExhaustibleUnpacker, PathSegment, Unpacker};
 
 /// An [`Unpacker`] able to keep count of the number of read bytes.
 pub struct CounterUnpacker<U: Unpacker> {
@@ -45,13 +45,68 @@ impl<U: Unpacker> Unpacker for CounterUnpacker<U> {
         Ok(())
     }
 
+    #[inline]
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        self.inner.skip_bytes(len)?;
+        self.counter += len;
+
+        Ok(())
+    }
+
     #[inline]
     fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
         self.inner.ensure_bytes(len)
     }
 
+    #[inline]
+    fn hint_bytes(&mut self, len: usize) {
+        self.inner.hint_bytes(len)
+    }
+
     #[inline]
     fn read_bytes(&self) -> Option<usize> {
         Some(self.counter)
     }
+
+    #[inline]
+    fn peek_bytes(&mut self, len: usize) -> Result<Option<&[u8]>, Self::Error> {
+        self.inner.peek_bytes(len)
+    }
+
+    #[inline]
+    fn track_alloc(&mut self, len: usize) -> Result<(), Self::Error> {
+        self.inner.track_alloc(len)
+    }
+
+    #[inline]
+    fn enter_nested(&mut self) -> Result<(), Self::Error> {
+        self.inner.enter_nested()
+    }
+
+    #[inline]
+    fn exit_nested(&mut self) {
+        self.inner.exit_nested()
+    }
+
+    #[inline]
+    fn push_path(&mut self, segment: PathSegment) {
+        self.inner.push_path(segment)
+    }
+
+    #[inline]
+    fn pop_path(&mut self) {
+        self.inner.pop_path()
+    }
+}
+
+impl<U: ExhaustibleUnpacker> ExhaustibleUnpacker for CounterUnpacker<U> {
+    #[inline]
+    fn is_exhausted(&mut self) -> Result<bool, Self::Error> {
+        self.inner.is_exhausted()
+    }
+
+    #[inline]
+    fn is_eof(err: &Self::Error) -> bool {
+        U::is_eof(err)
+    }
 }
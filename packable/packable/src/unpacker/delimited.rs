@@ -0,0 +1,125 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    error::{DelimitedLenError, UnpackError},
+    unpacker::{PathSegment, Unpacker},
+};
+
+/// An [`Unpacker`] that confines another [`Unpacker`] to the given number of bytes.
+///
+/// Any attempt to read past the end of the delimited bytes fails with [`DelimitedLenError`] without reading anything
+/// from the inner unpacker.
+pub struct DelimitedUnpacker<'a, U: Unpacker> {
+    inner: &'a mut U,
+    len: usize,
+    consumed: usize,
+}
+
+impl<'a, U: Unpacker> DelimitedUnpacker<'a, U> {
+    /// Creates a new [`DelimitedUnpacker`] that reads at most `len` bytes from `unpacker`.
+    #[inline]
+    pub(crate) fn new(unpacker: &'a mut U, len: usize) -> Self {
+        Self {
+            inner: unpacker,
+            len,
+            consumed: 0,
+        }
+    }
+
+    /// Returns the number of bytes that have been read.
+    #[inline]
+    pub(crate) fn consumed(&self) -> usize {
+        self.consumed
+    }
+
+    /// Returns the number of bytes that can still be read.
+    #[inline]
+    pub(crate) fn remaining(&self) -> usize {
+        self.len - self.consumed
+    }
+
+    fn check_len(&self, len: usize) -> Result<(), UnpackError<DelimitedLenError, U::Error>> {
+        if len > self.remaining() {
+            Err(UnpackError::Packable(DelimitedLenError {
+                expected: self.len,
+                consumed: self.consumed.saturating_add(len),
+            }))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'a, U: Unpacker> Unpacker for DelimitedUnpacker<'a, U> {
+    type Error = UnpackError<DelimitedLenError, U::Error>;
+
+    #[inline]
+    fn unpack_bytes<B: AsMut<[u8]>>(&mut self, mut bytes: B) -> Result<(), Self::Error> {
+        let bytes = bytes.as_mut();
+        self.check_len(bytes.len())?;
+        self.inner.unpack_bytes(&mut *bytes)?;
+        self.consumed += bytes.len();
+
+        Ok(())
+    }
+
+    #[inline]
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        self.check_len(len)?;
+        self.inner.skip_bytes(len)?;
+        self.consumed += len;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
+        self.check_len(len)?;
+
+        Ok(self.inner.ensure_bytes(len)?)
+    }
+
+    #[inline]
+    fn hint_bytes(&mut self, len: usize) {
+        // Bytes past the end of the delimited ones will never be unpacked through this unpacker.
+        self.inner.hint_bytes(len.min(self.remaining()))
+    }
+
+    #[inline]
+    fn read_bytes(&self) -> Option<usize> {
+        self.inner.read_bytes()
+    }
+
+    #[inline]
+    fn peek_bytes(&mut self, len: usize) -> Result<Option<&[u8]>, Self::Error> {
+        self.check_len(len)?;
+
+        Ok(self.inner.peek_bytes(len)?)
+    }
+
+    #[inline]
+    fn track_alloc(&mut self, len: usize) -> Result<(), Self::Error> {
+        Ok(self.inner.track_alloc(len)?)
+    }
+
+    #[inline]
+    fn enter_nested(&mut self) -> Result<(), Self::Error> {
+        Ok(self.inner.enter_nested()?)
+    }
+
+    #[inline]
+    fn exit_nested(&mut self) {
+        self.inner.exit_nested()
+    }
+
+    #[inline]
+    fn push_path(&mut self, segment: PathSegment) {
+        self.inner.push_path(segment)
+    }
+
+    #[inline]
+    fn pop_path(&mut self) {
+        self.inner.pop_path()
+    }
+}
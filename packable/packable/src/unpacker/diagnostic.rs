@@ -0,0 +1,190 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::unpacker::{ExhaustibleUnpacker, PathSegment, Unpacker};
+
+/// An [`Unpacker`] that keeps track of the byte offset and the path of the value being unpacked over another
+/// [`Unpacker`].
+///
+/// When unpacking fails, the offset and path at the moment of the failure are kept so they can be displayed using
+/// [`DiagnosticUnpacker::report`]. The path is populated by the types that derive
+/// [`Packable`](crate::Packable) and by the sequences provided by this crate.
+pub struct DiagnosticUnpacker<U: Unpacker> {
+    inner: U,
+    offset: usize,
+    path: Vec<PathSegment>,
+}
+
+impl<U: Unpacker> DiagnosticUnpacker<U> {
+    /// Creates a new [`DiagnosticUnpacker`].
+    #[inline]
+    pub fn new(unpacker: U) -> Self {
+        Self {
+            inner: unpacker,
+            offset: 0,
+            path: Vec::new(),
+        }
+    }
+
+    /// Returns the number of read bytes.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the path of the value being unpacked.
+    #[inline]
+    pub fn path(&self) -> &[PathSegment] {
+        &self.path
+    }
+
+    /// Returns a value that displays the offset and path of the value being unpacked.
+    #[inline]
+    pub fn report(&self) -> DiagnosticReport<'_> {
+        DiagnosticReport {
+            offset: self.offset,
+            path: &self.path,
+        }
+    }
+
+    /// Consumes the value to return the inner [`Unpacker`].
+    #[inline]
+    pub fn into_inner(self) -> U {
+        self.inner
+    }
+}
+
+impl<U: Unpacker> Unpacker for DiagnosticUnpacker<U> {
+    type Error = U::Error;
+
+    fn unpack_bytes<B: AsMut<[u8]>>(&mut self, mut bytes: B) -> Result<(), Self::Error> {
+        let bytes = bytes.as_mut();
+        let len = bytes.len();
+
+        self.inner.unpack_bytes(bytes)?;
+        self.offset += len;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        self.inner.skip_bytes(len)?;
+        self.offset += len;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
+        self.inner.ensure_bytes(len)
+    }
+
+    #[inline]
+    fn hint_bytes(&mut self, len: usize) {
+        self.inner.hint_bytes(len)
+    }
+
+    #[inline]
+    fn read_bytes(&self) -> Option<usize> {
+        Some(self.offset)
+    }
+
+    #[inline]
+    fn peek_bytes(&mut self, len: usize) -> Result<Option<&[u8]>, Self::Error> {
+        self.inner.peek_bytes(len)
+    }
+
+    #[inline]
+    fn track_alloc(&mut self, len: usize) -> Result<(), Self::Error> {
+        self.inner.track_alloc(len)
+    }
+
+    #[inline]
+    fn enter_nested(&mut self) -> Result<(), Self::Error> {
+        self.inner.enter_nested()
+    }
+
+    #[inline]
+    fn exit_nested(&mut self) {
+        self.inner.exit_nested()
+    }
+
+    #[inline]
+    fn push_path(&mut self, segment: PathSegment) {
+        self.inner.push_path(segment);
+        self.path.push(segment);
+    }
+
+    #[inline]
+    fn pop_path(&mut self) {
+        self.inner.pop_path();
+        self.path.pop();
+    }
+}
+
+/// A report of the byte offset and path of a value being unpacked by a [`DiagnosticUnpacker`].
+///
+/// The path is displayed starting from the outermost type, such as `Block.payload::Transaction.inputs[3].unlock`.
+/// Only the outermost type is displayed since the types of fields and items can be inferred from it.
+#[derive(Debug, Clone, Copy)]
+pub struct DiagnosticReport<'a> {
+    offset: usize,
+    path: &'a [PathSegment],
+}
+
+impl<'a> DiagnosticReport<'a> {
+    /// Returns the number of read bytes.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the path of the value being unpacked.
+    #[inline]
+    pub fn path(&self) -> &'a [PathSegment] {
+        self.path
+    }
+}
+
+impl fmt::Display for DiagnosticReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut empty = true;
+
+        for segment in self.path {
+            match segment {
+                PathSegment::Type(name) if empty => write!(f, "{name}")?,
+                PathSegment::Type(_) => continue,
+                PathSegment::Variant(name) => write!(f, "::{name}")?,
+                PathSegment::Field(name) if empty => write!(f, "{name}")?,
+                PathSegment::Field(name) => write!(f, ".{name}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+
+            empty = false;
+        }
+
+        if empty {
+            write!(f, "byte offset {}", self.offset)
+        } else {
+            write!(f, " at byte offset {}", self.offset)
+        }
+    }
+}
+
+impl<U: ExhaustibleUnpacker> ExhaustibleUnpacker for DiagnosticUnpacker<U> {
+    #[inline]
+    fn is_exhausted(&mut self) -> Result<bool, Self::Error> {
+        self.inner.is_exhausted()
+    }
+
+    #[inline]
+    fn is_eof(err: &Self::Error) -> bool {
+        U::is_eof(err)
+    }
+}
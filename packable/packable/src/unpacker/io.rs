@@ -4,24 +4,30 @@
 extern crate std;
 
 use std::{
-    io::{self, Read},
+    io::{self, BufRead, Read},
     ops::Deref,
 };
 
-use crate::unpacker::Unpacker;
+use crate::unpacker::{ExhaustibleUnpacker, Unpacker};
 
 /// An [`Unpacker`] backed by [`Read`].
-pub struct IoUnpacker<R: Read>(R);
+///
+/// Use a [`BufferedIoUnpacker`](crate::unpacker::BufferedIoUnpacker) instead to be able to look ahead with
+/// [`Unpacker::peek_bytes`].
+pub struct IoUnpacker<R: Read> {
+    reader: R,
+    read: usize,
+}
 
 impl<R: Read> IoUnpacker<R> {
     /// Creates a new [`Unpacker`] from a value that implements [`Read`].
     pub fn new(reader: R) -> Self {
-        Self(reader)
+        Self { reader, read: 0 }
     }
 
     /// Consumes the value to return the inner value that implements [`Read`].
     pub fn into_inner(self) -> R {
-        self.0
+        self.reader
     }
 }
 
@@ -29,7 +35,7 @@ impl<R: Read> Deref for IoUnpacker<R> {
     type Target = R;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.reader
     }
 }
 
@@ -38,6 +44,48 @@ impl<R: Read> Unpacker for IoUnpacker<R> {
 
     #[inline]
     fn unpack_bytes<B: AsMut<[u8]>>(&mut self, mut bytes: B) -> Result<(), Self::Error> {
-        self.0.read_exact(bytes.as_mut())
+        let bytes = bytes.as_mut();
+
+        self.reader.read_exact(bytes)?;
+        self.read += bytes.len();
+
+        Ok(())
+    }
+
+    #[inline]
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        let skipped = io::copy(&mut self.reader.by_ref().take(len as u64), &mut io::sink())?;
+
+        if skipped == len as u64 {
+            self.read += len;
+            Ok(())
+        } else {
+            Err(io::ErrorKind::UnexpectedEof.into())
+        }
+    }
+
+    #[inline]
+    fn read_bytes(&self) -> Option<usize> {
+        Some(self.read)
+    }
+}
+
+/// Requires [`BufRead`] to be able to check for more bytes without consuming them. Wrap the reader in a
+/// [`BufReader`](std::io::BufReader) if needed.
+impl<R: BufRead> ExhaustibleUnpacker for IoUnpacker<R> {
+    #[inline]
+    fn is_exhausted(&mut self) -> Result<bool, Self::Error> {
+        loop {
+            match self.reader.fill_buf() {
+                Ok(buf) => return Ok(buf.is_empty()),
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    #[inline]
+    fn is_eof(err: &Self::Error) -> bool {
+        err.kind() == io::ErrorKind::UnexpectedEof
     }
 }
@@ -0,0 +1,139 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    error::LimitError,
+    unpacker::{ExhaustibleUnpacker, PathSegment, Unpacker},
+};
+
+/// An [`Unpacker`] that enforces an allocation budget and a maximum nesting depth over another [`Unpacker`].
+///
+/// This unpacker should be used when unpacking untrusted inputs to avoid allocating huge amounts of memory because of
+/// a malicious length prefix or overflowing the stack with deeply nested values. An unpacker that returned an error
+/// should not be reused.
+pub struct LimitedUnpacker<U: Unpacker> {
+    inner: U,
+    remaining_alloc: usize,
+    max_depth: usize,
+    depth: usize,
+}
+
+impl<U: Unpacker> LimitedUnpacker<U> {
+    /// Creates a new [`LimitedUnpacker`] that allows allocating at most `max_alloc` bytes in total and nesting values
+    /// at most `max_depth` levels deep.
+    #[inline]
+    pub fn new(unpacker: U, max_alloc: usize, max_depth: usize) -> Self {
+        Self {
+            inner: unpacker,
+            remaining_alloc: max_alloc,
+            max_depth,
+            depth: 0,
+        }
+    }
+
+    /// Returns the number of bytes left in the allocation budget.
+    #[inline]
+    pub fn remaining_alloc(&self) -> usize {
+        self.remaining_alloc
+    }
+
+    /// Returns the current nesting depth.
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Consumes the value to return the inner [`Unpacker`].
+    #[inline]
+    pub fn into_inner(self) -> U {
+        self.inner
+    }
+}
+
+impl<U: Unpacker> Unpacker for LimitedUnpacker<U> {
+    type Error = LimitError<U::Error>;
+
+    #[inline]
+    fn unpack_bytes<B: AsMut<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        Ok(self.inner.unpack_bytes(bytes)?)
+    }
+
+    #[inline]
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        Ok(self.inner.skip_bytes(len)?)
+    }
+
+    #[inline]
+    fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
+        Ok(self.inner.ensure_bytes(len)?)
+    }
+
+    #[inline]
+    fn hint_bytes(&mut self, len: usize) {
+        self.inner.hint_bytes(len)
+    }
+
+    #[inline]
+    fn read_bytes(&self) -> Option<usize> {
+        self.inner.read_bytes()
+    }
+
+    #[inline]
+    fn peek_bytes(&mut self, len: usize) -> Result<Option<&[u8]>, Self::Error> {
+        Ok(self.inner.peek_bytes(len)?)
+    }
+
+    fn track_alloc(&mut self, len: usize) -> Result<(), Self::Error> {
+        self.inner.track_alloc(len)?;
+
+        match self.remaining_alloc.checked_sub(len) {
+            Some(remaining_alloc) => {
+                self.remaining_alloc = remaining_alloc;
+                Ok(())
+            }
+            None => Err(LimitError::AllocationLimit {
+                required: len,
+                remaining: self.remaining_alloc,
+            }),
+        }
+    }
+
+    fn enter_nested(&mut self) -> Result<(), Self::Error> {
+        if self.depth >= self.max_depth {
+            return Err(LimitError::DepthLimit(self.max_depth));
+        }
+
+        self.inner.enter_nested()?;
+        self.depth += 1;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn exit_nested(&mut self) {
+        self.inner.exit_nested();
+        self.depth = self.depth.saturating_sub(1);
+    }
+
+    #[inline]
+    fn push_path(&mut self, segment: PathSegment) {
+        self.inner.push_path(segment)
+    }
+
+    #[inline]
+    fn pop_path(&mut self) {
+        self.inner.pop_path()
+    }
+}
+
+impl<U: ExhaustibleUnpacker> ExhaustibleUnpacker for LimitedUnpacker<U> {
+    #[inline]
+    fn is_exhausted(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.inner.is_exhausted()?)
+    }
+
+    #[inline]
+    fn is_eof(err: &Self::Error) -> bool {
+        matches!(err, LimitError::Unpacker(err) if U::is_eof(err))
+    }
+}
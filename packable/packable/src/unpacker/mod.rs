@@ -6,16 +6,50 @@
 //! The [`Unpacker`] trait represents types that can be used to read bytes from it. It can be thought as a `no_std`
 //! friendly alternative to the [`Read`](std::io::Read) trait.
 
+#[cfg(feature = "tokio")]
+mod async_io;
+#[cfg(feature = "bytes")]
+mod buf;
+#[cfg(feature = "io")]
+mod buffered_io;
 mod counter;
+mod delimited;
+#[cfg(feature = "alloc")]
+mod diagnostic;
 #[cfg(feature = "io")]
 mod io;
+mod limited;
 mod slice;
 
+#[cfg(feature = "tokio")]
+pub use async_io::AsyncUnpacker;
+#[cfg(feature = "bytes")]
+pub use buf::BufUnpacker;
+#[cfg(feature = "io")]
+pub use buffered_io::BufferedIoUnpacker;
 pub use counter::CounterUnpacker;
+#[doc(hidden)]
+pub use delimited::DelimitedUnpacker;
+#[cfg(feature = "alloc")]
+pub use diagnostic::{DiagnosticReport, DiagnosticUnpacker};
 #[cfg(feature = "io")]
 pub use io::IoUnpacker;
+pub use limited::LimitedUnpacker;
 pub use slice::SliceUnpacker;
 
+/// A segment of the path to a value that is being unpacked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    /// A value of the named type.
+    Type(&'static str),
+    /// The named variant of an enum.
+    Variant(&'static str),
+    /// The named field of a struct or enum variant.
+    Field(&'static str),
+    /// The item at the given index of a sequence.
+    Index(usize),
+}
+
 /// A type that can unpack any value that implements [`Packable`](crate::Packable).
 pub trait Unpacker: Sized {
     /// An error type representing any error related to reading bytes.
@@ -25,6 +59,24 @@ pub trait Unpacker: Sized {
     /// This method **must** fail if the unpacker does not have enough bytes to fulfill the request.
     fn unpack_bytes<B: AsMut<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error>;
 
+    /// Skips a sequence of `len` bytes from the [`Unpacker`]. This method **must** fail if the unpacker does not have
+    /// enough bytes to fulfill the request.
+    ///
+    /// The default implementation reads the bytes into a buffer and discards them. Unpackers that can move past bytes
+    /// without copying them should override it.
+    #[inline]
+    fn skip_bytes(&mut self, mut len: usize) -> Result<(), Self::Error> {
+        let mut buffer = [0u8; 256];
+
+        while len > 0 {
+            let chunk = len.min(buffer.len());
+            self.unpack_bytes(&mut buffer[..chunk])?;
+            len -= chunk;
+        }
+
+        Ok(())
+    }
+
     /// Tries to guarantee that the [`Unpacker`] has at least `len` bytes.
     ///
     /// This method **must** fail if and only if it is certain that there are not enough bytes and
@@ -34,13 +86,65 @@ pub trait Unpacker: Sized {
         Ok(())
     }
 
+    /// Notifies the [`Unpacker`] that at least `len` more bytes are going to be unpacked.
+    ///
+    /// Unlike [`ensure_bytes`](Unpacker::ensure_bytes), this method cannot fail. It can be used by unpackers that
+    /// buffer their input to read these bytes at once.
+    #[inline]
+    fn hint_bytes(&mut self, _len: usize) {}
+
     /// Returns the exact number of read bytes if possible.
     #[inline]
     fn read_bytes(&self) -> Option<usize> {
         None
     }
+
+    /// Returns the next `len` bytes of the [`Unpacker`] without consuming them, if the unpacker can look ahead.
+    ///
+    /// This method returns `Ok(None)` if the unpacker cannot look ahead. Otherwise, it **must** fail if the unpacker
+    /// does not have enough bytes to fulfill the request.
+    #[inline]
+    fn peek_bytes(&mut self, _len: usize) -> Result<Option<&[u8]>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Notifies the [`Unpacker`] that `len` bytes of memory are about to be allocated to store unpacked values.
+    ///
+    /// This method can be used to enforce an allocation budget when unpacking untrusted inputs and **must** fail if
+    /// the allocation is not allowed.
+    #[inline]
+    fn track_alloc(&mut self, _len: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Notifies the [`Unpacker`] that a nested value, such as the items of a sequence, is about to be unpacked.
+    ///
+    /// This method can be used to enforce a maximum nesting depth when unpacking untrusted inputs and **must** fail
+    /// if the nesting is not allowed. Every successful call must be followed by a call to
+    /// [`exit_nested`](Unpacker::exit_nested) once the nested value has been unpacked successfully.
+    #[inline]
+    fn enter_nested(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Notifies the [`Unpacker`] that a nested value has been unpacked.
+    #[inline]
+    fn exit_nested(&mut self) {}
+
+    /// Notifies the [`Unpacker`] that the value identified by `segment` is about to be unpacked.
+    ///
+    /// This method can be used to keep track of the location of the value being unpacked for diagnostic purposes.
+    /// Every call must be followed by a call to [`pop_path`](Unpacker::pop_path) once the value has been unpacked
+    /// successfully, so the path is left untouched when unpacking fails.
+    #[inline]
+    fn push_path(&mut self, _segment: PathSegment) {}
+
+    /// Notifies the [`Unpacker`] that the value identified by the last pushed segment has been unpacked.
+    #[inline]
+    fn pop_path(&mut self) {}
 }
 
+#[allow(clippy::needless_maybe_sized)]
 impl<U: Unpacker + ?Sized> Unpacker for &mut U {
     type Error = U::Error;
 
@@ -49,13 +153,80 @@ impl<U: Unpacker + ?Sized> Unpacker for &mut U {
         U::unpack_bytes(*self, bytes)
     }
 
+    #[inline]
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        U::skip_bytes(*self, len)
+    }
+
     #[inline]
     fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
         U::ensure_bytes(*self, len)
     }
 
+    #[inline]
+    fn hint_bytes(&mut self, len: usize) {
+        U::hint_bytes(*self, len)
+    }
+
     #[inline]
     fn read_bytes(&self) -> Option<usize> {
         U::read_bytes(*self)
     }
+
+    #[inline]
+    fn peek_bytes(&mut self, len: usize) -> Result<Option<&[u8]>, Self::Error> {
+        U::peek_bytes(*self, len)
+    }
+
+    #[inline]
+    fn track_alloc(&mut self, len: usize) -> Result<(), Self::Error> {
+        U::track_alloc(*self, len)
+    }
+
+    #[inline]
+    fn enter_nested(&mut self) -> Result<(), Self::Error> {
+        U::enter_nested(*self)
+    }
+
+    #[inline]
+    fn exit_nested(&mut self) {
+        U::exit_nested(*self)
+    }
+
+    #[inline]
+    fn push_path(&mut self, segment: PathSegment) {
+        U::push_path(*self, segment)
+    }
+
+    #[inline]
+    fn pop_path(&mut self) {
+        U::pop_path(*self)
+    }
+}
+
+/// An [`Unpacker`] that can tell whether all of its bytes have been unpacked.
+///
+/// This trait is used to unpack a sequence of concatenated values until the input is cleanly exhausted, as done by
+/// [`UnpackIter`](crate::stream::UnpackIter).
+pub trait ExhaustibleUnpacker: Unpacker {
+    /// Returns `true` if there are no bytes left to be unpacked.
+    ///
+    /// This method may need to wait for more bytes to be available to answer, but it **must not** consume any of
+    /// them.
+    fn is_exhausted(&mut self) -> Result<bool, Self::Error>;
+
+    /// Returns `true` if `err` was raised because the [`Unpacker`] did not have enough bytes to fulfill a request.
+    fn is_eof(err: &Self::Error) -> bool;
+}
+
+impl<U: ExhaustibleUnpacker> ExhaustibleUnpacker for &mut U {
+    #[inline]
+    fn is_exhausted(&mut self) -> Result<bool, Self::Error> {
+        U::is_exhausted(*self)
+    }
+
+    #[inline]
+    fn is_eof(err: &Self::Error) -> bool {
+        U::is_eof(err)
+    }
 }
@@ -1,18 +1,59 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{error::UnexpectedEOF, unpacker::Unpacker};
+use crate::{
+    error::UnexpectedEOF,
+    unpacker::{ExhaustibleUnpacker, Unpacker},
+};
 
 /// A [`Unpacker`] backed by a `&mut [u8]`.
-#[repr(transparent)]
 pub struct SliceUnpacker<'a> {
     slice: &'a [u8],
+    len: usize,
 }
 
 impl<'a> SliceUnpacker<'a> {
     /// Creates a new [`SliceUnpacker`] from a `&[u8]`.
     pub fn new(slice: &'a [u8]) -> Self {
-        Self { slice }
+        Self {
+            slice,
+            len: slice.len(),
+        }
+    }
+
+    /// Returns the number of bytes that have not been unpacked yet.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Returns the bytes that have not been unpacked yet.
+    #[inline]
+    pub fn remaining_slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns `true` if all the bytes have been unpacked.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Consumes the value to return the bytes that have not been unpacked yet.
+    #[inline]
+    pub fn into_remaining(self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Unpacks `len` bytes by borrowing them from the underlying slice instead of copying them.
+    #[inline]
+    pub fn unpack_slice(&mut self, len: usize) -> Result<&'a [u8], UnexpectedEOF> {
+        self.ensure_bytes(len)?;
+
+        let (head, tail) = self.slice.split_at(len);
+        self.slice = tail;
+
+        Ok(head)
     }
 }
 
@@ -37,6 +78,11 @@ impl<'u> Unpacker for SliceUnpacker<'u> {
         }
     }
 
+    #[inline]
+    fn skip_bytes(&mut self, len: usize) -> Result<(), Self::Error> {
+        self.unpack_slice(len).map(drop)
+    }
+
     #[inline]
     fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
         if self.slice.len() < len {
@@ -48,4 +94,28 @@ impl<'u> Unpacker for SliceUnpacker<'u> {
             Ok(())
         }
     }
+
+    #[inline]
+    fn read_bytes(&self) -> Option<usize> {
+        Some(self.len - self.slice.len())
+    }
+
+    #[inline]
+    fn peek_bytes(&mut self, len: usize) -> Result<Option<&[u8]>, Self::Error> {
+        self.ensure_bytes(len)?;
+
+        Ok(Some(&self.slice[..len]))
+    }
+}
+
+impl<'u> ExhaustibleUnpacker for SliceUnpacker<'u> {
+    #[inline]
+    fn is_exhausted(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.is_empty())
+    }
+
+    #[inline]
+    fn is_eof(_err: &Self::Error) -> bool {
+        true
+    }
 }
@@ -0,0 +1,130 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "arrayvec")]
+
+mod common;
+
+use arrayvec::{ArrayString, ArrayVec};
+use packable::{
+    bounded::{BoundedU8, InvalidBoundedU8, TryIntoBoundedU8Error},
+    error::UnpackError,
+    prefix::{ArrayStringPrefix, ArrayVecPrefix, StringPrefix, UnpackArrayPrefixError, VecPrefix},
+    PackableExt,
+};
+
+#[test]
+fn packable_array_vec_prefix() {
+    let vec = ArrayVec::<u32, 8>::from_iter([1, 2, 3]);
+    let prefixed = ArrayVecPrefix::<u32, u8, 8>::try_from(vec).unwrap();
+
+    assert_eq!(
+        common::generic_test(&prefixed).0.len(),
+        core::mem::size_of::<u8>() + 3 * core::mem::size_of::<u32>()
+    );
+}
+
+#[test]
+fn packable_array_vec_prefix_same_bytes_as_vec_prefix() {
+    let items = [0x12u16, 0x3456, 0x789a];
+    let array_prefixed = ArrayVecPrefix::<u16, u16, 4>::try_from(ArrayVec::from_iter(items)).unwrap();
+    let prefixed = VecPrefix::<u16, u16>::try_from(items.to_vec()).unwrap();
+
+    assert_eq!(array_prefixed.pack_to_vec(), prefixed.pack_to_vec());
+}
+
+#[test]
+fn packable_array_vec_prefix_u8_same_bytes_as_vec_prefix() {
+    let items = *b"yellow submarine";
+    let array_prefixed = ArrayVecPrefix::<u8, u32, 16>::try_from(ArrayVec::from(items)).unwrap();
+    let prefixed = VecPrefix::<u8, u32>::try_from(items.to_vec()).unwrap();
+
+    assert_eq!(array_prefixed.pack_to_vec(), prefixed.pack_to_vec());
+}
+
+#[test]
+fn packable_array_vec_prefix_from_array_vec_invalid_error() {
+    let vec = ArrayVec::<u8, 8>::from_iter([1, 2, 3, 4, 5]);
+    let prefixed = ArrayVecPrefix::<u8, BoundedU8<1, 4>, 8>::try_from(vec);
+
+    assert!(matches!(prefixed, Err(TryIntoBoundedU8Error::Invalid(5))));
+}
+
+#[test]
+fn packable_array_vec_prefix_unpack_capacity_error() {
+    let bytes = VecPrefix::<u8, u8>::try_from(vec![0; 5]).unwrap().pack_to_vec();
+    let unpacked = ArrayVecPrefix::<u8, u8, 4>::unpack_bytes_verified(bytes, &());
+
+    assert!(matches!(
+        unpacked,
+        Err(UnpackError::Packable(UnpackArrayPrefixError::Capacity(4)))
+    ));
+}
+
+#[test]
+fn packable_array_vec_prefix_unpack_prefix_error() {
+    let bytes = [5u8, 0, 0, 0, 0, 0];
+    let unpacked = ArrayVecPrefix::<u8, BoundedU8<1, 4>, 8>::unpack_bytes_verified(bytes, &());
+
+    assert!(matches!(
+        unpacked,
+        Err(UnpackError::Packable(UnpackArrayPrefixError::Prefix(InvalidBoundedU8(
+            5
+        ))))
+    ));
+}
+
+#[test]
+fn packable_array_vec_prefix_unpack_item_error() {
+    let bytes = [2u8, 1, 7, 2];
+    let unpacked = ArrayVecPrefix::<Option<u8>, u8, 4>::unpack_bytes_verified(bytes, &());
+
+    assert!(matches!(
+        unpacked,
+        Err(UnpackError::Packable(UnpackArrayPrefixError::Item(_)))
+    ));
+}
+
+#[test]
+fn packable_array_string_prefix() {
+    let string = ArrayString::<16>::from("yellow submarine").unwrap();
+    let prefixed = ArrayStringPrefix::<u8, 16>::try_from(string).unwrap();
+
+    assert_eq!(
+        common::generic_test(&prefixed).0.len(),
+        core::mem::size_of::<u8>() + 16 * core::mem::size_of::<u8>()
+    );
+}
+
+#[test]
+fn packable_array_string_prefix_same_bytes_as_string_prefix() {
+    let array_prefixed =
+        ArrayStringPrefix::<u16, 32>::try_from(ArrayString::from("yellow submarine").unwrap()).unwrap();
+    let prefixed = StringPrefix::<u16>::try_from(String::from("yellow submarine")).unwrap();
+
+    assert_eq!(array_prefixed.pack_to_vec(), prefixed.pack_to_vec());
+}
+
+#[test]
+fn packable_array_string_prefix_unpack_capacity_error() {
+    let bytes = StringPrefix::<u8>::try_from(String::from("yellow submarine"))
+        .unwrap()
+        .pack_to_vec();
+    let unpacked = ArrayStringPrefix::<u8, 8>::unpack_bytes_verified(bytes, &());
+
+    assert!(matches!(
+        unpacked,
+        Err(UnpackError::Packable(UnpackArrayPrefixError::Capacity(8)))
+    ));
+}
+
+#[test]
+fn packable_array_string_prefix_unpack_utf8_error() {
+    let bytes = [2u8, 0xc3, 0x28];
+    let unpacked = ArrayStringPrefix::<u8, 8>::unpack_bytes_verified(bytes, &());
+
+    assert!(matches!(
+        unpacked,
+        Err(UnpackError::Packable(UnpackArrayPrefixError::Item(_)))
+    ));
+}
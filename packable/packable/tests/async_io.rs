@@ -0,0 +1,121 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "tokio")]
+
+use packable::{
+    error::UnpackError,
+    packer::AsyncPacker,
+    prefix::{BoxedSlicePrefix, StringPrefix, UnpackPrefixError, VecPrefix},
+    unpacker::AsyncUnpacker,
+    PackableExt,
+};
+use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+
+#[tokio::test]
+async fn async_roundtrip() {
+    let first = VecPrefix::<u32, u16>::try_from(vec![1, 2, 3, 4]).unwrap();
+    let second = (StringPrefix::<u8>::try_from(String::from("packable")).unwrap(), 0x45u64);
+
+    // A tiny buffer forces values to be split across several reads.
+    let (writer, reader) = duplex(3);
+
+    let expected = [first.pack_to_vec(), second.pack_to_vec()].concat();
+
+    let ((), read) = tokio::join!(
+        async {
+            let mut packer = AsyncPacker::new(writer);
+            packer.pack(&first).await.unwrap();
+            packer.pack(&second).await.unwrap();
+            packer.flush().await.unwrap();
+        },
+        async {
+            let mut unpacker = AsyncUnpacker::new(reader);
+            let unpacked_first = unpacker.unpack_verified::<VecPrefix<u32, u16>>(&()).await.unwrap();
+            let unpacked_second = unpacker.unpack_verified::<(StringPrefix<u8>, u64)>(&()).await.unwrap();
+            (unpacked_first, unpacked_second)
+        }
+    );
+
+    assert_eq!(read.0, first);
+    assert_eq!(read.1, second);
+
+    // The async packer writes the same bytes as the sync one.
+    let (mut writer, mut reader) = duplex(64);
+    let mut packer = AsyncPacker::new(&mut writer);
+    packer.pack(&first).await.unwrap();
+    packer.pack(&second).await.unwrap();
+    drop(packer);
+    writer.shutdown().await.unwrap();
+    drop(writer);
+
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).await.unwrap();
+    assert_eq!(bytes, expected);
+}
+
+#[tokio::test]
+async fn async_unexpected_eof() {
+    let (mut writer, reader) = duplex(64);
+
+    // The prefix announces 4 items but only 2 are written.
+    writer.write_all(&[4, 1, 0, 2, 0]).await.unwrap();
+    drop(writer);
+
+    let mut unpacker = AsyncUnpacker::new(reader);
+
+    assert!(matches!(
+        unpacker.unpack_verified::<VecPrefix<u16, u8>>(&()).await,
+        Err(UnpackError::Unpacker(err)) if err.kind() == std::io::ErrorKind::UnexpectedEof
+    ));
+}
+
+#[tokio::test]
+async fn async_packable_error() {
+    let (mut writer, reader) = duplex(64);
+
+    writer.write_all(&[2, 0xc3, 0x28]).await.unwrap();
+    drop(writer);
+
+    let mut unpacker = AsyncUnpacker::new(reader);
+
+    assert!(matches!(
+        unpacker.unpack_verified::<StringPrefix<u8>>(&()).await,
+        Err(UnpackError::Packable(UnpackPrefixError::Item(_)))
+    ));
+}
+
+#[tokio::test]
+async fn async_hostile_prefix() {
+    let (mut writer, reader) = duplex(64);
+
+    // The prefix announces `u32::MAX` bytes but only 3 are written.
+    writer.write_all(&[0xff, 0xff, 0xff, 0xff, 1, 2, 3]).await.unwrap();
+    drop(writer);
+
+    let mut unpacker = AsyncUnpacker::new(reader);
+
+    assert!(matches!(
+        unpacker.unpack_verified::<BoxedSlicePrefix<u8, u32>>(&()).await,
+        Err(UnpackError::Unpacker(err)) if err.kind() == std::io::ErrorKind::UnexpectedEof
+    ));
+    assert_eq!(unpacker.buffer(), [0xff, 0xff, 0xff, 0xff, 1, 2, 3]);
+}
+
+#[tokio::test]
+async fn async_max_len() {
+    // The writer is kept open, so the unpacker must fail without waiting for the announced bytes.
+    let (mut writer, reader) = duplex(64);
+
+    writer.write_all(&[0xff, 0xff, 0xff, 0xff]).await.unwrap();
+
+    let mut unpacker = AsyncUnpacker::with_max_len(1024, reader);
+
+    assert_eq!(unpacker.max_len(), 1024);
+    assert!(matches!(
+        unpacker.unpack_verified::<BoxedSlicePrefix<u8, u32>>(&()).await,
+        Err(UnpackError::Unpacker(err)) if err.kind() == std::io::ErrorKind::InvalidData
+    ));
+
+    drop(writer);
+}
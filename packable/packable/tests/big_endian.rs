@@ -0,0 +1,77 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use packable::{
+    num::BigEndian,
+    prefix::{StringPrefix, VecPrefix},
+    PackableExt,
+};
+
+macro_rules! impl_packable_test_for_big_endian {
+    ($name:ident, $ty:ident, $value:expr) => {
+        #[test]
+        fn $name() {
+            let value: $ty = $value;
+            let bytes = common::generic_test(&BigEndian(value));
+
+            assert_eq!(bytes.0, value.to_be_bytes());
+        }
+    };
+}
+
+impl_packable_test_for_big_endian!(packable_big_endian_i16, i16, -0x6F7B);
+impl_packable_test_for_big_endian!(packable_big_endian_u16, u16, 0x6F7B);
+impl_packable_test_for_big_endian!(packable_big_endian_i32, i32, -0x6F7BD423);
+impl_packable_test_for_big_endian!(packable_big_endian_u32, u32, 0x6F7BD423);
+impl_packable_test_for_big_endian!(packable_big_endian_i64, i64, -0x6F7BD423100423DB);
+impl_packable_test_for_big_endian!(packable_big_endian_u64, u64, 0x6F7BD423100423DB);
+impl_packable_test_for_big_endian!(packable_big_endian_i128, i128, -0x6F7BD423100423DBFF7B1AD0DF7B1AD0);
+impl_packable_test_for_big_endian!(packable_big_endian_u128, u128, 0x6F7BD423100423DBFF7B1AD0DF7B1AD0);
+
+#[test]
+fn big_endian_vec_prefix() {
+    let value = VecPrefix::<u8, BigEndian<u16>>::try_from(vec![1, 2, 3]).unwrap();
+    let bytes = common::generic_test(&value);
+
+    assert_eq!(bytes.0, [0, 3, 1, 2, 3]);
+}
+
+#[test]
+fn big_endian_string_prefix() {
+    let value = StringPrefix::<BigEndian<u32>>::try_from(String::from("yes")).unwrap();
+    let bytes = common::generic_test(&value);
+
+    assert_eq!(bytes.0, [0, 0, 0, 3, b'y', b'e', b's']);
+}
+
+#[test]
+fn big_endian_prefix_too_long() {
+    assert!(VecPrefix::<u8, BigEndian<u16>>::try_from(vec![0; u16::MAX as usize + 1]).is_err());
+    assert_eq!(
+        VecPrefix::<u8, BigEndian<u16>>::try_from(vec![0; 256])
+            .unwrap()
+            .packed_len(),
+        258
+    );
+}
+
+#[cfg(feature = "primitive-types")]
+#[test]
+fn big_endian_primitive_types() {
+    use primitive_types::{U128, U256, U512};
+
+    let bytes = common::generic_test(&BigEndian(U128::from(0x0102u64)));
+    assert_eq!(bytes.0.len(), 16);
+    assert_eq!(bytes.0[14..], [1, 2]);
+
+    let bytes = common::generic_test(&BigEndian(U256::from(0x0102u64)));
+    assert_eq!(bytes.0.len(), 32);
+    assert_eq!(bytes.0[30..], [1, 2]);
+    assert_eq!(U256::from(0x0102u64).pack_to_vec()[..2], [2, 1]);
+
+    let bytes = common::generic_test(&BigEndian(U512::MAX - 1));
+    assert_eq!(bytes.0.len(), 64);
+    assert_eq!(bytes.0[63], 0xFE);
+}
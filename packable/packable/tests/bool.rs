@@ -3,7 +3,10 @@
 
 mod common;
 
-use packable::{Packable, PackableExt};
+use packable::{
+    error::{InvalidBoolError, UnpackError},
+    Packable, PackableExt, StrictBool,
+};
 
 #[test]
 fn packable_bool() {
@@ -20,3 +23,35 @@ fn packable_bool_packed_non_zero_bytes_are_truthy() {
 
     assert!(is_true);
 }
+
+#[test]
+fn packable_strict_bool() {
+    assert_eq!(common::generic_test(&StrictBool(false)).0, false.pack_to_vec());
+    assert_eq!(common::generic_test(&StrictBool(true)).0, true.pack_to_vec());
+}
+
+#[test]
+fn packable_strict_bool_rejects_non_canonical_bytes() {
+    let unpacked = StrictBool::unpack_bytes_verified([42u8], &());
+
+    assert!(matches!(unpacked, Err(UnpackError::Packable(InvalidBoolError(42)))));
+}
+
+#[test]
+fn packable_strict_bool_in_derive() {
+    #[derive(Debug, PartialEq, Eq, Packable)]
+    #[packable(unpack_error = InvalidBoolError)]
+    struct Flags {
+        enabled: StrictBool,
+        visible: StrictBool,
+    }
+
+    let flags = Flags {
+        enabled: true.into(),
+        visible: false.into(),
+    };
+    assert_eq!(Flags::unpack_bytes_verified(flags.pack_to_vec(), &()).unwrap(), flags);
+
+    let unpacked = Flags::unpack_bytes_verified([1u8, 2], &());
+    assert!(matches!(unpacked, Err(UnpackError::Packable(InvalidBoolError(2)))));
+}
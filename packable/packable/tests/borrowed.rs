@@ -0,0 +1,114 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::Infallible;
+
+use packable::{
+    error::{UnexpectedEOF, UnpackError},
+    prefix::{SlicePrefix, StrPrefix, UnpackPrefixError},
+    Packable, PackableExt, UnpackBorrowed,
+};
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(unpack_error = Infallible, with = |err: UnpackPrefixError<Infallible, Infallible>| Infallible::from(err))]
+struct Header<'a> {
+    #[packable(unpack_error_with = core::convert::identity)]
+    version: u8,
+    data: SlicePrefix<'a, u16>,
+    #[packable(unpack_error_with = core::convert::identity)]
+    hash: &'a [u8; 4],
+}
+
+fn pack<'a, T: UnpackBorrowed<'a>>(value: &T) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    value.pack_borrowed(&mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn slice_prefix() {
+    let bytes = [3, 0, 1, 2, 3];
+    let prefixed = SlicePrefix::<u16>::unpack_borrowed_bytes_verified(&bytes, &()).unwrap();
+
+    assert_eq!(&*prefixed, &[1, 2, 3]);
+    // The unpacked slice borrows from the input.
+    assert_eq!(prefixed.as_ptr(), bytes[2..].as_ptr());
+    assert_eq!(pack(&prefixed), bytes);
+}
+
+#[test]
+fn slice_prefix_eof() {
+    let bytes = [4, 1, 2, 3];
+
+    assert!(matches!(
+        SlicePrefix::<u8>::unpack_borrowed_bytes_verified(&bytes, &()),
+        Err(UnpackError::Unpacker(UnexpectedEOF { required: 4, had: 3 }))
+    ));
+}
+
+#[test]
+fn str_prefix() {
+    let bytes = [5, b'h', b'e', b'l', b'l', b'o'];
+    let prefixed = StrPrefix::<u8>::unpack_borrowed_bytes_verified(&bytes, &()).unwrap();
+
+    assert_eq!(&*prefixed, "hello");
+    assert_eq!(pack(&prefixed), bytes);
+}
+
+#[test]
+fn str_prefix_invalid_utf8() {
+    let bytes = [2, 0xc3, 0x28];
+
+    assert!(matches!(
+        StrPrefix::<u8>::unpack_borrowed_bytes_verified(&bytes, &()),
+        Err(UnpackError::Packable(UnpackPrefixError::Item(_)))
+    ));
+}
+
+#[test]
+fn byte_array_ref() {
+    let bytes = [1, 2, 3, 4];
+    let array = <&[u8; 4]>::unpack_borrowed_bytes_unverified(&bytes).unwrap();
+
+    assert_eq!(array, &bytes);
+    assert_eq!(pack(&array), bytes);
+}
+
+#[test]
+fn owned_types() {
+    let value = (42u32, 7u16);
+    let bytes = value.pack_to_vec();
+
+    assert_eq!(pack(&value), bytes);
+    assert_eq!(
+        <(u32, u16)>::unpack_borrowed_bytes_verified(&bytes, &()).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn derived_struct() {
+    let data = [9, 8, 7];
+    let header = Header {
+        version: 1,
+        data: SlicePrefix::try_from(&data[..]).unwrap(),
+        hash: &[0xaa; 4],
+    };
+
+    let bytes = pack(&header);
+    assert_eq!(bytes, [1, 3, 0, 9, 8, 7, 0xaa, 0xaa, 0xaa, 0xaa]);
+
+    let unpacked = Header::unpack_borrowed_bytes_verified(&bytes, &()).unwrap();
+    assert_eq!(unpacked, header);
+}
+
+#[cfg(feature = "usize")]
+#[test]
+fn slice_and_str() {
+    let bytes = [3, 0, 0, 0, 0, 0, 0, 0, b'a', b'b', b'c'];
+
+    assert_eq!(<&[u8]>::unpack_borrowed_bytes_verified(&bytes, &()).unwrap(), b"abc");
+    assert_eq!(<&str>::unpack_borrowed_bytes_verified(&bytes, &()).unwrap(), "abc");
+    assert_eq!(pack(&"abc"), bytes);
+    assert_eq!(pack(&&b"abc"[..]), bytes);
+}
@@ -8,7 +8,7 @@ use std::collections::BTreeMap;
 use packable::{
     bounded::{
         BoundedU16, BoundedU32, BoundedU64, BoundedU8, InvalidBoundedU16, InvalidBoundedU32, InvalidBoundedU64,
-        InvalidBoundedU8, TryIntoBoundedU32Error,
+        InvalidBoundedU8, TryIntoBoundedU32Error, TryIntoBoundedU8Error,
     },
     error::UnpackError,
     map::{UnpackMapError, UnpackOrderedMapError},
@@ -250,3 +250,57 @@ impl_packable_test_for_bounded_btreemap_prefix!(
     1,
     64
 );
+
+#[test]
+fn btreemap_prefix_bounded_mutation() {
+    let mut prefixed = BTreeMapPrefix::<u8, u16, BoundedU8<1, 3>>::try_from(BTreeMap::from_iter([(1, 10)])).unwrap();
+
+    assert_eq!(prefixed.try_insert(2, 20).unwrap(), None);
+    assert_eq!(prefixed.try_insert(3, 30).unwrap(), None);
+    // Replacing the value of an existing key does not change the length.
+    assert_eq!(prefixed.try_insert(3, 31).unwrap(), Some(30));
+    assert!(matches!(
+        prefixed.try_insert(4, 40),
+        Err(TryIntoBoundedU8Error::Invalid(4))
+    ));
+
+    assert_eq!(prefixed.remove(&1).unwrap(), Some(10));
+    assert_eq!(prefixed.remove(&1).unwrap(), None);
+    assert!(matches!(
+        prefixed.try_extend([(4, 40), (5, 50)]),
+        Err(TryIntoBoundedU8Error::Invalid(4))
+    ));
+    prefixed.try_extend([(3, 32), (4, 40)]).unwrap();
+    assert_eq!(*prefixed, BTreeMap::from_iter([(2, 20), (3, 32), (4, 40)]));
+
+    *prefixed.get_mut(&2).unwrap() += 1;
+    assert!(matches!(
+        prefixed.retain(|_, _| false),
+        Err(TryIntoBoundedU8Error::Invalid(0))
+    ));
+    assert_eq!(*prefixed, BTreeMap::from_iter([(2, 21), (3, 32), (4, 40)]));
+    prefixed
+        .retain(|key, value| {
+            *value += 1;
+            *key != 3
+        })
+        .unwrap();
+    assert_eq!(*prefixed, BTreeMap::from_iter([(2, 22), (4, 41)]));
+
+    assert_eq!(prefixed.remove(&2).unwrap(), Some(22));
+    assert!(matches!(prefixed.remove(&4), Err(TryIntoBoundedU8Error::Invalid(0))));
+}
+
+#[test]
+fn btreemap_prefix_retain_panic() {
+    let mut prefixed =
+        BTreeMapPrefix::<u8, u16, BoundedU8<1, 3>>::try_from(BTreeMap::from_iter([(1, 10), (2, 20)])).unwrap();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        prefixed.retain(|key, _| key == &1 || panic!())
+    }));
+
+    assert!(result.is_err());
+    // No entry is removed if the predicate panics.
+    assert_eq!(*prefixed, BTreeMap::from_iter([(1, 10), (2, 20)]));
+}
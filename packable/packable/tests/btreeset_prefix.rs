@@ -8,7 +8,7 @@ use std::collections::BTreeSet;
 use packable::{
     bounded::{
         BoundedU16, BoundedU32, BoundedU64, BoundedU8, InvalidBoundedU16, InvalidBoundedU32, InvalidBoundedU64,
-        InvalidBoundedU8, TryIntoBoundedU32Error,
+        InvalidBoundedU8, TryIntoBoundedU32Error, TryIntoBoundedU8Error,
     },
     error::UnpackError,
     prefix::BTreeSetPrefix,
@@ -246,3 +246,48 @@ impl_packable_test_for_bounded_btreeset_prefix!(
     1,
     64
 );
+
+#[test]
+fn btreeset_prefix_bounded_mutation() {
+    let mut prefixed = BTreeSetPrefix::<u8, BoundedU8<1, 3>>::try_from(BTreeSet::from([1])).unwrap();
+
+    assert!(prefixed.try_insert(2).unwrap());
+    assert!(!prefixed.try_insert(2).unwrap());
+    assert!(prefixed.try_insert(3).unwrap());
+    // Inserting an existing value does not change the length.
+    assert!(!prefixed.try_insert(3).unwrap());
+    assert!(matches!(prefixed.try_insert(4), Err(TryIntoBoundedU8Error::Invalid(4))));
+
+    assert!(prefixed.remove(&1).unwrap());
+    assert!(!prefixed.remove(&1).unwrap());
+    assert!(matches!(
+        prefixed.try_extend([3, 4, 5]),
+        Err(TryIntoBoundedU8Error::Invalid(4))
+    ));
+    prefixed.try_extend([2, 3, 4]).unwrap();
+    assert_eq!(*prefixed, BTreeSet::from([2, 3, 4]));
+
+    assert!(matches!(
+        prefixed.retain(|_| false),
+        Err(TryIntoBoundedU8Error::Invalid(0))
+    ));
+    assert_eq!(*prefixed, BTreeSet::from([2, 3, 4]));
+    prefixed.retain(|value| *value != 3).unwrap();
+    assert_eq!(*prefixed, BTreeSet::from([2, 4]));
+
+    assert!(prefixed.remove(&2).unwrap());
+    assert!(matches!(prefixed.remove(&4), Err(TryIntoBoundedU8Error::Invalid(0))));
+}
+
+#[test]
+fn btreeset_prefix_retain_panic() {
+    let mut prefixed = BTreeSetPrefix::<u8, BoundedU8<1, 3>>::try_from(BTreeSet::from([1, 2])).unwrap();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        prefixed.retain(|value| value == &1 || panic!())
+    }));
+
+    assert!(result.is_err());
+    // No value is removed if the predicate panics.
+    assert_eq!(*prefixed, BTreeSet::from([1, 2]));
+}
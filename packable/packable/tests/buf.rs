@@ -0,0 +1,68 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "bytes")]
+
+use bytes::{Buf, BytesMut};
+use packable::{
+    error::{UnexpectedEOF, UnpackError},
+    packer::{BufMutPacker, Packer},
+    prefix::VecPrefix,
+    unpacker::{BufUnpacker, Unpacker},
+    Packable, PackableExt,
+};
+
+#[test]
+fn bytes_mut_packer() {
+    let value = (0x1234u16, VecPrefix::<u32, u8>::try_from(vec![1, 2, 3]).unwrap());
+
+    let mut buffer = BytesMut::new();
+    value.pack(&mut buffer).unwrap();
+
+    assert_eq!(&buffer[..], &value.pack_to_vec()[..]);
+    assert_eq!(buffer.written_bytes(), Some(value.packed_len()));
+}
+
+#[test]
+fn buf_mut_packer() {
+    let value = 0x01020304u32;
+
+    let mut array = [0u8; 6];
+    let mut packer = BufMutPacker::new(&mut array[..]);
+    value.pack(&mut packer).unwrap();
+    assert_eq!(packer.written_bytes(), Some(4));
+
+    // Only 2 bytes are left in the buffer.
+    assert!(matches!(
+        value.pack(&mut packer),
+        Err(UnexpectedEOF { required: 4, had: 2 })
+    ));
+    assert_eq!(array[..4], value.to_le_bytes());
+}
+
+#[test]
+fn buf_unpacker_chained() {
+    let value = (0x1234u16, VecPrefix::<u32, u8>::try_from(vec![1, 2, 3]).unwrap());
+    let bytes = value.pack_to_vec();
+
+    // Split the packed bytes in non-contiguous chunks in the middle of an integer.
+    let (first, second) = bytes.split_at(5);
+    let mut unpacker = BufUnpacker::new(first.chain(second));
+
+    let unpacked = <(u16, VecPrefix<u32, u8>)>::unpack_verified(&mut unpacker, &()).unwrap();
+
+    assert_eq!(unpacked, value);
+    assert_eq!(unpacker.read_bytes(), Some(bytes.len()));
+    assert!(!unpacker.into_inner().has_remaining());
+}
+
+#[test]
+fn buf_unpacker_eof() {
+    let bytes = [3u8, 1, 0, 0, 0];
+    let mut unpacker = BufUnpacker::new(&bytes[..]);
+
+    assert!(matches!(
+        VecPrefix::<u32, u8>::unpack_verified(&mut unpacker, &()),
+        Err(UnpackError::Unpacker(UnexpectedEOF { .. }))
+    ));
+}
@@ -0,0 +1,42 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::{
+    error::{UnpackBytesError, UnpackError},
+    prefix::VecPrefix,
+    PackableExt, StrictBool,
+};
+
+#[test]
+fn canonical_bytes() {
+    let value = (0x1234u16, VecPrefix::<u16, u8>::try_from(vec![1, 2]).unwrap());
+    let bytes = value.pack_to_vec();
+
+    assert_eq!(
+        <(u16, VecPrefix<u16, u8>)>::unpack_bytes_canonical(&bytes, &()).unwrap(),
+        value
+    );
+    assert!(StrictBool::unpack_bytes_canonical([1u8], &()).unwrap().into_inner());
+}
+
+#[test]
+fn non_canonical_bool() {
+    let unpacked = <(u8, bool)>::unpack_bytes_canonical([7u8, 1], &()).unwrap();
+    assert_eq!(unpacked, (7, true));
+
+    let unpacked = <(u8, bool)>::unpack_bytes_canonical([7u8, 0x7f], &());
+    assert!(matches!(
+        unpacked,
+        Err(UnpackError::Unpacker(UnpackBytesError::NonCanonical { offset: 1 }))
+    ));
+}
+
+#[test]
+fn non_canonical_bytes_are_checked_after_trailing_bytes() {
+    let unpacked = bool::unpack_bytes_canonical([0x7fu8, 0], &());
+
+    assert!(matches!(
+        unpacked,
+        Err(UnpackError::Unpacker(UnpackBytesError::TrailingBytes { remaining: 1 }))
+    ));
+}
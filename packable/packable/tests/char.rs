@@ -0,0 +1,29 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use packable::{
+    error::{InvalidCharError, UnpackError},
+    PackableExt,
+};
+
+#[test]
+fn packable_char() {
+    assert_eq!(common::generic_test(&'a').0, 0x61u32.pack_to_vec());
+    assert_eq!(common::generic_test(&'🦀').0, 0x1f980u32.pack_to_vec());
+}
+
+#[test]
+fn packable_char_rejects_invalid_scalar_values() {
+    let unpacked = char::unpack_bytes_verified(0xd800u32.pack_to_vec(), &());
+
+    assert!(matches!(unpacked, Err(UnpackError::Packable(InvalidCharError(0xd800)))));
+
+    let unpacked = char::unpack_bytes_verified(0x110000u32.pack_to_vec(), &());
+
+    assert!(matches!(
+        unpacked,
+        Err(UnpackError::Packable(InvalidCharError(0x110000)))
+    ));
+}
@@ -0,0 +1,117 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "codec")]
+
+use bytes::{BufMut, BytesMut};
+use packable::{
+    bounded::{BoundedU16, InvalidBoundedU16},
+    codec::{CodecError, PackableCodec},
+    prefix::{UnpackPrefixError, VecPrefix},
+    PackableExt,
+};
+use tokio_util::codec::{Decoder, Encoder};
+
+type Value = VecPrefix<u32, u8>;
+
+#[test]
+fn codec_roundtrip() {
+    let first = Value::try_from(vec![1, 2, 3]).unwrap();
+    let second = Value::try_from(vec![4, 5]).unwrap();
+
+    let mut codec = PackableCodec::<Value, u16>::verified(64, ());
+    let mut buffer = BytesMut::new();
+
+    codec.encode(&first, &mut buffer).unwrap();
+    codec.encode(second.clone(), &mut buffer).unwrap();
+
+    let mut expected = Vec::new();
+    expected.extend_from_slice(&(first.packed_len() as u16).to_le_bytes());
+    expected.extend_from_slice(&first.pack_to_vec());
+    expected.extend_from_slice(&(second.packed_len() as u16).to_le_bytes());
+    expected.extend_from_slice(&second.pack_to_vec());
+    assert_eq!(&buffer[..], &expected[..]);
+
+    assert_eq!(codec.decode(&mut buffer).unwrap(), Some(first));
+    assert_eq!(codec.decode(&mut buffer).unwrap(), Some(second));
+    assert_eq!(codec.decode(&mut buffer).unwrap(), None);
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn codec_partial_frames() {
+    let value = Value::try_from(vec![1, 2, 3]).unwrap();
+
+    let mut codec = PackableCodec::<Value>::unverified(64);
+    let mut encoded = BytesMut::new();
+    codec.encode(&value, &mut encoded).unwrap();
+
+    let mut buffer = BytesMut::new();
+
+    // Feed the frame one byte at a time, including the length prefix.
+    for byte in encoded.iter() {
+        assert_eq!(codec.decode(&mut buffer).unwrap(), None);
+        buffer.put_u8(*byte);
+    }
+
+    assert_eq!(codec.decode(&mut buffer).unwrap(), Some(value));
+}
+
+#[test]
+fn codec_frame_too_large() {
+    let value = Value::try_from(vec![0; 16]).unwrap();
+
+    let mut codec = PackableCodec::<Value, u8>::unverified(16);
+    let mut buffer = BytesMut::new();
+
+    assert!(matches!(
+        codec.encode(&value, &mut buffer),
+        Err(CodecError::FrameTooLarge { len: 65, max: 16 })
+    ));
+    assert!(buffer.is_empty());
+
+    buffer.put_slice(&[17]);
+    assert!(matches!(
+        codec.decode(&mut buffer),
+        Err(CodecError::FrameTooLarge { len: 17, max: 16 })
+    ));
+}
+
+#[test]
+fn codec_invalid_prefix() {
+    let mut codec = PackableCodec::<Value, BoundedU16<1, 8>>::verified(64, ());
+    let mut buffer = BytesMut::from(&[0u8, 0][..]);
+
+    assert!(matches!(
+        codec.decode(&mut buffer),
+        Err(CodecError::Prefix(InvalidBoundedU16(0)))
+    ));
+}
+
+#[test]
+fn codec_truncated_frame() {
+    let mut codec = PackableCodec::<Value, u8>::verified(64, ());
+    // The frame is 3 bytes long but the value needs 5.
+    let mut buffer = BytesMut::from(&[3u8, 1, 0, 0][..]);
+
+    assert!(matches!(codec.decode(&mut buffer), Err(CodecError::Truncated(_))));
+}
+
+#[test]
+fn codec_trailing_bytes() {
+    let mut codec = PackableCodec::<Value, u8>::verified(64, ());
+    let mut buffer = BytesMut::from(&[7u8, 1, 0, 0, 0, 0, 0xff, 0xff][..]);
+
+    assert!(matches!(codec.decode(&mut buffer), Err(CodecError::TrailingBytes(2))));
+}
+
+#[test]
+fn codec_packable_error() {
+    let mut codec = PackableCodec::<VecPrefix<u8, BoundedU16<0, 1>>, u8>::verified(64, ());
+    let mut buffer = BytesMut::from(&[4u8, 2, 0, 0, 0][..]);
+
+    assert!(matches!(
+        codec.decode(&mut buffer),
+        Err(CodecError::Packable(UnpackPrefixError::Prefix(InvalidBoundedU16(2))))
+    ));
+}
@@ -0,0 +1,32 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use std::borrow::Cow;
+
+use packable::PackableExt;
+
+#[test]
+fn packable_cow() {
+    assert_eq!(
+        common::generic_test(&Cow::<'static, u32>::Borrowed(&42)).0,
+        42u32.pack_to_vec()
+    );
+}
+
+#[test]
+fn packable_cow_str() {
+    assert_eq!(
+        common::generic_test(&Cow::<'static, str>::Borrowed("yellow submarine")).0,
+        "yellow submarine".to_owned().pack_to_vec()
+    );
+}
+
+#[test]
+fn packable_cow_slice() {
+    assert_eq!(
+        common::generic_test(&Cow::<'static, [u16]>::Borrowed(&[1, 2, 3])).0,
+        vec![1u16, 2, 3].pack_to_vec()
+    );
+}
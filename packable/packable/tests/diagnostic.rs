@@ -0,0 +1,155 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::Infallible;
+
+use packable::{
+    error::{UnknownTagError, UnpackError},
+    prefix::{UnpackPrefixError, VecPrefix},
+    unpacker::{DiagnosticUnpacker, PathSegment, SliceUnpacker},
+    Packable, PackableExt,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum BlockError {
+    InvalidUnlock(u8),
+    UnknownPayload(u8),
+    InvalidInputs,
+}
+
+impl From<Infallible> for BlockError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<UnknownTagError<u8>> for BlockError {
+    fn from(err: UnknownTagError<u8>) -> Self {
+        Self::UnknownPayload(err.0)
+    }
+}
+
+impl From<UnpackPrefixError<BlockError, Infallible>> for BlockError {
+    fn from(err: UnpackPrefixError<BlockError, Infallible>) -> Self {
+        match err {
+            UnpackPrefixError::Item(err) => err,
+            _ => Self::InvalidInputs,
+        }
+    }
+}
+
+fn verify_unlock(unlock: &u8) -> Result<(), BlockError> {
+    if *unlock == 0 {
+        Ok(())
+    } else {
+        Err(BlockError::InvalidUnlock(*unlock))
+    }
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(unpack_error = BlockError)]
+pub struct Input {
+    id: u32,
+    #[packable(verify_with = verify_unlock)]
+    unlock: u8,
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(unpack_error = BlockError)]
+pub struct Transaction {
+    inputs: VecPrefix<Input, u8>,
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(unpack_error = BlockError)]
+#[packable(tag_type = u8)]
+pub enum Payload {
+    #[packable(tag = 0)]
+    Transaction(Transaction),
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(unpack_error = BlockError)]
+pub struct Block {
+    version: u8,
+    payload: Payload,
+}
+
+fn block() -> Block {
+    Block {
+        version: 1,
+        payload: Payload::Transaction(Transaction {
+            inputs: VecPrefix::try_from(vec![Input { id: 1, unlock: 0 }, Input { id: 2, unlock: 0 }]).unwrap(),
+        }),
+    }
+}
+
+#[test]
+fn diagnostic_success() {
+    let bytes = block().pack_to_vec();
+    let mut unpacker = DiagnosticUnpacker::new(SliceUnpacker::new(&bytes));
+
+    assert_eq!(Block::unpack_verified(&mut unpacker, &()).unwrap(), block());
+    assert!(unpacker.path().is_empty());
+    assert_eq!(unpacker.offset(), bytes.len());
+    assert_eq!(unpacker.report().to_string(), "byte offset 13");
+}
+
+#[test]
+fn diagnostic_verification_error() {
+    let mut bytes = block().pack_to_vec();
+    // The unlock of the second input.
+    bytes[12] = 7;
+    let mut unpacker = DiagnosticUnpacker::new(SliceUnpacker::new(&bytes));
+
+    assert!(matches!(
+        Block::unpack_verified(&mut unpacker, &()),
+        Err(UnpackError::Packable(BlockError::InvalidUnlock(7)))
+    ));
+    assert_eq!(
+        unpacker.path(),
+        [
+            PathSegment::Type("Block"),
+            PathSegment::Field("payload"),
+            PathSegment::Type("Payload"),
+            PathSegment::Variant("Transaction"),
+            PathSegment::Type("Transaction"),
+            PathSegment::Field("inputs"),
+            PathSegment::Index(1),
+            PathSegment::Type("Input"),
+            PathSegment::Field("unlock"),
+        ]
+    );
+    assert_eq!(
+        unpacker.report().to_string(),
+        "Block.payload::Transaction.inputs[1].unlock at byte offset 13"
+    );
+}
+
+#[test]
+fn diagnostic_unexpected_eof() {
+    let bytes = block().pack_to_vec();
+    let mut unpacker = DiagnosticUnpacker::new(SliceUnpacker::new(&bytes[..10]));
+
+    assert!(matches!(
+        Block::unpack_verified(&mut unpacker, &()),
+        Err(UnpackError::Unpacker(_))
+    ));
+    assert_eq!(
+        unpacker.report().to_string(),
+        "Block.payload::Transaction.inputs[1].id at byte offset 8"
+    );
+}
+
+#[test]
+fn diagnostic_unknown_tag() {
+    let mut bytes = block().pack_to_vec();
+    bytes[1] = 3;
+    let mut unpacker = DiagnosticUnpacker::new(SliceUnpacker::new(&bytes));
+
+    assert!(matches!(
+        Block::unpack_verified(&mut unpacker, &()),
+        Err(UnpackError::Packable(BlockError::UnknownPayload(3)))
+    ));
+    assert_eq!(unpacker.report().to_string(), "Block.payload at byte offset 2");
+}
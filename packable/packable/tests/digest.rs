@@ -0,0 +1,44 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "digest")]
+
+use blake2::Blake2b512;
+use packable::{
+    packer::{DigestPacker, Packer},
+    prefix::VecPrefix,
+    Packable, PackableExt,
+};
+use sha2::{Digest, Sha256};
+
+fn value() -> (VecPrefix<u64, u16>, u32, [u8; 8]) {
+    (VecPrefix::try_from(vec![1, 2, 3, u64::MAX]).unwrap(), 42, *b"packable")
+}
+
+#[test]
+fn hash_packed_sha256() {
+    let value = value();
+
+    assert_eq!(value.hash_packed::<Sha256>(), Sha256::digest(value.pack_to_vec()));
+}
+
+#[test]
+fn hash_packed_blake2b() {
+    let value = value();
+
+    assert_eq!(
+        value.hash_packed::<Blake2b512>(),
+        Blake2b512::digest(value.pack_to_vec())
+    );
+}
+
+#[test]
+fn digest_packer_written_bytes() {
+    let value = value();
+    let mut packer = DigestPacker::new(Sha256::new());
+
+    value.pack(&mut packer).unwrap();
+
+    assert_eq!(packer.written_bytes(), Some(value.packed_len()));
+    assert_eq!(packer.into_inner().finalize(), Sha256::digest(value.pack_to_vec()));
+}
@@ -0,0 +1,29 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use core::time::Duration;
+
+use packable::{
+    error::{InvalidDurationError, UnpackError},
+    PackableExt,
+};
+
+#[test]
+fn packable_duration() {
+    assert_eq!(
+        common::generic_test(&Duration::new(42, 999_999_999)).0,
+        (42u64, 999_999_999u32).pack_to_vec()
+    );
+}
+
+#[test]
+fn packable_duration_rejects_invalid_nanos() {
+    let unpacked = Duration::unpack_bytes_verified((42u64, 1_000_000_000u32).pack_to_vec(), &());
+
+    assert!(matches!(
+        unpacked,
+        Err(UnpackError::Packable(InvalidDurationError(1_000_000_000)))
+    ));
+}
@@ -0,0 +1,114 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use core::convert::Infallible;
+
+use packable::{
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    prefix::VecPrefix,
+    unpacker::Unpacker,
+    Packable, PackableExt,
+};
+
+// A `u32` packed in big-endian order.
+mod be_u32 {
+    use super::*;
+
+    pub fn pack<P: Packer>(value: &u32, packer: &mut P) -> Result<(), P::Error> {
+        packer.pack_bytes(value.to_be_bytes())
+    }
+
+    pub fn unpack<U: Unpacker>(unpacker: &mut U, _: Option<&()>) -> Result<u32, UnpackError<Infallible, U::Error>> {
+        let mut bytes = [0u8; 4];
+        unpacker.unpack_bytes(&mut bytes)?;
+
+        Ok(u32::from_be_bytes(bytes))
+    }
+}
+
+#[derive(Debug)]
+pub struct NonAsciiError(u8);
+
+impl From<Infallible> for NonAsciiError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+// A `char` packed as a single ASCII byte.
+fn pack_ascii<P: Packer>(value: &char, packer: &mut P) -> Result<(), P::Error> {
+    (*value as u8).pack(packer)
+}
+
+fn unpack_ascii<U: Unpacker>(
+    unpacker: &mut U,
+    visitor: Option<&()>,
+) -> Result<char, UnpackError<NonAsciiError, U::Error>> {
+    let byte = u8::unpack(unpacker, visitor).coerce()?;
+
+    if byte.is_ascii() {
+        Ok(byte as char)
+    } else {
+        Err(UnpackError::Packable(NonAsciiError(byte)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Packable)]
+#[packable(unpack_error = NonAsciiError)]
+pub struct Record {
+    id: u16,
+    #[packable(with = be_u32)]
+    value: u32,
+    #[packable(pack_with = pack_ascii, unpack_with = unpack_ascii)]
+    symbol: char,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Packable)]
+#[packable(tag_type = u8)]
+pub enum Message {
+    #[packable(tag = 0)]
+    Value(#[packable(with = be_u32)] u32),
+    #[packable(unknown)]
+    Unknown(u8, VecPrefix<u8, u32>),
+}
+
+#[test]
+fn packable_field_with() {
+    let value = Record {
+        id: 1,
+        value: 2,
+        symbol: 'a',
+    };
+
+    assert_eq!(common::generic_test(&value).0, [1, 0, 0, 0, 0, 2, b'a']);
+    assert_eq!(Record::PACKED_LEN, None);
+}
+
+#[test]
+fn packable_field_with_error() {
+    assert!(matches!(
+        Record::unpack_bytes_verified([1, 0, 0, 0, 0, 2, 0xff], &()),
+        Err(UnpackError::Packable(NonAsciiError(0xff)))
+    ));
+}
+
+#[test]
+fn packable_field_with_skip() {
+    let bytes = [1, 0, 0, 0, 0, 2, b'a', 7];
+    let mut unpacker = packable::unpacker::SliceUnpacker::new(&bytes);
+
+    Record::skip(&mut unpacker, Some(&())).unwrap();
+
+    assert_eq!(u8::unpack_verified(&mut unpacker, &()).unwrap(), 7);
+}
+
+#[test]
+fn packable_field_with_delimited_payload() {
+    assert_eq!(
+        common::generic_test(&Message::Value(42)).0,
+        [0, 4, 0, 0, 0, 0, 0, 0, 42]
+    );
+}
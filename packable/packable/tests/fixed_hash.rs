@@ -0,0 +1,24 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "primitive-types")]
+
+mod common;
+
+use primitive_types::{H160, H256, H512};
+
+macro_rules! impl_packable_test_for_hash {
+    ($name:ident, $ty:ident) => {
+        #[test]
+        fn $name() {
+            let value = $ty::repeat_byte(0x6F);
+            let bytes = common::generic_test(&value);
+
+            assert_eq!(bytes.0, value.as_bytes());
+        }
+    };
+}
+
+impl_packable_test_for_hash!(packable_h160, H160);
+impl_packable_test_for_hash!(packable_h256, H256);
+impl_packable_test_for_hash!(packable_h512, H512);
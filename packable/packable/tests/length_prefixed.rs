@@ -0,0 +1,133 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use core::convert::Infallible;
+
+use packable::{
+    bounded::BoundedU16,
+    error::{DelimitedLenError, UnpackError},
+    prefix::VecPrefix,
+    Packable, PackableExt,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Packable)]
+#[packable(unpack_error = DelimitedLenError)]
+pub struct Envelope {
+    version: u8,
+    #[packable(length_prefixed = u16)]
+    body: (u32, u8),
+    #[packable(length_prefixed = u32)]
+    tail: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Packable)]
+#[packable(unpack_error = DelimitedLenError)]
+pub struct Wrapper(#[packable(length_prefixed = u16)] u32);
+
+#[derive(Debug)]
+pub enum BytesError {
+    Len(DelimitedLenError),
+    Prefix,
+}
+
+impl From<Infallible> for BytesError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<DelimitedLenError> for BytesError {
+    fn from(err: DelimitedLenError) -> Self {
+        Self::Len(err)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Packable)]
+#[packable(unpack_error = BytesError)]
+pub struct Bytes(
+    #[packable(length_prefixed = u16)]
+    #[packable(unpack_error_with = |_| BytesError::Prefix)]
+    VecPrefix<u8, BoundedU16<0, 1024>>,
+);
+
+#[test]
+fn packable_length_prefixed_round_trip() {
+    let value = Envelope {
+        version: 1,
+        body: (2, 3),
+        tail: 4,
+    };
+
+    assert_eq!(
+        common::generic_test(&value).0,
+        [1, 5, 0, 2, 0, 0, 0, 3, 8, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0]
+    );
+    assert_eq!(Envelope::PACKED_LEN, Some(20));
+}
+
+#[test]
+fn packable_length_prefixed_newtype() {
+    assert_eq!(common::generic_test(&Wrapper(42)).0, [4, 0, 42, 0, 0, 0]);
+}
+
+#[test]
+fn packable_length_prefixed_over_consume() {
+    // The prefix claims 3 bytes but the `u32` needs 4.
+    let bytes = [3, 0, 42, 0, 0, 0];
+
+    assert!(matches!(
+        Wrapper::unpack_bytes_verified(bytes, &()),
+        Err(UnpackError::Packable(DelimitedLenError {
+            expected: 3,
+            consumed: 4
+        }))
+    ));
+}
+
+#[test]
+fn packable_length_prefixed_under_consume() {
+    // The prefix claims 5 bytes but the `u32` only uses 4.
+    let bytes = [5, 0, 42, 0, 0, 0, 0];
+
+    assert!(matches!(
+        Wrapper::unpack_bytes_verified(bytes, &()),
+        Err(UnpackError::Packable(DelimitedLenError {
+            expected: 5,
+            consumed: 4
+        }))
+    ));
+}
+
+#[test]
+fn packable_length_prefixed_skip() {
+    // Skipping only relies on the prefix, so trailing bytes of the payload are not an error.
+    let bytes = [6, 0, 1, 2, 3, 4, 5, 6, 7];
+    let mut unpacker = packable::unpacker::SliceUnpacker::new(&bytes);
+
+    Wrapper::skip(&mut unpacker, Some(&())).unwrap();
+
+    assert_eq!(u8::unpack_verified(&mut unpacker, &()).unwrap(), 7);
+}
+
+#[test]
+fn packable_length_prefixed_packed_len() {
+    // The `VecPrefix` is packed as a `u16` length followed by its bytes.
+    let value = Bytes(vec![0; 100].try_into().unwrap());
+
+    assert_eq!(value.packed_len(), 2 + 2 + 100);
+    assert_eq!(value.packed_len(), value.pack_to_vec().len());
+}
+
+#[test]
+fn packable_length_prefixed_max_packed_len() {
+    assert_eq!(Envelope::MAX_PACKED_LEN, Some(20));
+    assert_eq!(Bytes::MAX_PACKED_LEN, Some(2 + 2 + 1024));
+}
+
+#[test]
+fn packable_length_prefixed_too_long() {
+    // Values that do not fit in the length prefix cannot be built.
+    assert!(VecPrefix::<u8, BoundedU16<0, 1024>>::try_from(vec![0; 1025]).is_err());
+}
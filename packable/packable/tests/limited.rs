@@ -0,0 +1,116 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::{
+    error::{LimitError, UnexpectedEOF, UnpackError},
+    prefix::{StringPrefix, VecPrefix},
+    unpacker::{IoUnpacker, LimitedUnpacker, SliceUnpacker},
+    Packable, PackableExt,
+};
+
+#[test]
+fn limited_roundtrip() {
+    let value = VecPrefix::<VecPrefix<u32, u8>, u8>::try_from(vec![
+        VecPrefix::try_from(vec![1, 2, 3]).unwrap(),
+        VecPrefix::try_from(vec![4, 5]).unwrap(),
+    ])
+    .unwrap();
+    let bytes = value.pack_to_vec();
+
+    let mut unpacker = LimitedUnpacker::new(SliceUnpacker::new(&bytes), 1024, 2);
+    let unpacked = VecPrefix::<VecPrefix<u32, u8>, u8>::unpack_verified(&mut unpacker, &()).unwrap();
+
+    assert_eq!(value, unpacked);
+    assert_eq!(unpacker.depth(), 0);
+}
+
+#[test]
+fn huge_prefix_slice() {
+    // A length prefix of `u32::MAX` followed by a handful of bytes must not allocate the whole sequence upfront.
+    let bytes = u32::MAX.to_le_bytes().into_iter().chain([0; 4]).collect::<Vec<_>>();
+
+    assert!(matches!(
+        VecPrefix::<u8, u32>::unpack_bytes_verified(&bytes, &()),
+        Err(UnpackError::Unpacker(UnexpectedEOF { had: 4, .. }))
+    ));
+    assert!(matches!(
+        StringPrefix::<u32>::unpack_bytes_verified(&bytes, &()),
+        Err(UnpackError::Unpacker(UnexpectedEOF { had: 4, .. }))
+    ));
+    assert!(matches!(
+        VecPrefix::<u64, u32>::unpack_bytes_verified(&bytes, &()),
+        Err(UnpackError::Unpacker(UnexpectedEOF { .. }))
+    ));
+}
+
+#[test]
+fn huge_prefix_io() {
+    let bytes = u32::MAX.to_le_bytes().into_iter().chain([0; 4]).collect::<Vec<_>>();
+
+    let mut unpacker = IoUnpacker::new(bytes.as_slice());
+
+    assert!(matches!(
+        VecPrefix::<u8, u32>::unpack_verified(&mut unpacker, &()),
+        Err(UnpackError::Unpacker(_))
+    ));
+}
+
+#[test]
+fn allocation_limit() {
+    let bytes = VecPrefix::<u8, u32>::try_from(vec![0; 64]).unwrap().pack_to_vec();
+
+    let mut unpacker = LimitedUnpacker::new(SliceUnpacker::new(&bytes), 32, 8);
+
+    assert!(matches!(
+        VecPrefix::<u8, u32>::unpack_verified(&mut unpacker, &()),
+        Err(UnpackError::Unpacker(LimitError::AllocationLimit {
+            required: 64,
+            remaining: 32
+        }))
+    ));
+}
+
+#[test]
+fn allocation_limit_items() {
+    let bytes = VecPrefix::<u64, u32>::try_from(vec![0; 64]).unwrap().pack_to_vec();
+
+    let mut unpacker = LimitedUnpacker::new(SliceUnpacker::new(&bytes), 64, 8);
+
+    assert!(matches!(
+        VecPrefix::<u64, u32>::unpack_verified(&mut unpacker, &()),
+        Err(UnpackError::Unpacker(LimitError::AllocationLimit {
+            required: 8,
+            remaining: 0
+        }))
+    ));
+}
+
+#[test]
+fn depth_limit() {
+    let value = Box::new(Box::new(Box::new(42u8)));
+    let bytes = value.pack_to_vec();
+
+    let mut unpacker = LimitedUnpacker::new(SliceUnpacker::new(&bytes), 1024, 3);
+    assert_eq!(Box::<Box<Box<u8>>>::unpack_verified(&mut unpacker, &()).unwrap(), value);
+
+    let mut unpacker = LimitedUnpacker::new(SliceUnpacker::new(&bytes), 1024, 2);
+    assert!(matches!(
+        Box::<Box<Box<u8>>>::unpack_verified(&mut unpacker, &()),
+        Err(UnpackError::Unpacker(LimitError::DepthLimit(2)))
+    ));
+}
+
+#[test]
+fn inner_unpacker_error() {
+    let bytes = 4u32.to_le_bytes();
+
+    let mut unpacker = LimitedUnpacker::new(SliceUnpacker::new(&bytes), 1024, 8);
+
+    assert!(matches!(
+        VecPrefix::<u8, u32>::unpack_verified(&mut unpacker, &()),
+        Err(UnpackError::Unpacker(LimitError::Unpacker(UnexpectedEOF {
+            required: 4,
+            had: 0
+        })))
+    ));
+}
@@ -2,7 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use hashbrown::HashMap;
-use packable::{error::UnpackError, map::UnpackMapError, PackableExt};
+use packable::{
+    error::UnpackError,
+    map::{UnpackMapError, UnpackOrderedMapError},
+    PackableExt,
+};
 
 mod common;
 
@@ -33,6 +37,46 @@ fn invalid_duplicate() {
 
     assert!(matches!(
         prefixed,
-        Err(UnpackError::Packable(UnpackMapError::DuplicateKey(3u8))),
+        Err(UnpackError::Packable(UnpackOrderedMapError::Map(
+            UnpackMapError::DuplicateKey(3u8)
+        ))),
     ));
 }
+
+#[test]
+fn invalid_unordered() {
+    let bytes = [(1, 4), (2, 3), (4, 1), (3, 2)];
+    let bytes = Vec::from_iter(
+        bytes
+            .len()
+            .to_le_bytes()
+            .into_iter()
+            .chain(bytes.into_iter().flat_map(|(k, v)| [k, v])),
+    );
+
+    let prefixed = HashMap::<u8, u8>::unpack_bytes_verified(bytes, &());
+
+    assert!(matches!(
+        prefixed,
+        Err(UnpackError::Packable(UnpackOrderedMapError::Unordered)),
+    ));
+}
+
+#[test]
+fn canonical_packing() {
+    let a = HashMap::<u16, u8>::from_iter((0..64).rev().zip(0..64));
+    let b = HashMap::<u16, u8>::from_iter((0..64).zip((0..64).rev()));
+
+    let bytes = a.pack_to_vec();
+
+    assert_eq!(bytes, b.pack_to_vec());
+    assert_eq!(
+        bytes,
+        Vec::from_iter(
+            64u64
+                .to_le_bytes()
+                .into_iter()
+                .chain((0u16..64).flat_map(|k| k.to_le_bytes().into_iter().chain([63 - k as u8])))
+        )
+    );
+}
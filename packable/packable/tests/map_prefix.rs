@@ -7,10 +7,10 @@ use hashbrown::HashMap;
 use packable::{
     bounded::{
         BoundedU16, BoundedU32, BoundedU64, BoundedU8, InvalidBoundedU16, InvalidBoundedU32, InvalidBoundedU64,
-        InvalidBoundedU8, TryIntoBoundedU32Error,
+        InvalidBoundedU8, TryIntoBoundedU32Error, TryIntoBoundedU8Error,
     },
     error::UnpackError,
-    map::UnpackMapError,
+    map::{UnpackMapError, UnpackOrderedMapError},
     prefix::HashMapPrefix,
     PackableExt,
 };
@@ -31,10 +31,28 @@ fn map_prefix_from_map_truncated_error() {
     assert!(prefixed.is_err());
 }
 
+#[test]
+fn map_prefix_canonical_packing() {
+    let a = HashMapPrefix::<u16, u8, u8>::try_from(HashMap::from_iter((0..64).rev().zip(0..64))).unwrap();
+    let b = HashMapPrefix::<u16, u8, u8>::try_from(HashMap::from_iter((0..64).zip((0..64).rev()))).unwrap();
+
+    let bytes = a.pack_to_vec();
+
+    assert_eq!(a, b);
+    assert_eq!(bytes, b.pack_to_vec());
+    assert_eq!(
+        bytes,
+        Vec::from_iter(
+            core::iter::once(64u8).chain((0u16..64).flat_map(|k| k.to_le_bytes().into_iter().chain([63 - k as u8])))
+        )
+    );
+}
+
 macro_rules! impl_packable_test_for_map_prefix {
     (
         $packable_map_prefix:ident,
         $packable_map_prefix_duplicate:ident,
+        $packable_map_prefix_unordered:ident,
         $ty:ty) => {
         #[test]
         fn $packable_map_prefix() {
@@ -65,9 +83,29 @@ macro_rules! impl_packable_test_for_map_prefix {
 
             assert!(matches!(
                 prefixed,
-                Err(UnpackError::Packable(
+                Err(UnpackError::Packable(UnpackOrderedMapError::Map(
                     UnpackMapError::DuplicateKey(d)
-                )) if d == dup
+                ))) if d == dup
+            ));
+        }
+
+        #[test]
+        fn $packable_map_prefix_unordered() {
+            const LEN: usize = 64;
+            const LEN_AS_TY: $ty = LEN as $ty;
+
+            let mut bytes = (0u8..LEN as u8).zip((0u8..LEN as u8).rev()).flat_map(|(k, v)| [k, v]).collect::<Vec<_>>();
+            // Swap the key and value at index 0 and 1
+            let (s1, s2) = bytes.split_at_mut(2);
+            s1.swap_with_slice(&mut s2[..2]);
+
+            let bytes = Vec::from_iter(LEN_AS_TY.to_le_bytes().into_iter().chain(bytes));
+
+            let prefixed = HashMapPrefix::<u8, u8, $ty>::unpack_bytes_verified(bytes, &());
+
+            assert!(matches!(
+                prefixed,
+                Err(UnpackError::Packable(UnpackOrderedMapError::Unordered)),
             ));
         }
     };
@@ -78,6 +116,7 @@ macro_rules! impl_packable_test_for_bounded_map_prefix {
         $packable_map_prefix:ident,
         $packable_map_prefix_invalid_length:ident,
         $packable_map_prefix_duplicate:ident,
+        $packable_map_prefix_unordered:ident,
         $ty:ty,
         $bounded:ident,
         $err:ident,
@@ -109,9 +148,9 @@ macro_rules! impl_packable_test_for_bounded_map_prefix {
 
             assert!(matches!(
                 prefixed,
-                Err(UnpackError::Packable(
+                Err(UnpackError::Packable(UnpackOrderedMapError::Map(
                     UnpackMapError::Prefix($err(LEN_AS_TY))
-                )),
+                ))),
             ));
         }
 
@@ -130,23 +169,64 @@ macro_rules! impl_packable_test_for_bounded_map_prefix {
 
             assert!(matches!(
                 prefixed,
-                Err(UnpackError::Packable(
+                Err(UnpackError::Packable(UnpackOrderedMapError::Map(
                     UnpackMapError::DuplicateKey(d)
-                )) if d == dup
+                ))) if d == dup
+            ));
+        }
+
+        #[test]
+        fn $packable_map_prefix_unordered() {
+            const LEN: usize = $max;
+            const LEN_AS_TY: $ty = LEN as $ty;
+
+            let mut bytes = (0u8..LEN as u8).zip((0u8..LEN as u8).rev()).flat_map(|(k, v)| [k, v]).collect::<Vec<_>>();
+            // Swap the key and value at index 0 and 1
+            let (s1, s2) = bytes.split_at_mut(2);
+            s1.swap_with_slice(&mut s2[..2]);
+
+            let bytes = Vec::from_iter(LEN_AS_TY.to_le_bytes().into_iter().chain(bytes));
+
+            let prefixed = HashMapPrefix::<u8, u8, $bounded<$min, $max>>::unpack_bytes_verified(bytes, &());
+
+            assert!(matches!(
+                prefixed,
+                Err(UnpackError::Packable(UnpackOrderedMapError::Unordered)),
             ));
         }
     };
 }
 
-impl_packable_test_for_map_prefix!(packable_map_prefix_u8, packable_map_prefix_duplicate_u8, u8);
-impl_packable_test_for_map_prefix!(packable_map_prefix_u16, packable_map_prefix_duplicate_u16, u16);
-impl_packable_test_for_map_prefix!(packable_map_prefix_u32, packable_map_prefix_duplicate_u32, u32);
-impl_packable_test_for_map_prefix!(packable_map_prefix_u64, packable_map_prefix_duplicate_u64, u64);
+impl_packable_test_for_map_prefix!(
+    packable_map_prefix_u8,
+    packable_map_prefix_duplicate_u8,
+    packable_map_prefix_unordered_u8,
+    u8
+);
+impl_packable_test_for_map_prefix!(
+    packable_map_prefix_u16,
+    packable_map_prefix_duplicate_u16,
+    packable_map_prefix_unordered_u16,
+    u16
+);
+impl_packable_test_for_map_prefix!(
+    packable_map_prefix_u32,
+    packable_map_prefix_duplicate_u32,
+    packable_map_prefix_unordered_u32,
+    u32
+);
+impl_packable_test_for_map_prefix!(
+    packable_map_prefix_u64,
+    packable_map_prefix_duplicate_u64,
+    packable_map_prefix_unordered_u64,
+    u64
+);
 
 impl_packable_test_for_bounded_map_prefix!(
     packable_map_prefix_bounded_u8,
     packable_map_prefix_invalid_length_bounded_u8,
     packable_map_prefix_duplicate_bounded_u8,
+    packable_map_prefix_unordered_bounded_u8,
     u8,
     BoundedU8,
     InvalidBoundedU8,
@@ -157,6 +237,7 @@ impl_packable_test_for_bounded_map_prefix!(
     packable_map_prefix_bounded_u16,
     packable_map_prefix_invalid_length_bounded_u16,
     packable_map_prefix_duplicate_bounded_u16,
+    packable_map_prefix_unordered_bounded_u16,
     u16,
     BoundedU16,
     InvalidBoundedU16,
@@ -167,6 +248,7 @@ impl_packable_test_for_bounded_map_prefix!(
     packable_map_prefix_bounded_u32,
     packable_map_prefix_invalid_length_bounded_u32,
     packable_map_prefix_duplicate_bounded_u32,
+    packable_map_prefix_unordered_bounded_u32,
     u32,
     BoundedU32,
     InvalidBoundedU32,
@@ -177,9 +259,64 @@ impl_packable_test_for_bounded_map_prefix!(
     packable_map_prefix_bounded_u64,
     packable_map_prefix_invalid_length_bounded_u64,
     packable_map_prefix_duplicate_bounded_u64,
+    packable_map_prefix_unordered_bounded_u64,
     u64,
     BoundedU64,
     InvalidBoundedU64,
     1,
     64
 );
+
+#[test]
+fn map_prefix_bounded_mutation() {
+    let mut prefixed = HashMapPrefix::<u8, u16, BoundedU8<1, 3>>::try_from(HashMap::from_iter([(1, 10)])).unwrap();
+
+    assert_eq!(prefixed.try_insert(2, 20).unwrap(), None);
+    assert_eq!(prefixed.try_insert(3, 30).unwrap(), None);
+    // Replacing the value of an existing key does not change the length.
+    assert_eq!(prefixed.try_insert(3, 31).unwrap(), Some(30));
+    assert!(matches!(
+        prefixed.try_insert(4, 40),
+        Err(TryIntoBoundedU8Error::Invalid(4))
+    ));
+
+    assert_eq!(prefixed.remove(&1).unwrap(), Some(10));
+    assert_eq!(prefixed.remove(&1).unwrap(), None);
+    assert!(matches!(
+        prefixed.try_extend([(4, 40), (5, 50)]),
+        Err(TryIntoBoundedU8Error::Invalid(4))
+    ));
+    prefixed.try_extend([(3, 32), (4, 40)]).unwrap();
+    assert_eq!(*prefixed, HashMap::from_iter([(2, 20), (3, 32), (4, 40)]));
+
+    *prefixed.get_mut(&2).unwrap() += 1;
+    assert!(matches!(
+        prefixed.retain(|_, _| false),
+        Err(TryIntoBoundedU8Error::Invalid(0))
+    ));
+    assert_eq!(*prefixed, HashMap::from_iter([(2, 21), (3, 32), (4, 40)]));
+    prefixed
+        .retain(|key, value| {
+            *value += 1;
+            *key != 3
+        })
+        .unwrap();
+    assert_eq!(*prefixed, HashMap::from_iter([(2, 22), (4, 41)]));
+
+    assert_eq!(prefixed.remove(&2).unwrap(), Some(22));
+    assert!(matches!(prefixed.remove(&4), Err(TryIntoBoundedU8Error::Invalid(0))));
+}
+
+#[test]
+fn map_prefix_retain_panic() {
+    let mut prefixed =
+        HashMapPrefix::<u8, u16, BoundedU8<1, 3>>::try_from(HashMap::from_iter([(1, 10), (2, 20)])).unwrap();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        prefixed.retain(|key, _| key == &1 || panic!())
+    }));
+
+    assert!(result.is_err());
+    // No entry is removed if the predicate panics.
+    assert_eq!(*prefixed, HashMap::from_iter([(1, 10), (2, 20)]));
+}
@@ -0,0 +1,48 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(any(has_core_net, feature = "std"))]
+
+mod common;
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use packable::{
+    error::{UnknownTagError, UnpackError},
+    PackableExt,
+};
+
+#[test]
+fn packable_ip_addr() {
+    let v4 = Ipv4Addr::new(127, 0, 0, 1);
+    let v6 = Ipv6Addr::LOCALHOST;
+
+    assert_eq!(common::generic_test(&v4).0, [127, 0, 0, 1]);
+    assert_eq!(common::generic_test(&v6).0, v6.octets());
+    assert_eq!(common::generic_test(&IpAddr::V4(v4)).0, [0, 127, 0, 0, 1]);
+    assert_eq!(
+        common::generic_test(&IpAddr::V6(v6)).0,
+        (1u8, v6.octets()).pack_to_vec()
+    );
+}
+
+#[test]
+fn packable_socket_addr() {
+    let v4 = SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 8080);
+    let v6 = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 1, 2);
+
+    assert_eq!(common::generic_test(&v4).0, (v4.ip().octets(), 8080u16).pack_to_vec());
+    assert_eq!(
+        common::generic_test(&v6).0,
+        (v6.ip().octets(), 8080u16, 1u32, 2u32).pack_to_vec()
+    );
+    assert_eq!(common::generic_test(&SocketAddr::V4(v4)).0.len(), 7);
+    assert_eq!(common::generic_test(&SocketAddr::V6(v6)).0.len(), 27);
+}
+
+#[test]
+fn packable_socket_addr_unknown_tag() {
+    let unpacked = SocketAddr::unpack_bytes_verified([2u8, 127, 0, 0, 1, 0, 0], &());
+
+    assert!(matches!(unpacked, Err(UnpackError::Packable(UnknownTagError(2)))));
+}
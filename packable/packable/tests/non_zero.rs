@@ -0,0 +1,32 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use core::num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8};
+
+use packable::{error::UnpackError, num::UnpackNonZeroError, PackableExt};
+
+#[test]
+fn packable_non_zero() {
+    assert_eq!(common::generic_test(&NonZeroU8::new(42).unwrap()).0, 42u8.pack_to_vec());
+    assert_eq!(
+        common::generic_test(&NonZeroU16::new(42).unwrap()).0,
+        42u16.pack_to_vec()
+    );
+    assert_eq!(
+        common::generic_test(&NonZeroU32::new(42).unwrap()).0,
+        42u32.pack_to_vec()
+    );
+    assert_eq!(
+        common::generic_test(&NonZeroU64::new(42).unwrap()).0,
+        42u64.pack_to_vec()
+    );
+}
+
+#[test]
+fn packable_non_zero_rejects_zero() {
+    let unpacked = NonZeroU32::unpack_bytes_verified(0u32.pack_to_vec(), &());
+
+    assert!(matches!(unpacked, Err(UnpackError::Packable(UnpackNonZeroError))));
+}
@@ -4,7 +4,7 @@
 mod common;
 
 #[cfg(feature = "primitive-types")]
-use primitive_types::U256;
+use primitive_types::{U128, U256, U512};
 
 macro_rules! impl_packable_test_for_num {
     ($name:ident, $ty:ident, $value:expr) => {
@@ -45,5 +45,13 @@ impl_packable_test_for_num!(
         0x6F7BD423100423DB
     ])
 );
+#[cfg(feature = "primitive-types")]
+impl_packable_test_for_num!(
+    packable_u128_primitive,
+    U128,
+    U128([0x6F7BD423100423DB, 0x6F7BD423100423DB])
+);
+#[cfg(feature = "primitive-types")]
+impl_packable_test_for_num!(packable_u512, U512, U512([0x6F7BD423100423DB; 8]));
 impl_packable_test_for_num!(packable_f32, f32, core::f32::consts::PI);
 impl_packable_test_for_num!(packable_f64, f64, core::f64::consts::PI);
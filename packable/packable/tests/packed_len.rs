@@ -0,0 +1,148 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::Infallible;
+
+use packable::{
+    bounded::{Bounded, BoundedU16},
+    error::UnknownTagError,
+    num::{BigEndian, VarInt},
+    prefix::{StringPrefix, VecPrefix},
+    Packable, PackableExt,
+};
+
+#[derive(Debug)]
+pub struct HeaderError;
+
+impl From<Infallible> for HeaderError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<UnknownTagError<u8>> for HeaderError {
+    fn from(_: UnknownTagError<u8>) -> Self {
+        Self
+    }
+}
+
+#[derive(Debug, PartialEq, Packable)]
+pub struct Header {
+    version: u8,
+    timestamp: BigEndian<u64>,
+    id: [u8; 4],
+    flags: (bool, u16),
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(unpack_error = HeaderError)]
+#[packable(tag_type = u8)]
+pub enum Address {
+    #[packable(tag = 0)]
+    Ed25519([u8; 32]),
+    #[packable(tag = 1)]
+    Alias { id: [u8; 16], index: [u64; 2] },
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(unpack_error = HeaderError)]
+#[packable(tag_type = u8)]
+pub enum Payload {
+    #[packable(tag = 0)]
+    Empty,
+    #[packable(tag = 1)]
+    Tagged(u32),
+}
+
+#[test]
+fn primitives_packed_len() {
+    assert_eq!(u8::PACKED_LEN, Some(1));
+    assert_eq!(i64::PACKED_LEN, Some(8));
+    assert_eq!(f32::PACKED_LEN, Some(4));
+    assert_eq!(bool::PACKED_LEN, Some(1));
+    assert_eq!(<[u16; 5]>::PACKED_LEN, Some(10));
+    assert_eq!(<(u8, u32, [u8; 3])>::PACKED_LEN, Some(8));
+    assert_eq!(<Box<u32>>::PACKED_LEN, Some(4));
+    assert_eq!(<BoundedU16<1, 8>>::PACKED_LEN, Some(2));
+    assert_eq!(<BigEndian<u32>>::PACKED_LEN, Some(4));
+}
+
+#[cfg(feature = "usize")]
+#[test]
+fn usize_packed_len() {
+    assert_eq!(usize::PACKED_LEN, Some(8));
+    assert_eq!(isize::PACKED_LEN, Some(8));
+    assert_eq!(usize::PACKED_LEN, Some(usize::MAX.pack_to_vec().len()));
+    assert_eq!(isize::PACKED_LEN, Some(isize::MIN.pack_to_vec().len()));
+}
+
+#[test]
+fn variable_packed_len() {
+    assert_eq!(<Option<u8>>::PACKED_LEN, None);
+    assert_eq!(<VecPrefix<u8, u8>>::PACKED_LEN, None);
+    assert_eq!(<(Option<u8>, u8)>::PACKED_LEN, None);
+    assert_eq!(<[Option<u8>; 2]>::PACKED_LEN, None);
+    assert_eq!(Payload::PACKED_LEN, None);
+}
+
+#[test]
+fn max_packed_len() {
+    assert_eq!(u32::MAX_PACKED_LEN, Some(4));
+    assert_eq!(<Option<u8>>::MAX_PACKED_LEN, Some(2));
+    assert_eq!(<Result<u8, u32>>::MAX_PACKED_LEN, Some(5));
+    assert_eq!(<[Option<u8>; 2]>::MAX_PACKED_LEN, Some(4));
+    assert_eq!(<VarInt<u32>>::MAX_PACKED_LEN, Some(5));
+    assert_eq!(<VarInt<i64>>::MAX_PACKED_LEN, Some(10));
+    assert_eq!(<VecPrefix<u16, u8>>::MAX_PACKED_LEN, Some(1 + 255 * 2));
+    assert_eq!(<VecPrefix<u8, BoundedU16<0, 8>>>::MAX_PACKED_LEN, Some(2 + 8));
+    assert_eq!(<StringPrefix<u16>>::MAX_PACKED_LEN, Some(2 + 65535));
+    assert_eq!(<VecPrefix<Option<u8>, u64>>::MAX_PACKED_LEN, None);
+    assert_eq!(Header::MAX_PACKED_LEN, Some(16));
+    assert_eq!(Payload::MAX_PACKED_LEN, Some(5));
+}
+
+#[test]
+fn bounded_max_len() {
+    assert_eq!(u8::MAX_LEN, 255);
+    assert_eq!(<BoundedU16<1, 8>>::MAX_LEN, 8);
+    assert_eq!(<VarInt<u16>>::MAX_LEN, 65535);
+    assert_eq!(<BigEndian<u32>>::MAX_LEN, u32::MAX as usize);
+}
+
+#[test]
+fn derived_packed_len() {
+    assert_eq!(Header::PACKED_LEN, Some(16));
+    assert_eq!(Address::PACKED_LEN, Some(33));
+
+    let header = Header {
+        version: 1,
+        timestamp: BigEndian(1_700_000_000),
+        id: [1, 2, 3, 4],
+        flags: (true, 7),
+    };
+
+    assert_eq!(header.packed_len(), header.pack_to_vec().len());
+}
+
+#[test]
+fn pack_to_array() {
+    let header = Header {
+        version: 1,
+        timestamp: BigEndian(1_700_000_000),
+        id: [1, 2, 3, 4],
+        flags: (true, 7),
+    };
+    let array: [u8; 16] = header.pack_to_array();
+
+    assert_eq!(array.as_slice(), header.pack_to_vec());
+    assert_eq!(Header::unpack_bytes_verified(array, &()).unwrap(), header);
+
+    let address = Address::Alias {
+        id: [7; 16],
+        index: [1, 2],
+    };
+    let array = address.pack_to_array::<33>();
+
+    assert_eq!(array.as_slice(), address.pack_to_vec());
+    assert_eq!(0x0102_0304u32.pack_to_array(), [4, 3, 2, 1]);
+}
@@ -0,0 +1,205 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::Infallible;
+
+use packable::{
+    error::{UnexpectedEOF, UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::{CounterUnpacker, SliceUnpacker, Unpacker},
+    Packable, PackableExt,
+};
+
+const MARKER: u8 = 0xff;
+
+/// A value that is packed either as a legacy `u16` lower than `0xff00` or as a marker followed by a `u32`.
+#[derive(Debug, PartialEq, Eq)]
+enum Versioned {
+    Legacy(u16),
+    Current(u32),
+}
+
+impl Packable for Versioned {
+    type UnpackError = Infallible;
+    type UnpackVisitor = ();
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        match self {
+            Self::Legacy(value) => value.pack(packer),
+            Self::Current(value) => {
+                MARKER.pack(packer)?;
+                value.pack(packer)
+            }
+        }
+    }
+
+    fn unpack<U: Unpacker>(
+        unpacker: &mut U,
+        visitor: Option<&Self::UnpackVisitor>,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let is_current = match unpacker.peek_bytes(1)? {
+            Some(tag) => tag[0] == MARKER,
+            // The tag cannot be peeked at, so the legacy value is rebuilt from it instead.
+            None => {
+                let low = u8::unpack(unpacker, visitor).coerce()?;
+
+                if low != MARKER {
+                    let high = u8::unpack(unpacker, visitor).coerce()?;
+                    return Ok(Self::Legacy(u16::from_le_bytes([low, high])));
+                }
+
+                return Ok(Self::Current(u32::unpack(unpacker, visitor).coerce()?));
+            }
+        };
+
+        if is_current {
+            u8::unpack(unpacker, visitor).coerce()?;
+            Ok(Self::Current(u32::unpack(unpacker, visitor).coerce()?))
+        } else {
+            Ok(Self::Legacy(u16::unpack(unpacker, visitor).coerce()?))
+        }
+    }
+}
+
+#[test]
+fn slice_unpacker() {
+    let bytes = [1, 2, 3];
+    let mut unpacker = SliceUnpacker::new(&bytes);
+
+    assert_eq!(unpacker.peek_bytes(2).unwrap(), Some(&[1, 2][..]));
+    assert_eq!(unpacker.read_bytes(), Some(0));
+    assert!(matches!(
+        unpacker.peek_bytes(4),
+        Err(UnexpectedEOF { required: 4, had: 3 })
+    ));
+}
+
+#[test]
+fn wrapped_slice_unpacker() {
+    let bytes = [1, 2, 3];
+    let mut unpacker = CounterUnpacker::new(SliceUnpacker::new(&bytes));
+
+    assert_eq!(unpacker.peek_bytes(3).unwrap(), Some(&[1, 2, 3][..]));
+    assert_eq!(unpacker.counter(), 0);
+}
+
+#[test]
+fn custom_packable_peeks_tag() {
+    for value in [Versioned::Legacy(0x1234), Versioned::Current(0x1234_5678)] {
+        let bytes = value.pack_to_vec();
+        assert_eq!(Versioned::unpack_bytes_strict_verified(&bytes, &()).unwrap(), value);
+    }
+}
+
+#[cfg(feature = "io")]
+mod io {
+    use std::io::Read;
+
+    use packable::unpacker::{BufferedIoUnpacker, IoUnpacker, Unpacker};
+
+    use super::*;
+
+    #[test]
+    fn io_unpacker_cannot_peek() {
+        let mut bytes = Versioned::Legacy(0x1234).pack_to_vec();
+        bytes.extend(Versioned::Current(7).pack_to_vec());
+        let mut unpacker = IoUnpacker::new(&bytes[..]);
+
+        assert!(unpacker.peek_bytes(1).unwrap().is_none());
+        assert_eq!(
+            Versioned::unpack_verified(&mut unpacker, &()).unwrap(),
+            Versioned::Legacy(0x1234)
+        );
+        assert_eq!(
+            Versioned::unpack_verified(&mut unpacker, &()).unwrap(),
+            Versioned::Current(7)
+        );
+    }
+
+    #[test]
+    fn buffered_io_unpacker() {
+        let bytes = (0u8..=255).collect::<Vec<_>>();
+        let mut unpacker = BufferedIoUnpacker::with_capacity(8, &bytes[..]);
+
+        assert_eq!(unpacker.peek_bytes(6).unwrap(), Some(&[0, 1, 2, 3, 4, 5][..]));
+        assert_eq!(unpacker.read_bytes(), Some(0));
+        // Peeking past the capacity is not possible.
+        assert!(unpacker.peek_bytes(9).unwrap().is_none());
+
+        let mut buffer = [0; 3];
+        unpacker.unpack_bytes(&mut buffer).unwrap();
+        assert_eq!(buffer, [0, 1, 2]);
+
+        // Large reads bypass the buffer.
+        let mut buffer = [0; 16];
+        unpacker.unpack_bytes(&mut buffer).unwrap();
+        assert_eq!(buffer[0], 3);
+        assert_eq!(buffer[15], 18);
+
+        unpacker.skip_bytes(200).unwrap();
+        assert_eq!(unpacker.read_bytes(), Some(219));
+        assert_eq!(unpacker.peek_bytes(1).unwrap(), Some(&[219][..]));
+
+        unpacker.skip_bytes(32).unwrap();
+        assert_eq!(
+            unpacker.peek_bytes(8).unwrap_err().kind(),
+            std::io::ErrorKind::UnexpectedEof
+        );
+        assert_eq!(unpacker.peek_bytes(5).unwrap(), Some(&[251, 252, 253, 254, 255][..]));
+        unpacker.skip_bytes(5).unwrap();
+        assert!(unpacker.skip_bytes(1).is_err());
+
+        let mut reader = unpacker.into_inner();
+        assert_eq!(reader.read(&mut [0; 1]).unwrap(), 0);
+    }
+
+    /// A reader that returns a single byte on every read.
+    struct ByteReader<'a>(&'a [u8]);
+
+    impl Read for ByteReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            match (self.0.split_first(), buf.first_mut()) {
+                (Some((byte, rest)), Some(slot)) => {
+                    *slot = *byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn buffered_io_unpacker_short_reads() {
+        let mut unpacker = BufferedIoUnpacker::with_capacity(4, ByteReader(&[1, 2, 3, 4, 5]));
+
+        assert_eq!(unpacker.peek_bytes(4).unwrap(), Some(&[1, 2, 3, 4][..]));
+        assert_eq!(unpacker.buffer(), [1, 2, 3, 4]);
+
+        let mut buffer = [0; 2];
+        unpacker.unpack_bytes(&mut buffer).unwrap();
+        assert_eq!(buffer, [1, 2]);
+        assert_eq!(
+            unpacker.peek_bytes(4).unwrap_err().kind(),
+            std::io::ErrorKind::UnexpectedEof
+        );
+        assert_eq!(unpacker.buffer(), [3, 4, 5]);
+    }
+
+    #[test]
+    fn buffered_io_unpacker_custom_packable() {
+        let mut bytes = Versioned::Current(0x1234_5678).pack_to_vec();
+        bytes.extend(Versioned::Legacy(7).pack_to_vec());
+        let mut unpacker = BufferedIoUnpacker::new(&bytes[..]);
+
+        assert_eq!(
+            Versioned::unpack_verified(&mut unpacker, &()).unwrap(),
+            Versioned::Current(0x1234_5678)
+        );
+        assert_eq!(
+            Versioned::unpack_verified(&mut unpacker, &()).unwrap(),
+            Versioned::Legacy(7)
+        );
+        assert_eq!(unpacker.read_bytes(), Some(bytes.len()));
+    }
+}
@@ -0,0 +1,18 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use core::ops::Range;
+
+use packable::{Packable, PackableExt};
+
+#[test]
+fn packable_range() {
+    assert_eq!(common::generic_test(&(3u32..7)).0, (3u32, 7u32).pack_to_vec());
+    assert_eq!(
+        common::generic_test(&Range { start: 7u32, end: 3 }).0,
+        (7u32, 3u32).pack_to_vec()
+    );
+    assert_eq!(Range::<u32>::PACKED_LEN, Some(8));
+}
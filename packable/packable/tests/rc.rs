@@ -0,0 +1,21 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use std::{rc::Rc, sync::Arc};
+
+use packable::PackableExt;
+
+#[test]
+fn packable_rc() {
+    assert_eq!(common::generic_test(&Rc::new(42u64)).0, 42u64.pack_to_vec());
+}
+
+#[test]
+fn packable_arc() {
+    assert_eq!(
+        common::generic_test(&Arc::new(Some(42u64))).0,
+        Some(42u64).pack_to_vec()
+    );
+}
@@ -0,0 +1,51 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::{
+    prefix::VecPrefix,
+    unpacker::{SliceUnpacker, Unpacker},
+    Packable, PackableExt,
+};
+
+fn value() -> (u16, VecPrefix<u32, u8>) {
+    (0x1234, VecPrefix::try_from(vec![1, 2, 3]).unwrap())
+}
+
+#[test]
+fn slice_unpacker() {
+    let mut bytes = value().pack_to_vec();
+    bytes.extend([0xff, 0xee]);
+
+    let mut unpacker = SliceUnpacker::new(&bytes);
+    assert_eq!(unpacker.read_bytes(), Some(0));
+
+    <(u16, VecPrefix<u32, u8>)>::unpack_verified(&mut unpacker, &()).unwrap();
+    assert_eq!(unpacker.read_bytes(), Some(value().packed_len()));
+    assert_eq!(unpacker.remaining_slice(), [0xff, 0xee]);
+
+    unpacker.skip_bytes(1).unwrap();
+    assert_eq!(unpacker.read_bytes(), Some(value().packed_len() + 1));
+    assert_eq!(unpacker.remaining_slice(), [0xee]);
+}
+
+#[cfg(feature = "io")]
+#[test]
+fn io_unpackers() {
+    use packable::unpacker::{BufferedIoUnpacker, IoUnpacker};
+
+    let bytes = value().pack_to_vec();
+
+    let mut unpacker = IoUnpacker::new(&bytes[..]);
+    u16::unpack_verified(&mut unpacker, &()).unwrap();
+    assert_eq!(unpacker.read_bytes(), Some(2));
+    unpacker.skip_bytes(1).unwrap();
+    assert_eq!(unpacker.read_bytes(), Some(3));
+
+    let mut unpacker = BufferedIoUnpacker::new(&bytes[..]);
+    u16::unpack_verified(&mut unpacker, &()).unwrap();
+    assert_eq!(unpacker.read_bytes(), Some(2));
+    unpacker.peek_bytes(1).unwrap();
+    assert_eq!(unpacker.read_bytes(), Some(2));
+    unpacker.skip_bytes(1).unwrap();
+    assert_eq!(unpacker.read_bytes(), Some(3));
+}
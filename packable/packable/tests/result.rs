@@ -0,0 +1,40 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use core::num::NonZeroU8;
+
+use packable::{error::UnpackError, result::UnpackResultError, PackableExt};
+
+#[test]
+fn packable_result() {
+    assert_eq!(
+        common::generic_test(&Result::<u32, u16>::Ok(42)).0,
+        (0u8, 42u32).pack_to_vec()
+    );
+    assert_eq!(
+        common::generic_test(&Result::<u32, u16>::Err(42)).0,
+        (1u8, 42u16).pack_to_vec()
+    );
+}
+
+#[test]
+fn packable_result_unknown_tag() {
+    let unpacked = Result::<u32, u16>::unpack_bytes_verified([2u8, 0, 0, 0, 0], &());
+
+    assert!(matches!(
+        unpacked,
+        Err(UnpackError::Packable(UnpackResultError::UnknownTag(2)))
+    ));
+}
+
+#[test]
+fn packable_result_err_variant_error() {
+    let unpacked = Result::<u32, NonZeroU8>::unpack_bytes_verified([1u8, 0], &());
+
+    assert!(matches!(
+        unpacked,
+        Err(UnpackError::Packable(UnpackResultError::Err(_)))
+    ));
+}
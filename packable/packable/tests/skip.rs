@@ -0,0 +1,168 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{convert::Infallible, fmt::Debug};
+
+use packable::{
+    bounded::BoundedU8,
+    error::{UnknownTagError, UnpackError},
+    prefix::{StringPrefix, UnpackPrefixError, VecPrefix},
+    unpacker::{CounterUnpacker, IoUnpacker, SliceUnpacker, Unpacker},
+    Packable, PackableExt,
+};
+
+#[derive(Debug)]
+pub enum RecordError {
+    Invalid(u8),
+    UnknownKind(u8),
+    Prefix,
+}
+
+impl From<Infallible> for RecordError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<UnknownTagError<u8>> for RecordError {
+    fn from(err: UnknownTagError<u8>) -> Self {
+        Self::UnknownKind(err.0)
+    }
+}
+
+impl<T, E> From<UnpackPrefixError<T, E>> for RecordError {
+    fn from(_: UnpackPrefixError<T, E>) -> Self {
+        Self::Prefix
+    }
+}
+
+fn verify_flag(flag: &u8) -> Result<(), RecordError> {
+    if *flag <= 1 {
+        Ok(())
+    } else {
+        Err(RecordError::Invalid(*flag))
+    }
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(unpack_error = RecordError)]
+#[packable(tag_type = u8)]
+pub enum Kind {
+    #[packable(tag = 0)]
+    Empty,
+    #[packable(tag = 1)]
+    Data(VecPrefix<u32, BoundedU8<0, 4>>),
+}
+
+#[derive(Debug, PartialEq, Packable)]
+#[packable(unpack_error = RecordError)]
+pub struct Record {
+    name: StringPrefix<u8>,
+    #[packable(verify_with = verify_flag)]
+    flag: u8,
+    kind: Kind,
+    payload: VecPrefix<u8, u16>,
+}
+
+fn record() -> Record {
+    Record {
+        name: StringPrefix::try_from(String::from("record")).unwrap(),
+        flag: 1,
+        kind: Kind::Data(VecPrefix::try_from(vec![1, 2, 3]).unwrap()),
+        payload: VecPrefix::try_from(vec![7; 1000]).unwrap(),
+    }
+}
+
+fn skipped_len<P>(bytes: &[u8]) -> usize
+where
+    P: Packable<UnpackVisitor = ()>,
+    P::UnpackError: Debug,
+{
+    let mut unpacker = CounterUnpacker::new(SliceUnpacker::new(bytes));
+    P::skip(&mut unpacker, Some(&())).unwrap();
+    unpacker.counter()
+}
+
+#[test]
+fn skip_bytes() {
+    let bytes = [1, 2, 3, 4, 5];
+
+    let mut unpacker = SliceUnpacker::new(&bytes);
+    unpacker.skip_bytes(3).unwrap();
+    assert_eq!(u16::unpack_verified(&mut unpacker, &()).unwrap(), 0x0504);
+    assert!(unpacker.skip_bytes(1).is_err());
+
+    let mut unpacker = IoUnpacker::new(bytes.as_slice());
+    unpacker.skip_bytes(3).unwrap();
+    assert_eq!(u16::unpack_verified(&mut unpacker, &()).unwrap(), 0x0504);
+    assert!(unpacker.skip_bytes(1).is_err());
+
+    let mut unpacker = CounterUnpacker::new(SliceUnpacker::new(&bytes));
+    unpacker.skip_bytes(4).unwrap();
+    assert_eq!(unpacker.counter(), 4);
+}
+
+#[test]
+fn skip_matches_packed_len() {
+    assert_eq!(skipped_len::<u64>(&42u64.pack_to_vec()), 8);
+    assert_eq!(skipped_len::<[u16; 3]>(&[1u16, 2, 3].pack_to_vec()), 6);
+    assert_eq!(skipped_len::<Option<u32>>(&Some(1u32).pack_to_vec()), 5);
+    assert_eq!(skipped_len::<(u8, u32)>(&(1u8, 2u32).pack_to_vec()), 5);
+
+    let record = record();
+    assert_eq!(skipped_len::<Record>(&record.pack_to_vec()), record.packed_len());
+}
+
+#[test]
+fn skip_then_unpack() {
+    let bytes = (record(), 42u32).pack_to_vec();
+    let mut unpacker = SliceUnpacker::new(&bytes);
+
+    Record::skip(&mut unpacker, Some(&())).unwrap();
+
+    assert_eq!(u32::unpack_verified(&mut unpacker, &()).unwrap(), 42);
+    assert!(unpacker.is_empty());
+}
+
+#[test]
+fn skip_validates_prefix() {
+    let mut bytes = Kind::Data(VecPrefix::try_from(vec![1, 2, 3]).unwrap()).pack_to_vec();
+    bytes[1] = 5;
+
+    assert!(matches!(
+        Kind::skip(&mut SliceUnpacker::new(&bytes), Some(&())),
+        Err(UnpackError::Packable(RecordError::Prefix))
+    ));
+}
+
+#[test]
+fn skip_verifies_fields() {
+    let mut bytes = record().pack_to_vec();
+    // The flag is right after the name.
+    bytes[7] = 2;
+
+    assert!(matches!(
+        Record::skip(&mut SliceUnpacker::new(&bytes), Some(&())),
+        Err(UnpackError::Packable(RecordError::Invalid(2)))
+    ));
+    // Without a visitor the field is not verified.
+    assert!(Record::skip(&mut SliceUnpacker::new(&bytes), None).is_ok());
+}
+
+#[test]
+fn skip_unknown_tag() {
+    assert!(matches!(
+        Kind::skip(&mut SliceUnpacker::new(&[3]), Some(&())),
+        Err(UnpackError::Packable(RecordError::UnknownKind(3)))
+    ));
+}
+
+#[test]
+fn skip_unexpected_eof() {
+    let bytes = record().pack_to_vec();
+
+    assert!(matches!(
+        Record::skip(&mut SliceUnpacker::new(&bytes[..bytes.len() - 1]), Some(&())),
+        Err(UnpackError::Unpacker(_))
+    ));
+}
@@ -0,0 +1,91 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use packable::{unpacker::SliceUnpacker, Packable, PackableExt};
+
+#[derive(Debug, Clone, PartialEq, Eq, Packable)]
+pub struct Cached {
+    value: u32,
+    #[packable(skip)]
+    hits: u64,
+    #[packable(skip, default = Some(1))]
+    version: Option<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Packable)]
+#[packable(post_unpack = Block::compute_id)]
+pub struct Block {
+    parents: [u8; 2],
+    payload: u16,
+    #[packable(skip)]
+    id: u32,
+}
+
+impl Block {
+    fn new(parents: [u8; 2], payload: u16) -> Self {
+        let mut block = Self {
+            parents,
+            payload,
+            id: 0,
+        };
+        block.compute_id();
+        block
+    }
+
+    fn compute_id(&mut self) {
+        self.id = u32::from(self.parents[0]) << 24 | u32::from(self.parents[1]) << 16 | u32::from(self.payload);
+    }
+}
+
+#[test]
+fn packable_skipped_field_default() {
+    let value = Cached {
+        value: 42,
+        hits: 0,
+        version: Some(1),
+    };
+
+    assert_eq!(common::generic_test(&value).0, [42, 0, 0, 0]);
+    assert_eq!(Cached::PACKED_LEN, Some(4));
+}
+
+#[test]
+fn packable_skipped_field_is_reset() {
+    let value = Cached {
+        value: 42,
+        hits: 7,
+        version: None,
+    };
+
+    let unpacked = Cached::unpack_bytes_verified(value.pack_to_vec(), &()).unwrap();
+
+    assert_eq!(
+        unpacked,
+        Cached {
+            value: 42,
+            hits: 0,
+            version: Some(1),
+        }
+    );
+}
+
+#[test]
+fn packable_skipped_field_post_unpack() {
+    let block = Block::new([1, 2], 3);
+
+    assert_eq!(block.id, 0x0102_0003);
+    assert_eq!(common::generic_test(&block).0, [1, 2, 3, 0]);
+    assert_eq!(Block::unpack_bytes_verified([1, 2, 3, 0], &()).unwrap().id, 0x0102_0003);
+}
+
+#[test]
+fn packable_skipped_field_skip() {
+    let bytes = [42, 0, 0, 0, 7];
+    let mut unpacker = SliceUnpacker::new(&bytes);
+
+    Cached::skip(&mut unpacker, Some(&())).unwrap();
+
+    assert_eq!(u8::unpack_verified(&mut unpacker, &()).unwrap(), 7);
+}
@@ -0,0 +1,123 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use packable::{
+    bounded::{BoundedU8, TryIntoBoundedU8Error},
+    error::UnpackError,
+    prefix::{SortKey, SortedVecPrefix, TryIntoSortedPrefixError, UnpackSortedPrefixError, VecPrefix},
+    Packable, PackableExt,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Packable)]
+struct Output {
+    id: u16,
+    amount: u64,
+}
+
+struct ById;
+
+impl SortKey<Output> for ById {
+    type Key = u16;
+
+    fn key(item: &Output) -> &Self::Key {
+        &item.id
+    }
+}
+
+fn output(id: u16, amount: u64) -> Output {
+    Output { id, amount }
+}
+
+#[test]
+fn sorted_vec_prefix() {
+    let prefixed = SortedVecPrefix::<u32, u8>::try_from(vec![1, 2, 5]).unwrap();
+
+    assert_eq!(
+        common::generic_test(&prefixed).0.len(),
+        core::mem::size_of::<u8>() + 3 * core::mem::size_of::<u32>()
+    );
+}
+
+#[test]
+fn sorted_vec_prefix_same_bytes_as_vec_prefix() {
+    let items = vec![output(1, 30), output(4, 10), output(9, 20)];
+    let sorted = SortedVecPrefix::<Output, u16, ById>::try_from(items.clone()).unwrap();
+    let prefixed = VecPrefix::<Output, u16>::try_from(items).unwrap();
+
+    assert_eq!(sorted.pack_to_vec(), prefixed.pack_to_vec());
+    assert_eq!(
+        SortedVecPrefix::<Output, u16, ById>::unpack_bytes_verified(prefixed.pack_to_vec(), &()).unwrap(),
+        sorted
+    );
+}
+
+#[test]
+fn sorted_vec_prefix_from_vec_errors() {
+    assert_eq!(
+        SortedVecPrefix::<u8, u8>::try_from(vec![1, 3, 2, 1]),
+        Err(TryIntoSortedPrefixError::Unsorted(2))
+    );
+    assert_eq!(
+        SortedVecPrefix::<u8, u8>::try_from(vec![1, 3, 3]),
+        Err(TryIntoSortedPrefixError::Unsorted(2))
+    );
+    assert_eq!(
+        SortedVecPrefix::<u8, BoundedU8<1, 2>>::try_from(vec![1, 2, 3]),
+        Err(TryIntoSortedPrefixError::Invalid(TryIntoBoundedU8Error::Invalid(3)))
+    );
+    // Items are sorted by their key only.
+    assert!(matches!(
+        SortedVecPrefix::<Output, u8, ById>::try_from(vec![output(1, 20), output(2, 10), output(2, 30)]),
+        Err(TryIntoSortedPrefixError::Unsorted(2))
+    ));
+}
+
+#[test]
+fn sorted_vec_prefix_unpack_unsorted_error() {
+    let bytes = VecPrefix::<u16, u8>::try_from(vec![1, 2, 4, 3]).unwrap().pack_to_vec();
+    let unpacked = SortedVecPrefix::<u16, u8>::unpack_bytes_verified(bytes, &());
+
+    assert!(matches!(
+        unpacked,
+        Err(UnpackError::Packable(UnpackSortedPrefixError::Unsorted(3)))
+    ));
+}
+
+#[test]
+fn sorted_vec_prefix_unpack_prefix_error() {
+    let bytes = VecPrefix::<u8, u8>::try_from(vec![1, 2, 3]).unwrap().pack_to_vec();
+    let unpacked = SortedVecPrefix::<u8, BoundedU8<0, 2>>::unpack_bytes_verified(bytes, &());
+
+    assert!(matches!(
+        unpacked,
+        Err(UnpackError::Packable(UnpackSortedPrefixError::Prefix(_)))
+    ));
+}
+
+#[test]
+fn sorted_vec_prefix_insert_and_lookup() {
+    let mut sorted = SortedVecPrefix::<Output, BoundedU8<0, 3>, ById>::default();
+
+    assert_eq!(sorted.try_insert(output(5, 50)).unwrap(), None);
+    assert_eq!(sorted.try_insert(output(1, 10)).unwrap(), None);
+    assert_eq!(sorted.try_insert(output(3, 30)).unwrap(), None);
+    // Replacing an item with the same key does not change the length.
+    assert_eq!(sorted.try_insert(output(3, 31)).unwrap(), Some(output(3, 30)));
+    assert!(matches!(
+        sorted.try_insert(output(4, 40)),
+        Err(TryIntoBoundedU8Error::Invalid(4))
+    ));
+
+    assert_eq!(*sorted, [output(1, 10), output(3, 31), output(5, 50)]);
+    assert_eq!(sorted.binary_search_key(&3), Ok(1));
+    assert_eq!(sorted.binary_search_key(&4), Err(2));
+    assert_eq!(sorted.find(&5), Some(&output(5, 50)));
+    assert_eq!(sorted.find(&2), None);
+    assert!(sorted.contains_key(&1));
+
+    assert_eq!(sorted.remove_key(&1).unwrap(), Some(output(1, 10)));
+    assert_eq!(sorted.remove_key(&1).unwrap(), None);
+    assert!(!sorted.contains_key(&1));
+}
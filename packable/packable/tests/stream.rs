@@ -0,0 +1,141 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::{
+    bounded::BoundedU8,
+    error::UnexpectedEOF,
+    prefix::{UnpackPrefixError, VecPrefix},
+    stream::{PackIter, UnpackIter, UnpackIterError},
+    unpacker::{CounterUnpacker, LimitedUnpacker, SliceUnpacker},
+    Packable,
+};
+
+#[derive(Debug, PartialEq, Eq, Packable)]
+struct Record {
+    id: u32,
+    payload: VecPrefix<u8, u8>,
+}
+
+fn records() -> Vec<Record> {
+    (0..3)
+        .map(|id| Record {
+            id,
+            payload: VecPrefix::try_from(vec![id as u8; id as usize]).unwrap(),
+        })
+        .collect()
+}
+
+fn stream() -> Vec<u8> {
+    let mut writer = PackIter::new(Vec::new());
+    writer.extend(&records()).unwrap();
+    assert_eq!(writer.count(), 3);
+
+    writer.into_inner()
+}
+
+#[test]
+fn round_trip() {
+    let bytes = stream();
+    let unpacked = UnpackIter::<Record, _>::new_verified(SliceUnpacker::new(&bytes), ())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(unpacked, records());
+}
+
+#[test]
+fn empty_stream() {
+    let mut iter = UnpackIter::<Record, _>::new_unverified(SliceUnpacker::new(&[]));
+
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn push_appends_records() {
+    let mut writer = PackIter::new(Vec::new());
+    writer.push(&1u16).unwrap();
+    writer.push(&2u16).unwrap();
+
+    assert_eq!(writer.count(), 2);
+    assert_eq!(writer.into_inner(), [1, 0, 2, 0]);
+}
+
+#[test]
+fn truncated_record() {
+    let bytes = stream();
+    let mut iter = UnpackIter::<Record, _>::new_verified(SliceUnpacker::new(&bytes[..bytes.len() - 1]), ());
+
+    assert!(matches!(iter.next(), Some(Ok(record)) if record.id == 0));
+    assert!(matches!(iter.next(), Some(Ok(record)) if record.id == 1));
+    assert!(matches!(
+        iter.next(),
+        Some(Err(UnpackIterError::Truncated(UnexpectedEOF { required: 2, had: 1 })))
+    ));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn packable_error_stops_iteration() {
+    let mut iter = UnpackIter::<Option<u8>, _>::new_verified(SliceUnpacker::new(&[1, 7, 2, 1, 3]), ());
+
+    assert!(matches!(iter.next(), Some(Ok(Some(7)))));
+    assert!(matches!(iter.next(), Some(Err(UnpackIterError::Packable(_)))));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn wrapped_unpackers() {
+    let bytes = stream();
+
+    let mut counter = CounterUnpacker::new(SliceUnpacker::new(&bytes));
+    assert_eq!(UnpackIter::<Record, _>::new_verified(&mut counter, ()).count(), 3);
+    assert_eq!(counter.counter(), bytes.len());
+
+    let mut iter = UnpackIter::<Record, _>::new_verified(
+        LimitedUnpacker::new(SliceUnpacker::new(&bytes[..bytes.len() - 1]), 1024, 8),
+        (),
+    );
+    assert_eq!(iter.by_ref().filter(Result::is_ok).count(), 2);
+    assert!(iter.next().is_none());
+
+    let mut iter = UnpackIter::<Record, _>::new_verified(LimitedUnpacker::new(SliceUnpacker::new(&bytes), 1, 8), ());
+    assert!(matches!(iter.next(), Some(Ok(_))));
+    assert!(matches!(iter.next(), Some(Ok(_))));
+    assert!(matches!(iter.next(), Some(Err(UnpackIterError::Unpacker(_)))));
+}
+
+#[test]
+fn prefix_error_is_not_truncation() {
+    let mut iter = UnpackIter::<VecPrefix<u8, BoundedU8<0, 4>>, _>::new_verified(SliceUnpacker::new(&[9, 0]), ());
+
+    assert!(matches!(
+        iter.next(),
+        Some(Err(UnpackIterError::Packable(UnpackPrefixError::Prefix(_))))
+    ));
+}
+
+#[cfg(feature = "io")]
+#[test]
+fn io_unpacker() {
+    use std::io::BufReader;
+
+    use packable::unpacker::IoUnpacker;
+
+    let bytes = stream();
+
+    let unpacked = UnpackIter::<Record, _>::new_verified(IoUnpacker::new(BufReader::new(&bytes[..])), ())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(unpacked, records());
+
+    let mut iter =
+        UnpackIter::<Record, _>::new_verified(IoUnpacker::new(BufReader::new(&bytes[..bytes.len() - 1])), ());
+    assert_eq!(iter.by_ref().filter(Result::is_ok).count(), 2);
+
+    let mut iter =
+        UnpackIter::<Record, _>::new_verified(IoUnpacker::new(BufReader::new(&bytes[..bytes.len() - 1])), ());
+    iter.next();
+    iter.next();
+    assert!(matches!(iter.next(), Some(Err(UnpackIterError::Truncated(_)))));
+}
@@ -3,6 +3,8 @@
 
 mod common;
 
+use packable::PackableExt;
+
 #[test]
 fn packable_string() {
     assert_eq!(
@@ -10,3 +12,11 @@ fn packable_string() {
         core::mem::size_of::<u64>() + 16 * core::mem::size_of::<u8>()
     );
 }
+
+#[test]
+fn packable_boxed_str() {
+    assert_eq!(
+        common::generic_test(&Box::<str>::from("yellow submarine")).0,
+        "yellow submarine".to_owned().pack_to_vec()
+    );
+}
@@ -6,7 +6,7 @@ mod common;
 use packable::{
     bounded::{
         BoundedU16, BoundedU32, BoundedU64, BoundedU8, InvalidBoundedU16, InvalidBoundedU32, InvalidBoundedU64,
-        InvalidBoundedU8, TryIntoBoundedU32Error,
+        InvalidBoundedU8, TryIntoBoundedU32Error, TryIntoBoundedU8Error,
     },
     error::UnpackError,
     prefix::{StringPrefix, UnpackPrefixError},
@@ -133,3 +133,27 @@ impl_packable_test_for_bounded_string_prefix!(
     1,
     64
 );
+
+#[test]
+fn packable_string_prefix_bounded_mutation() {
+    let mut prefixed = StringPrefix::<BoundedU8<1, 4>>::try_from(String::from("a")).unwrap();
+
+    prefixed.try_push('b').unwrap();
+    assert!(matches!(
+        prefixed.try_push_str("cde"),
+        Err(TryIntoBoundedU8Error::Invalid(5))
+    ));
+    // This character measures 2 bytes.
+    prefixed.try_push('é').unwrap();
+    assert_eq!(prefixed.as_str(), "abé");
+    assert!(matches!(prefixed.try_push('f'), Err(TryIntoBoundedU8Error::Invalid(5))));
+
+    assert_eq!(prefixed.pop().unwrap(), Some('é'));
+    prefixed.try_push_str("cd").unwrap();
+    assert_eq!(prefixed.as_str(), "abcd");
+
+    prefixed.truncate(1).unwrap();
+    assert!(matches!(prefixed.pop(), Err(TryIntoBoundedU8Error::Invalid(0))));
+    assert!(matches!(prefixed.truncate(0), Err(TryIntoBoundedU8Error::Invalid(0))));
+    assert_eq!(prefixed.as_str(), "a");
+}
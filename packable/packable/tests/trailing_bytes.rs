@@ -0,0 +1,83 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::{
+    error::{UnpackBytesError, UnpackError},
+    prefix::VecPrefix,
+    unpacker::SliceUnpacker,
+    Packable, PackableExt,
+};
+
+#[test]
+fn strict_exact() {
+    let value = VecPrefix::<u16, u8>::try_from(vec![1, 2, 3]).unwrap();
+    let bytes = value.pack_to_vec();
+
+    assert_eq!(
+        VecPrefix::<u16, u8>::unpack_bytes_strict_verified(&bytes, &()).unwrap(),
+        value
+    );
+    assert_eq!(
+        VecPrefix::<u16, u8>::unpack_bytes_strict_unverified(&bytes).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn strict_trailing_bytes() {
+    let mut bytes = 42u32.pack_to_vec();
+    bytes.extend_from_slice(&[0, 0]);
+
+    // The lenient methods ignore the trailing bytes.
+    assert_eq!(u32::unpack_bytes_verified(&bytes, &()).unwrap(), 42);
+
+    assert!(matches!(
+        u32::unpack_bytes_strict_verified(&bytes, &()),
+        Err(UnpackError::Unpacker(UnpackBytesError::TrailingBytes { remaining: 2 }))
+    ));
+    assert!(matches!(
+        u32::unpack_bytes_strict_unverified(&bytes),
+        Err(UnpackError::Unpacker(UnpackBytesError::TrailingBytes { remaining: 2 }))
+    ));
+}
+
+#[test]
+fn strict_unexpected_eof() {
+    assert!(matches!(
+        u32::unpack_bytes_strict_verified([0, 0], &()),
+        Err(UnpackError::Unpacker(UnpackBytesError::UnexpectedEOF(_)))
+    ));
+}
+
+#[test]
+fn partial() {
+    let bytes = [42, 0, 1, 2, 3];
+    let (value, tail) = u16::unpack_bytes_partial(&bytes, &()).unwrap();
+
+    assert_eq!(value, 42);
+    assert_eq!(tail, [1, 2, 3]);
+
+    let (value, tail) = u8::unpack_bytes_partial(tail, &()).unwrap();
+
+    assert_eq!(value, 1);
+    assert_eq!(tail, [2, 3]);
+}
+
+#[test]
+fn slice_unpacker_remaining() {
+    let bytes = [1, 2, 3];
+    let mut unpacker = SliceUnpacker::new(&bytes);
+
+    assert_eq!(unpacker.remaining(), 3);
+    assert!(!unpacker.is_empty());
+
+    u16::unpack_verified(&mut unpacker, &()).unwrap();
+
+    assert_eq!(unpacker.remaining(), 1);
+
+    u8::unpack_verified(&mut unpacker, &()).unwrap();
+
+    assert_eq!(unpacker.remaining(), 0);
+    assert!(unpacker.is_empty());
+    assert!(unpacker.into_remaining().is_empty());
+}
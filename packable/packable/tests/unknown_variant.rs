@@ -0,0 +1,185 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use packable::{
+    error::{DelimitedLenError, UnexpectedEOF, UnpackError},
+    prefix::{BoxedSlicePrefix, VecPrefix},
+    unpacker::{SliceUnpacker, Unpacker},
+    KnownTags, Packable, PackableExt,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Packable)]
+#[packable(tag_type = u8)]
+pub enum Payload {
+    #[packable(tag = 0)]
+    Empty,
+    #[packable(tag = 1)]
+    Value(u32),
+    #[packable(tag = 2)]
+    Pair { first: u16, second: u8 },
+    #[packable(unknown)]
+    Unknown { tag: u8, payload: VecPrefix<u8, u32> },
+}
+
+// A newer version of `Payload` with an additional variant.
+#[derive(Debug, Clone, PartialEq, Eq, Packable)]
+#[packable(tag_type = u8)]
+pub enum PayloadV2 {
+    #[packable(tag = 0)]
+    Empty,
+    #[packable(tag = 1)]
+    Value(u32),
+    #[packable(tag = 2)]
+    Pair { first: u16, second: u8 },
+    #[packable(tag = 3)]
+    Values(u64, u64),
+    #[packable(unknown)]
+    Unknown(u8, BoxedSlicePrefix<u8, u32>),
+}
+
+#[test]
+fn packable_unknown_variant_known_variants() {
+    assert_eq!(common::generic_test(&Payload::Empty).0, [0, 0, 0, 0, 0]);
+    assert_eq!(
+        common::generic_test(&Payload::Value(42)).0,
+        [1, 4, 0, 0, 0, 42, 0, 0, 0]
+    );
+    assert_eq!(
+        common::generic_test(&Payload::Pair { first: 1, second: 2 }).0,
+        [2, 3, 0, 0, 0, 1, 0, 2]
+    );
+    assert_eq!(Payload::PACKED_LEN, None);
+    assert_eq!(Payload::MAX_PACKED_LEN, Some(1 + 4 + u32::MAX as usize));
+}
+
+#[test]
+fn packable_unknown_variant_round_trip() {
+    let value = Payload::Unknown {
+        tag: 7,
+        payload: vec![1, 2, 3].try_into().unwrap(),
+    };
+
+    assert_eq!(common::generic_test(&value).0, [7, 3, 0, 0, 0, 1, 2, 3]);
+}
+
+#[test]
+fn packable_unknown_variant_forward_compatibility() {
+    let newer = PayloadV2::Values(1, 2);
+    let bytes = newer.pack_to_vec();
+
+    let older = Payload::unpack_bytes_verified(&bytes, &()).unwrap();
+
+    assert!(matches!(&older, Payload::Unknown { tag: 3, payload } if payload.len() == 16));
+    // The unknown variant is packed byte-identically, so it can be relayed.
+    assert_eq!(older.pack_to_vec(), bytes);
+    assert_eq!(
+        PayloadV2::unpack_bytes_verified(older.pack_to_vec(), &()).unwrap(),
+        newer
+    );
+}
+
+#[test]
+fn packable_unknown_variant_known_tags() {
+    assert_eq!(Payload::KNOWN_TAGS, [0, 1, 2]);
+    assert_eq!(PayloadV2::KNOWN_TAGS, [0, 1, 2, 3]);
+    assert!(Payload::is_known_tag(&2));
+    assert!(!Payload::is_known_tag(&3));
+}
+
+#[test]
+fn packable_unknown_variant_known_tag_is_not_unknown() {
+    // The payload of a known tag is always unpacked as its known variant, even if it is invalid for it.
+    let bytes = [1, 3, 0, 0, 0, 1, 2, 3];
+
+    assert!(matches!(
+        Payload::unpack_bytes_verified(bytes, &()),
+        Err(UnpackError::Packable(DelimitedLenError {
+            expected: 3,
+            consumed: 4
+        }))
+    ));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "the tag of an `unknown` variant cannot be the tag of a known variant")]
+fn packable_unknown_variant_known_tag_pack() {
+    Payload::Unknown {
+        tag: 1,
+        payload: vec![42, 0, 0, 0].try_into().unwrap(),
+    }
+    .pack_to_vec();
+}
+
+#[test]
+fn packable_unknown_variant_skip() {
+    let mut bytes = PayloadV2::Values(1, 2).pack_to_vec();
+    bytes.extend(Payload::Value(42).pack_to_vec());
+
+    let mut unpacker = SliceUnpacker::new(&bytes);
+    Payload::skip(&mut unpacker, Some(&())).unwrap();
+
+    assert_eq!(
+        Payload::unpack_verified(&mut unpacker, &()).unwrap(),
+        Payload::Value(42)
+    );
+    assert!(unpacker.is_empty());
+}
+
+#[test]
+fn packable_unknown_variant_invalid_payload_len() {
+    // The payload of `Value` is 4 bytes long but the prefix claims 5.
+    let unpacked = Payload::unpack_bytes_verified([1, 5, 0, 0, 0, 42, 0, 0, 0, 0], &());
+
+    assert!(matches!(
+        unpacked,
+        Err(UnpackError::Packable(DelimitedLenError {
+            expected: 5,
+            consumed: 4
+        }))
+    ));
+}
+
+#[test]
+fn packable_unknown_variant_payload_len_too_short() {
+    // The payload of `Value` is 4 bytes long but the prefix claims 2, so the next value starts at byte 7.
+    let bytes = [1, 2, 0, 0, 0, 42, 0, 0, 0];
+    let mut unpacker = SliceUnpacker::new(&bytes);
+
+    assert!(matches!(
+        Payload::unpack_verified(&mut unpacker, &()),
+        Err(UnpackError::Packable(DelimitedLenError {
+            expected: 2,
+            consumed: 4
+        }))
+    ));
+    // The bytes past the payload have not been consumed.
+    assert_eq!(unpacker.remaining_slice(), [42, 0, 0, 0]);
+}
+
+/// An unpacker that does not report the number of bytes it has read.
+struct OpaqueUnpacker<'a>(SliceUnpacker<'a>);
+
+impl Unpacker for OpaqueUnpacker<'_> {
+    type Error = UnexpectedEOF;
+
+    fn unpack_bytes<B: AsMut<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        self.0.unpack_bytes(bytes)
+    }
+}
+
+#[test]
+fn packable_unknown_variant_invalid_payload_len_opaque_unpacker() {
+    let mut unpacker = OpaqueUnpacker(SliceUnpacker::new(&[1, 5, 0, 0, 0, 42, 0, 0, 0, 0]));
+
+    assert!(unpacker.read_bytes().is_none());
+    assert!(matches!(
+        Payload::unpack_verified(&mut unpacker, &()),
+        Err(UnpackError::Packable(DelimitedLenError {
+            expected: 5,
+            consumed: 4
+        }))
+    ));
+}
@@ -0,0 +1,100 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use packable::{
+    error::UnpackError,
+    num::{UnpackVarIntError, VarInt},
+    prefix::{StringPrefix, VecPrefix},
+    PackableExt,
+};
+
+macro_rules! impl_packable_test_for_varint {
+    ($name:ident, $ty:ident, $value:expr, $len:expr) => {
+        #[test]
+        fn $name() {
+            let value: $ty = $value;
+            let bytes = common::generic_test(&VarInt(value));
+
+            assert_eq!(bytes.0.len(), $len);
+        }
+    };
+}
+
+impl_packable_test_for_varint!(packable_varint_u8_zero, u8, 0, 1);
+impl_packable_test_for_varint!(packable_varint_u8_max, u8, u8::MAX, 2);
+impl_packable_test_for_varint!(packable_varint_u16, u16, 0x6F7B, 3);
+impl_packable_test_for_varint!(packable_varint_u32, u32, u32::MAX, 5);
+impl_packable_test_for_varint!(packable_varint_u64, u64, u64::MAX, 10);
+impl_packable_test_for_varint!(packable_varint_u128, u128, u128::MAX, 19);
+impl_packable_test_for_varint!(packable_varint_i8_min, i8, i8::MIN, 2);
+impl_packable_test_for_varint!(packable_varint_i16_negative, i16, -1, 1);
+impl_packable_test_for_varint!(packable_varint_i32_positive, i32, 63, 1);
+impl_packable_test_for_varint!(packable_varint_i32_negative, i32, -65, 2);
+impl_packable_test_for_varint!(packable_varint_i64_max, i64, i64::MAX, 10);
+impl_packable_test_for_varint!(packable_varint_i128_min, i128, i128::MIN, 19);
+
+#[test]
+fn varint_encoding() {
+    assert_eq!(VarInt(300u16).pack_to_vec(), [0xAC, 0x02]);
+    assert_eq!(VarInt(0u32).pack_to_vec(), [0x00]);
+    assert_eq!(VarInt(-1i32).pack_to_vec(), [0x01]);
+    assert_eq!(VarInt(1i32).pack_to_vec(), [0x02]);
+    assert_eq!(VarInt(-2i32).pack_to_vec(), [0x03]);
+}
+
+#[test]
+fn varint_overlong() {
+    // Too many bytes for the type.
+    assert!(matches!(
+        VarInt::<u16>::unpack_bytes_verified([0x80, 0x80, 0x80, 0x01], &()),
+        Err(UnpackError::Packable(UnpackVarIntError::Overlong))
+    ));
+    // Value does not fit in the type.
+    assert!(matches!(
+        VarInt::<u8>::unpack_bytes_verified([0xFF, 0x02], &()),
+        Err(UnpackError::Packable(UnpackVarIntError::Overlong))
+    ));
+    assert!(matches!(
+        VarInt::<u32>::unpack_bytes_verified([0xFF, 0xFF, 0xFF, 0xFF, 0x10], &()),
+        Err(UnpackError::Packable(UnpackVarIntError::Overlong))
+    ));
+}
+
+#[test]
+fn varint_non_minimal() {
+    assert!(matches!(
+        VarInt::<u32>::unpack_bytes_verified([0x81, 0x00], &()),
+        Err(UnpackError::Packable(UnpackVarIntError::NonMinimal))
+    ));
+    assert!(matches!(
+        VarInt::<i64>::unpack_bytes_verified([0x80, 0x80, 0x00], &()),
+        Err(UnpackError::Packable(UnpackVarIntError::NonMinimal))
+    ));
+}
+
+#[test]
+fn varint_truncated() {
+    assert!(matches!(
+        VarInt::<u32>::unpack_bytes_verified([0x80], &()),
+        Err(UnpackError::Unpacker(_))
+    ));
+}
+
+#[test]
+fn varint_vec_prefix() {
+    let value = VecPrefix::<u8, VarInt<u16>>::try_from(vec![7; 200]).unwrap();
+    let bytes = common::generic_test(&value);
+
+    assert_eq!(&bytes.0[..2], [0xC8, 0x01]);
+    assert_eq!(bytes.0.len(), 202);
+}
+
+#[test]
+fn varint_string_prefix() {
+    let value = StringPrefix::<VarInt<u32>>::try_from(String::from("yes")).unwrap();
+    let bytes = common::generic_test(&value);
+
+    assert_eq!(bytes.0, [3, b'y', b'e', b's']);
+}
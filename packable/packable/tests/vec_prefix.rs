@@ -6,11 +6,12 @@ mod common;
 use packable::{
     bounded::{
         BoundedU16, BoundedU32, BoundedU64, BoundedU8, InvalidBoundedU16, InvalidBoundedU32, InvalidBoundedU64,
-        InvalidBoundedU8, TryIntoBoundedU32Error,
+        InvalidBoundedU8, TryIntoBoundedU32Error, TryIntoBoundedU8Error,
     },
-    error::UnpackError,
+    error::{UnexpectedEOF, UnpackError},
     prefix::{UnpackPrefixError, VecPrefix},
-    PackableExt,
+    unpacker::{SliceUnpacker, Unpacker},
+    Packable, PackableExt,
 };
 
 #[test]
@@ -121,3 +122,79 @@ impl_packable_test_for_bounded_vec_prefix!(
     1,
     64
 );
+
+#[test]
+fn packable_vec_prefix_bounded_mutation() {
+    let mut prefixed = VecPrefix::<u8, BoundedU8<1, 3>>::try_from(vec![1]).unwrap();
+
+    prefixed.try_push(2).unwrap();
+    prefixed.try_insert(0, 0).unwrap();
+    assert_eq!(*prefixed, [0, 1, 2]);
+    assert!(matches!(prefixed.try_push(3), Err(TryIntoBoundedU8Error::Invalid(4))));
+    assert!(matches!(
+        prefixed.try_insert(0, 3),
+        Err(TryIntoBoundedU8Error::Invalid(4))
+    ));
+
+    assert_eq!(prefixed.remove(0).unwrap(), 0);
+    assert_eq!(prefixed.pop().unwrap(), Some(2));
+    assert!(matches!(prefixed.pop(), Err(TryIntoBoundedU8Error::Invalid(0))));
+    assert!(matches!(prefixed.remove(0), Err(TryIntoBoundedU8Error::Invalid(0))));
+    assert!(matches!(prefixed.truncate(0), Err(TryIntoBoundedU8Error::Invalid(0))));
+    prefixed.truncate(5).unwrap();
+
+    assert!(matches!(
+        prefixed.try_extend([2, 3, 4]),
+        Err(TryIntoBoundedU8Error::Invalid(4))
+    ));
+    assert_eq!(*prefixed, [1]);
+    prefixed.try_extend([2, 3]).unwrap();
+    assert_eq!(*prefixed, [1, 2, 3]);
+
+    assert!(matches!(
+        prefixed.retain(|_| false),
+        Err(TryIntoBoundedU8Error::Invalid(0))
+    ));
+    assert_eq!(*prefixed, [1, 2, 3]);
+    prefixed.retain(|item| item % 2 == 1).unwrap();
+    assert_eq!(*prefixed, [1, 3]);
+
+    prefixed.as_mut_slice()[0] = 5;
+    assert_eq!(*prefixed, [5, 3]);
+    assert_eq!(
+        prefixed,
+        VecPrefix::unpack_bytes_verified(prefixed.pack_to_vec(), &()).unwrap()
+    );
+}
+
+/// An unpacker that records the bytes hinted to it.
+struct HintUnpacker<'a> {
+    inner: SliceUnpacker<'a>,
+    hints: Vec<usize>,
+}
+
+impl Unpacker for HintUnpacker<'_> {
+    type Error = UnexpectedEOF;
+
+    fn unpack_bytes<B: AsMut<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        self.inner.unpack_bytes(bytes)
+    }
+
+    fn hint_bytes(&mut self, len: usize) {
+        self.hints.push(len);
+    }
+}
+
+#[test]
+fn packable_vec_prefix_hint_fixed_len_items() {
+    let mut unpacker = HintUnpacker {
+        inner: SliceUnpacker::new(&[3, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0]),
+        hints: Vec::new(),
+    };
+
+    // The bytes of the whole sequence are hinted upfront because every `u32` has the same packed length.
+    let prefixed = VecPrefix::<u32, u8>::unpack_verified(&mut unpacker, &()).unwrap();
+
+    assert_eq!(*prefixed, [1, 2, 3]);
+    assert_eq!(unpacker.hints, [12]);
+}
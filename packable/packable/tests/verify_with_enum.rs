@@ -0,0 +1,92 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::Infallible;
+
+use packable::{error::UnpackError, unpacker::SliceUnpacker, Packable, PackableExt};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RangeError {
+    UnknownTag(u8),
+    Empty,
+    TooLong,
+}
+
+impl From<Infallible> for RangeError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+fn verify_bounds(value: &Range, _: &()) -> Result<(), RangeError> {
+    match value {
+        Range::Bounded { start, end } if start >= end => Err(RangeError::Empty),
+        _ => Ok(()),
+    }
+}
+
+fn verify_len(value: &Range, _: &()) -> Result<(), RangeError> {
+    match value {
+        Range::Bounded { start, end } if end - start > 100 => Err(RangeError::TooLong),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Packable)]
+#[packable(tag_type = u8, with_error = RangeError::UnknownTag)]
+#[packable(unpack_error = RangeError)]
+#[packable(verify_with = verify_len)]
+#[packable(unpack_visitor = ())]
+pub enum Range {
+    #[packable(tag = 0)]
+    Full,
+    #[packable(verify_with = verify_bounds)]
+    #[packable(tag = 1)]
+    Bounded { start: u8, end: u8 },
+}
+
+#[test]
+fn verify_with_variant() {
+    let value = Range::Bounded { start: 1, end: 2 };
+    let bytes = value.pack_to_vec();
+
+    assert_eq!(bytes, [1, 1, 2]);
+    assert_eq!(Range::unpack_bytes_verified(&bytes, &()).unwrap(), value);
+    assert!(matches!(
+        Range::unpack_bytes_verified([1, 2, 2], &()),
+        Err(UnpackError::Packable(RangeError::Empty))
+    ));
+    assert_eq!(Range::unpack_bytes_verified([0], &()).unwrap(), Range::Full);
+}
+
+#[test]
+fn verify_with_enum() {
+    assert!(matches!(
+        Range::unpack_bytes_verified([1, 0, 200], &()),
+        Err(UnpackError::Packable(RangeError::TooLong))
+    ));
+    assert!(matches!(
+        Range::unpack_bytes_verified([2], &()),
+        Err(UnpackError::Packable(RangeError::UnknownTag(2)))
+    ));
+}
+
+#[test]
+fn verify_with_variant_unverified() {
+    assert_eq!(
+        Range::unpack_bytes_unverified([1, 2, 2]).unwrap(),
+        Range::Bounded { start: 2, end: 2 }
+    );
+}
+
+#[test]
+fn verify_with_enum_skip() {
+    // Verified enums are unpacked entirely when skipped.
+    let bytes = [1, 2, 1];
+    let mut unpacker = SliceUnpacker::new(&bytes);
+
+    assert!(matches!(
+        Range::skip(&mut unpacker, Some(&())),
+        Err(UnpackError::Packable(RangeError::Empty))
+    ));
+}
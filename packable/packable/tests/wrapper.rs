@@ -0,0 +1,26 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use core::{cmp::Reverse, marker::PhantomData, num::Wrapping};
+
+use packable::{Packable, PackableExt};
+
+#[test]
+fn packable_phantom_data() {
+    assert!(common::generic_test(&PhantomData::<u64>).0.is_empty());
+    assert_eq!(PhantomData::<u64>::PACKED_LEN, Some(0));
+}
+
+#[test]
+fn packable_wrapping() {
+    assert_eq!(common::generic_test(&Wrapping(42u32)).0, 42u32.pack_to_vec());
+    assert_eq!(Wrapping::<u32>::PACKED_LEN, u32::PACKED_LEN);
+}
+
+#[test]
+fn packable_reverse() {
+    assert_eq!(common::generic_test(&Reverse(42u32)).0, 42u32.pack_to_vec());
+    assert_eq!(Reverse::<u32>::PACKED_LEN, u32::PACKED_LEN);
+}